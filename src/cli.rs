@@ -1,39 +1,75 @@
 use crate::{
     commands::{
-        balance::Balance, cipher::Cipher, create::Create, edit::Edit, export::Export,
-        faucet::Faucet, import::Import, list::List, rpc::Rpc, tag::Tag, Command,
+        backup::Backup, balance::Balance, call::Call, cipher::Cipher, contact::Contact,
+        create::Create, edit::Edit, events::Events, export::Export, faucet::Faucet, import::Import,
+        inspect::Inspect, list::List, objects::Objects, package::Package,
+        profile::Profile as ProfileCommand, rotate_key::RotateKey, rpc::Rpc, stake::Stake,
+        stakes::Stakes, storage::Storage, tag::Tag, transfer_object::TransferObject,
+        unstake::Unstake, validators::Validators, view::View, Command,
     },
     error::Error,
-    models::wallet_confy::WalletConfy,
+    models::{
+        profile::{Profile, PROFILE_ENV},
+        wallet_confy::WalletConfy,
+    },
     services::{
-        cipher_service::CipherServiceImpl, rpc_service::RpcServiceImpl,
-        tag_service::TagServiceImpl, transaction_service::TransactionServiceImpl,
-        wallet_service::WalletServiceImpl,
+        backup_service::BackupServiceImpl, cipher_service::CipherServiceImpl,
+        contact_service::ContactServiceImpl, event_service::EventServiceImpl,
+        inspect_service::InspectServiceImpl, move_service::MoveServiceImpl,
+        object_service::ObjectServiceImpl, profile_service::ProfileServiceImpl,
+        rpc_service::RpcServiceImpl, stake_service::StakeServiceImpl,
+        storage_service::StorageServiceImpl, tag_service::TagServiceImpl,
+        transaction_service::TransactionServiceImpl, wallet_service::WalletServiceImpl,
     },
-    storages::confy::ConfyClient,
+    storages::{StorageConfig, CONFIG_ENV},
 };
 use clap::{Parser, Subcommand};
-use std::process::ExitCode;
+use std::{env, path::PathBuf, process::ExitCode, str::FromStr};
 
 #[derive(Parser)]
 #[command(author, version, about)]
 pub struct Cli {
+    #[arg(long, global = true, env = PROFILE_ENV, value_parser = Profile::from_str)]
+    profile: Option<Profile>,
+
+    #[arg(long, global = true, env = CONFIG_ENV)]
+    config: Option<PathBuf>,
+
+    #[arg(long, global = true)]
+    accept_changes: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    Backup(Backup),
     Balance(Balance),
+    Call(Call),
+    Contact(Contact),
     Create(Create),
     Edit(Edit),
+    Events(Events),
     Export(Export),
     Faucet(Faucet),
     Import(Import),
+    Inspect(Inspect),
     List(List),
     NewCipher(Cipher),
+    Objects(Objects),
+    Package(Package),
+    Profile(ProfileCommand),
+    RotateKey(RotateKey),
     Rpc(Rpc),
+    Stake(Stake),
+    Stakes(Stakes),
+    Storage(Storage),
     Tag(Tag),
+    TransferObject(TransferObject),
+    Unstake(Unstake),
+    Validators(Validators),
+    View(View),
 }
 
 impl Cli {
@@ -47,25 +83,69 @@ impl Cli {
     }
 
     fn try_run(self) -> Result<(), Error> {
+        // Storage and cipher settings are read from the environment, so the
+        // flags are passed on through it.
+        unsafe {
+            if let Some(profile) = &self.profile {
+                env::set_var(PROFILE_ENV, profile.to_string());
+            }
+            if let Some(config) = &self.config {
+                env::set_var(CONFIG_ENV, config);
+            }
+        }
+
         let wallet_service = WalletServiceImpl::new();
         let tag_service = TagServiceImpl::new();
         let rpc_service = RpcServiceImpl::new();
+        let stake_service = StakeServiceImpl::new();
         let transaction_service = TransactionServiceImpl::new();
         let cipher_service = CipherServiceImpl::new();
-        let wallet_repository = ConfyClient::<WalletConfy>::new();
+        let object_service = ObjectServiceImpl::new();
+        let move_service = MoveServiceImpl::new();
+        let inspect_service = InspectServiceImpl::new();
+        let event_service = EventServiceImpl::new();
+        let contact_service = ContactServiceImpl::new();
+        let storage_service = StorageServiceImpl::new();
+        let backup_service = BackupServiceImpl::new();
+        let profile_service = ProfileServiceImpl::new();
+        let storage_config =
+            StorageConfig::load_from_env()?.with_accept_changes(self.accept_changes);
+        let wallet_repository = storage_config.open::<WalletConfy>(storage_config.get_storage())?;
 
         match self.command {
+            Commands::Backup(backup) => backup.execute(backup_service, wallet_repository),
             Commands::Balance(balance) => balance.execute(transaction_service, wallet_repository),
+            Commands::Call(call) => call.execute(move_service, wallet_repository),
+            Commands::Contact(contact) => contact.execute(contact_service, wallet_repository),
             Commands::Create(create) => create.execute(wallet_service, wallet_repository),
             Commands::Edit(edit) => edit.execute(wallet_service, wallet_repository),
+            Commands::Events(events) => events.execute(event_service, wallet_repository),
             Commands::Faucet(faucet) => faucet.execute(transaction_service, wallet_repository),
             Commands::Import(import) => import.execute(wallet_service, wallet_repository),
+            Commands::Inspect(inspect) => inspect.execute(inspect_service, wallet_repository),
             Commands::List(list) => list.execute(wallet_service, wallet_repository),
             Commands::NewCipher(new_cipher) => {
                 new_cipher.execute(cipher_service, wallet_repository)
             }
+            Commands::Objects(objects) => objects.execute(object_service, wallet_repository),
+            Commands::Package(package) => package.execute(move_service, wallet_repository),
+            Commands::Profile(profile) => profile.execute(profile_service, wallet_repository),
+            Commands::RotateKey(rotate_key) => {
+                rotate_key.execute(cipher_service, wallet_repository)
+            }
             Commands::Rpc(rpc) => rpc.execute(rpc_service, wallet_repository),
+            Commands::Stake(stake) => stake.execute(stake_service, wallet_repository),
+            Commands::Stakes(stakes) => stakes.execute(stake_service, wallet_repository),
+            Commands::Storage(storage) => storage.execute(storage_service, wallet_repository),
+            Commands::Unstake(unstake) => unstake.execute(stake_service, wallet_repository),
+            Commands::Validators(validators) => {
+                validators.execute(stake_service, wallet_repository)
+            }
+            Commands::View(view) => view.execute(move_service, wallet_repository),
             Commands::Tag(tag) => tag.execute(tag_service, wallet_repository),
+            Commands::TransferObject(transfer_object) => {
+                transfer_object.execute(transaction_service, wallet_repository)
+            }
             Commands::Export(export) => export.execute(wallet_service, wallet_repository),
         }
     }
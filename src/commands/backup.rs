@@ -0,0 +1,61 @@
+use super::{BackupService, Command};
+use crate::{
+    error::Result,
+    services::backup_service::{CreateBackup, RestoreBackup, RestoreMode, VerifyBackup},
+};
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct Backup {
+    #[command(subcommand)]
+    pub command: BackupCommand,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum BackupCommand {
+    Create {
+        file: PathBuf,
+    },
+    Restore {
+        file: PathBuf,
+
+        #[arg(short, long, default_value = "merge")]
+        mode: RestoreMode,
+
+        #[arg(long)]
+        dry_run: bool,
+
+        #[arg(short, long)]
+        yes: bool,
+    },
+    Verify {
+        file: PathBuf,
+
+        #[arg(short, long)]
+        json: bool,
+    },
+}
+
+impl<S: BackupService<R>, R> Command<S, R> for Backup {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        match self.command.clone() {
+            BackupCommand::Create { file } => service.create(CreateBackup { file }, repository),
+            BackupCommand::Restore {
+                file,
+                mode,
+                dry_run,
+                yes,
+            } => service.restore(
+                RestoreBackup {
+                    file,
+                    mode,
+                    dry_run,
+                    yes,
+                },
+                repository,
+            ),
+            BackupCommand::Verify { file, json } => service.verify(VerifyBackup { file, json }),
+        }
+    }
+}
@@ -1,23 +1,26 @@
-use super::{Command, TransactionService};
+use super::{selection::Selection, Command, TransactionService};
 use crate::{
     error::Result,
-    models::{alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, tag_list::TagList},
-    services::transaction_service::GetAllBalance,
+    models::alias_or_url::AliasOrUrl,
+    services::transaction_service::{BalanceGroupBy, GetAllBalance},
 };
 use clap::Args;
 use std::str::FromStr;
 
 #[derive(Debug, Args)]
 pub struct Balance {
-    #[arg(value_parser = AliasOrAddress::from_str)]
-    aliases_or_addresses: Vec<AliasOrAddress>,
-
-    #[arg(short, long, value_parser = TagList::from_str)]
-    tags: Option<TagList>,
+    #[command(flatten)]
+    selection: Selection,
 
     #[arg(short, long, value_parser = AliasOrUrl::from_str)]
     rpc: AliasOrUrl,
 
+    #[arg(short, long, default_value = "coin")]
+    group_by: BalanceGroupBy,
+
+    #[arg(long)]
+    include_zero: bool,
+
     #[arg(short, long)]
     json: bool,
 }
@@ -26,9 +29,10 @@ impl<S: TransactionService<R>, R> Command<S, R> for Balance {
     fn execute(&self, service: S, repository: R) -> Result<()> {
         service.balance(
             GetAllBalance {
-                aliases_or_addresses: self.aliases_or_addresses.clone(),
-                tags: self.tags.clone(),
+                selection: self.selection.to_wallet_selection(),
                 rpc: self.rpc.clone(),
+                group_by: self.group_by.clone(),
+                include_zero: self.include_zero,
                 json: self.json,
             },
             repository,
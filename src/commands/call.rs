@@ -0,0 +1,56 @@
+use super::{Command, MoveService};
+use crate::{
+    error::Result,
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl,
+        move_function_id::MoveFunctionId, type_argument::TypeArgument,
+    },
+    services::{move_service::CallMove, rpc_client::DEFAULT_GAS_BUDGET},
+};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct Call {
+    #[arg(value_parser = AliasOrAddress::from_str)]
+    alias_or_address: AliasOrAddress,
+
+    #[arg(value_parser = MoveFunctionId::from_str)]
+    function: MoveFunctionId,
+
+    #[arg(long, num_args = 1.., value_parser = TypeArgument::from_str)]
+    type_args: Vec<TypeArgument>,
+
+    #[arg(long, num_args = 1.., allow_hyphen_values = true)]
+    args: Vec<String>,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long, default_value_t = DEFAULT_GAS_BUDGET)]
+    gas_budget: u64,
+
+    #[arg(short, long)]
+    yes: bool,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: MoveService<R>, R> Command<S, R> for Call {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.call(
+            CallMove {
+                alias_or_address: self.alias_or_address.clone(),
+                function: self.function.clone(),
+                type_args: self.type_args.clone(),
+                args: self.args.clone(),
+                rpc: self.rpc.clone(),
+                gas_budget: self.gas_budget,
+                yes: self.yes,
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
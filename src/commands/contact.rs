@@ -0,0 +1,105 @@
+use super::{Command, ContactService};
+use crate::{
+    error::Result,
+    models::{
+        alias::Alias, alias_or_address::AliasOrAddress, contact::parse_contact_alias,
+        tag_filter::TagFilter, tag_list::TagList,
+    },
+    services::contact_service::{CreateContact, EditContact, ListContacts, RemoveContact},
+};
+use clap::{Args, Subcommand};
+use std::str::FromStr;
+use sui_sdk::types::base_types::SuiAddress;
+
+#[derive(Debug, Args)]
+pub struct Contact {
+    #[command(subcommand)]
+    pub command: ContactCommand,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum ContactCommand {
+    Add {
+        #[arg(value_parser = SuiAddress::from_str)]
+        address: SuiAddress,
+
+        #[arg(short, long, value_parser = parse_contact_alias)]
+        alias: Alias,
+
+        #[arg(short, long, value_parser = TagList::from_str)]
+        tags: Option<TagList>,
+
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+    Remove {
+        #[arg(value_parser = AliasOrAddress::from_str)]
+        alias_or_address: AliasOrAddress,
+    },
+    Edit {
+        #[arg(value_parser = AliasOrAddress::from_str)]
+        alias_or_address: AliasOrAddress,
+
+        #[arg(long, value_parser = SuiAddress::from_str)]
+        address: Option<SuiAddress>,
+
+        #[arg(short, long, value_parser = parse_contact_alias)]
+        alias: Option<Alias>,
+
+        #[arg(short, long, value_parser = TagList::from_str)]
+        tags: Option<TagList>,
+
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+    List {
+        #[arg(short, long, value_parser = TagFilter::from_str)]
+        tags: Option<TagFilter>,
+
+        #[arg(short, long)]
+        json: bool,
+    },
+}
+
+impl<S: ContactService<R>, R> Command<S, R> for Contact {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        match self.command.clone() {
+            ContactCommand::Add {
+                address,
+                alias,
+                tags,
+                note,
+            } => service.create(
+                CreateContact {
+                    address,
+                    alias,
+                    tags,
+                    note,
+                },
+                repository,
+            ),
+            ContactCommand::Remove { alias_or_address } => {
+                service.remove(RemoveContact { alias_or_address }, repository)
+            }
+            ContactCommand::Edit {
+                alias_or_address,
+                address,
+                alias,
+                tags,
+                note,
+            } => service.edit(
+                EditContact {
+                    alias_or_address,
+                    address,
+                    alias,
+                    tags,
+                    note,
+                },
+                repository,
+            ),
+            ContactCommand::List { tags, json } => {
+                service.list(ListContacts { tags, json }, repository)
+            }
+        }
+    }
+}
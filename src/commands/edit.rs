@@ -1,6 +1,12 @@
 use crate::{
     error::Result,
-    models::{alias::Alias, alias_or_address::AliasOrAddress, tag_list::TagList},
+    models::{
+        alias::Alias,
+        alias_or_address::AliasOrAddress,
+        tag_list::TagList,
+        wallet_edit::WalletEdit,
+        wallet_metadata::{parse_metadata_entry, parse_metadata_key},
+    },
     services::wallet_service::EditWallet,
 };
 use clap::Args;
@@ -16,8 +22,26 @@ pub struct Edit {
     #[arg(short, long, value_parser = Alias::new)]
     alias: Option<Alias>,
 
+    #[arg(long, conflicts_with = "alias")]
+    clear_alias: bool,
+
     #[arg(short, long, value_parser = TagList::from_str)]
     tags: Option<TagList>,
+
+    #[arg(long, value_parser = TagList::from_str)]
+    add_tags: Option<TagList>,
+
+    #[arg(long, value_parser = TagList::from_str)]
+    remove_tags: Option<TagList>,
+
+    #[arg(short, long)]
+    note: Option<String>,
+
+    #[arg(short, long = "set-meta", value_parser = parse_metadata_entry)]
+    set_metadata: Vec<(String, String)>,
+
+    #[arg(short, long = "unset-meta", value_parser = parse_metadata_key)]
+    unset_metadata: Vec<String>,
 }
 
 impl<S: WalletService<R>, R> Command<S, R> for Edit {
@@ -25,8 +49,12 @@ impl<S: WalletService<R>, R> Command<S, R> for Edit {
         service.edit(
             EditWallet {
                 alias_or_address: self.alias_or_address.clone(),
-                alias: self.alias.clone(),
-                tags: self.tags.clone(),
+                edit: WalletEdit::new(self.alias.clone(), self.clear_alias, self.tags.clone())
+                    .with_add_tags(self.add_tags.clone().unwrap_or_default())
+                    .with_remove_tags(self.remove_tags.clone().unwrap_or_default())
+                    .with_note(self.note.clone())
+                    .with_set_metadata(self.set_metadata.clone())
+                    .with_unset_metadata(self.unset_metadata.clone()),
             },
             repository,
         )
@@ -0,0 +1,78 @@
+use super::{Command, EventService};
+use crate::{
+    error::Result,
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, tag_filter::TagFilter,
+        timestamp::Timestamp,
+    },
+    services::event_service::ListEvents,
+};
+use clap::Args;
+use move_core_types::{identifier::Identifier, language_storage::StructTag};
+use std::str::FromStr;
+use sui_sdk::types::{base_types::ObjectID, parse_sui_struct_tag};
+
+#[derive(Debug, Args)]
+pub struct Events {
+    #[arg(long = "type", value_parser = parse_sui_struct_tag)]
+    event_type: Option<StructTag>,
+
+    #[arg(short, long, value_parser = ObjectID::from_str)]
+    package: Option<ObjectID>,
+
+    #[arg(short, long, requires = "package", value_parser = Identifier::from_str)]
+    module: Option<Identifier>,
+
+    #[arg(short, long, value_parser = AliasOrAddress::from_str)]
+    sender: Vec<AliasOrAddress>,
+
+    #[arg(short, long, value_parser = TagFilter::from_str)]
+    tags: Option<TagFilter>,
+
+    #[arg(long, value_parser = Timestamp::from_str)]
+    since: Option<Timestamp>,
+
+    #[arg(long, value_parser = Timestamp::from_str)]
+    until: Option<Timestamp>,
+
+    #[arg(short, long, default_value_t = 50)]
+    limit: usize,
+
+    #[arg(short, long, conflicts_with = "follow")]
+    ascending: bool,
+
+    #[arg(short, long)]
+    follow: bool,
+
+    #[arg(short, long, default_value_t = 2, requires = "follow")]
+    interval: u64,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: EventService<R>, R> Command<S, R> for Events {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.list(
+            ListEvents {
+                event_type: self.event_type.clone(),
+                package: self.package,
+                module: self.module.clone(),
+                senders: self.sender.clone(),
+                tags: self.tags.clone(),
+                since: self.since,
+                until: self.until,
+                limit: self.limit,
+                ascending: self.ascending,
+                follow: self.follow,
+                interval: self.interval,
+                rpc: self.rpc.clone(),
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
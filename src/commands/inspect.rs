@@ -0,0 +1,61 @@
+use super::{Command, InspectService};
+use crate::{
+    error::Result,
+    models::alias_or_url::AliasOrUrl,
+    services::inspect_service::{InspectObject, InspectTransaction},
+};
+use clap::{Args, Subcommand};
+use std::str::FromStr;
+use sui_sdk::types::{base_types::ObjectID, digests::TransactionDigest};
+
+#[derive(Debug, Args)]
+pub struct Inspect {
+    #[command(subcommand)]
+    pub command: InspectCommand,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum InspectCommand {
+    Object {
+        #[arg(value_parser = ObjectID::from_str)]
+        object_id: ObjectID,
+
+        #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+        rpc: AliasOrUrl,
+
+        #[arg(short, long)]
+        json: bool,
+    },
+    Tx {
+        #[arg(value_parser = TransactionDigest::from_str)]
+        digest: TransactionDigest,
+
+        #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+        rpc: AliasOrUrl,
+
+        #[arg(short, long)]
+        json: bool,
+    },
+}
+
+impl<S: InspectService<R>, R> Command<S, R> for Inspect {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        match self.command.clone() {
+            InspectCommand::Object {
+                object_id,
+                rpc,
+                json,
+            } => service.object(
+                InspectObject {
+                    object_id,
+                    rpc,
+                    json,
+                },
+                repository,
+            ),
+            InspectCommand::Tx { digest, rpc, json } => {
+                service.transaction(InspectTransaction { digest, rpc, json }, repository)
+            }
+        }
+    }
+}
@@ -1,19 +1,43 @@
 use super::{Command, WalletService};
 use crate::{
     error::Result,
-    models::{alias::Alias, tag_list::TagList},
-    services::wallet_service::ListWallet,
+    models::{
+        alias_or_url::AliasOrUrl, alias_pattern::AliasPattern, metadata_filter::MetadataFilter,
+        tag_filter::TagFilter,
+    },
+    services::wallet_service::{ListWallet, WalletColumn, WalletSortBy},
 };
 use clap::Args;
 use std::str::FromStr;
 
 #[derive(Debug, Args)]
 pub struct List {
-    #[arg(short, long, value_parser = Alias::new)]
-    alias: Option<Alias>,
+    #[arg(short, long, value_parser = AliasPattern::from_str)]
+    alias: Option<AliasPattern>,
 
-    #[arg(short, long, value_parser = TagList::from_str)]
-    tags: Option<TagList>,
+    #[arg(short, long, value_parser = TagFilter::from_str)]
+    tags: Option<TagFilter>,
+
+    #[arg(short, long = "meta", value_parser = MetadataFilter::from_str)]
+    metadata: Vec<MetadataFilter>,
+
+    #[arg(short, long, default_value = "address")]
+    sort: WalletSortBy,
+
+    #[arg(short, long, value_delimiter = ',')]
+    columns: Vec<WalletColumn>,
+
+    #[arg(short, long)]
+    details: bool,
+
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    #[arg(short, long, default_value_t = 0)]
+    offset: usize,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: Option<AliasOrUrl>,
 
     #[arg(short, long)]
     json: bool,
@@ -25,6 +49,13 @@ impl<S: WalletService<R>, R> Command<S, R> for List {
             ListWallet {
                 alias: self.alias.clone(),
                 tags: self.tags.clone(),
+                metadata: self.metadata.clone(),
+                sort: self.sort.clone(),
+                columns: self.columns.clone(),
+                details: self.details,
+                limit: self.limit,
+                offset: self.offset,
+                rpc: self.rpc.clone(),
                 json: self.json,
             },
             repository,
@@ -1,23 +1,53 @@
 use crate::{
     error::Result,
+    models::cipher::Cipher,
     services::{
+        backup_service::{CreateBackup, RestoreBackup, VerifyBackup},
+        cipher_service::RotateCipher,
+        contact_service::{CreateContact, EditContact, ListContacts, RemoveContact},
+        event_service::ListEvents,
+        inspect_service::{InspectObject, InspectTransaction},
+        move_service::{CallMove, InspectPackage, ViewMove},
+        object_service::ListObjects,
+        profile_service::{CreateProfile, DeleteProfile, ListProfiles, TransferWallets},
         rpc_service::{CreateRpc, ListRpc, RemoveRpc},
-        tag_service::{CreateTags, ListTags, RemoveTags},
-        transaction_service::{GetAllBalance, RequestFaucet},
+        stake_service::{AddStake, ListStakes, ListValidators, WithdrawStake},
+        storage_service::MigrateStorage,
+        tag_service::{
+            AssignTags, CreateTags, EditTag, ListTags, RemoveTags, RenameTag, UnassignTags,
+        },
+        transaction_service::{GetAllBalance, RequestFaucet, TransferObjects},
         wallet_service::{CreateWallet, EditWallet, ExportWallet, ImportWallet, ListWallet},
     },
 };
 
+pub mod backup;
 pub mod balance;
+pub mod call;
 pub mod cipher;
+pub mod contact;
 pub mod create;
 pub mod edit;
+pub mod events;
 pub mod export;
 pub mod faucet;
 pub mod import;
+pub mod inspect;
 pub mod list;
+pub mod objects;
+pub mod package;
+pub mod profile;
+pub mod rotate_key;
 pub mod rpc;
+pub mod selection;
+pub mod stake;
+pub mod stakes;
+pub mod storage;
 pub mod tag;
+pub mod transfer_object;
+pub mod unstake;
+pub mod validators;
+pub mod view;
 
 pub trait Command<S, R> {
     fn execute(&self, service: S, repository: R) -> Result<()>;
@@ -34,9 +64,20 @@ pub trait WalletService<R> {
 pub trait TagService<R> {
     fn create(&self, create_tags: CreateTags, repository: R) -> Result<()>;
     fn remote(&self, remove_tags: RemoveTags, repository: R) -> Result<()>;
+    fn edit(&self, edit_tag: EditTag, repository: R) -> Result<()>;
+    fn rename(&self, rename_tag: RenameTag, repository: R) -> Result<()>;
+    fn assign(&self, assign_tags: AssignTags, repository: R) -> Result<()>;
+    fn unassign(&self, unassign_tags: UnassignTags, repository: R) -> Result<()>;
     fn list(&self, list_tags: ListTags, repository: R) -> Result<()>;
 }
 
+pub trait ContactService<R> {
+    fn create(&self, create_contact: CreateContact, repository: R) -> Result<()>;
+    fn remove(&self, remove_contact: RemoveContact, repository: R) -> Result<()>;
+    fn edit(&self, edit_contact: EditContact, repository: R) -> Result<()>;
+    fn list(&self, list_contacts: ListContacts, repository: R) -> Result<()>;
+}
+
 pub trait RpcService<R> {
     fn create(&self, create_rpc: CreateRpc, repository: R) -> Result<()>;
     fn remote(&self, remove_rpc: RemoveRpc, repository: R) -> Result<()>;
@@ -48,13 +89,68 @@ pub trait TransactionService<R> {
     fn balance(&self, get_all_balance: GetAllBalance, repository: R) -> Result<()>;
     fn faucet(&self, request_faucet: RequestFaucet, repository: R) -> Result<()>;
     fn send_coin(&self) -> Result<()>;
+    fn transfer_object(&self, transfer_objects: TransferObjects, repository: R) -> Result<()>;
+}
+
+pub trait ObjectService<R> {
+    fn list(&self, list_objects: ListObjects, repository: R) -> Result<()>;
+}
+
+pub trait EventService<R> {
+    fn list(&self, list_events: ListEvents, repository: R) -> Result<()>;
+}
+
+pub trait InspectService<R> {
+    fn object(&self, inspect_object: InspectObject, repository: R) -> Result<()>;
+    fn transaction(&self, inspect_transaction: InspectTransaction, repository: R) -> Result<()>;
+}
+
+pub trait MoveService<R> {
+    fn call(&self, call_move: CallMove, repository: R) -> Result<()>;
+    fn view(&self, view_move: ViewMove, repository: R) -> Result<()>;
+    fn inspect_package(&self, inspect_package: InspectPackage, repository: R) -> Result<()>;
+}
+
+pub trait StakeService<R> {
+    fn stake(&self, add_stake: AddStake, repository: R) -> Result<()>;
+    fn unstake(&self, withdraw_stake: WithdrawStake, repository: R) -> Result<()>;
+    fn list_stakes(&self, list_stakes: ListStakes, repository: R) -> Result<()>;
+    fn list_validators(&self, list_validators: ListValidators, repository: R) -> Result<()>;
+}
+
+pub trait BackupService<R> {
+    fn create(&self, create_backup: CreateBackup, repository: R) -> Result<()>;
+    fn restore(&self, restore_backup: RestoreBackup, repository: R) -> Result<()>;
+    fn verify(&self, verify_backup: VerifyBackup) -> Result<()>;
 }
 
-pub trait CipherService {
+pub trait ProfileService<R> {
+    fn list(&self, list_profiles: ListProfiles) -> Result<()>;
+    fn create(&self, create_profile: CreateProfile) -> Result<()>;
+    fn delete(&self, delete_profile: DeleteProfile) -> Result<()>;
+    fn transfer(&self, transfer_wallets: TransferWallets, repository: R) -> Result<()>;
+}
+
+pub trait CipherService<R> {
     fn create(&self) -> Result<()>;
+    fn rotate(&self, rotate_cipher: RotateCipher, repository: R) -> Result<()>;
+}
+
+pub trait StorageService {
+    fn migrate(&self, migrate_storage: MigrateStorage) -> Result<()>;
 }
 
+/// `load` locks the vault against other invocations until `store`, or
+/// `release` for commands that don't change it.
 pub trait WalletRepository<C> {
     fn load(&self) -> Result<C>;
     fn store(&self, confy: C) -> Result<()>;
+    /// Stores `confy` signed with `cipher` instead of the profile's, for
+    /// when the cipher itself is being replaced. Backups of the vault are
+    /// still readable with the old cipher, so they're removed instead of
+    /// rotated.
+    fn store_with_cipher(&self, confy: C, cipher: &Cipher) -> Result<()>;
+    /// Lets other invocations in without storing, e.g. before slow network
+    /// calls. A later `store` overwrites whatever they changed meanwhile.
+    fn release(&self);
 }
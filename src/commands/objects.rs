@@ -0,0 +1,39 @@
+use super::{Command, ObjectService};
+use crate::{
+    error::Result,
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, object_filter::ObjectFilter,
+    },
+    services::object_service::ListObjects,
+};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct Objects {
+    #[arg(value_parser = AliasOrAddress::from_str)]
+    alias_or_address: AliasOrAddress,
+
+    #[arg(short, long, value_parser = ObjectFilter::from_str)]
+    filter: Option<ObjectFilter>,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: ObjectService<R>, R> Command<S, R> for Objects {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.list(
+            ListObjects {
+                alias_or_address: self.alias_or_address.clone(),
+                filter: self.filter.clone(),
+                rpc: self.rpc.clone(),
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use super::{selection::Selection, Command, MoveService};
+use crate::{
+    error::Result, models::alias_or_url::AliasOrUrl, services::move_service::InspectPackage,
+};
+use clap::Args;
+use std::str::FromStr;
+use sui_sdk::types::base_types::ObjectID;
+
+#[derive(Debug, Args)]
+pub struct Package {
+    #[arg(value_parser = ObjectID::from_str)]
+    package: ObjectID,
+
+    #[command(flatten)]
+    selection: Selection,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: MoveService<R>, R> Command<S, R> for Package {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.inspect_package(
+            InspectPackage {
+                package: self.package,
+                selection: self.selection.to_wallet_selection(),
+                rpc: self.rpc.clone(),
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use super::{selection::Selection, Command, ProfileService};
+use crate::{
+    error::Result,
+    models::profile::Profile as ProfileName,
+    services::profile_service::{CreateProfile, DeleteProfile, ListProfiles, TransferWallets},
+};
+use clap::{Args, Subcommand};
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct Profile {
+    #[command(subcommand)]
+    pub command: ProfileCommand,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum ProfileCommand {
+    List {
+        #[arg(short, long)]
+        json: bool,
+    },
+    Create {
+        #[arg(value_parser = ProfileName::from_str)]
+        name: ProfileName,
+    },
+    Delete {
+        #[arg(value_parser = ProfileName::from_str)]
+        name: ProfileName,
+
+        #[arg(short, long)]
+        yes: bool,
+    },
+    Copy {
+        #[command(flatten)]
+        selection: Selection,
+
+        #[arg(long, value_parser = ProfileName::from_str)]
+        to: ProfileName,
+    },
+    Move {
+        #[command(flatten)]
+        selection: Selection,
+
+        #[arg(long, value_parser = ProfileName::from_str)]
+        to: ProfileName,
+    },
+}
+
+impl<S: ProfileService<R>, R> Command<S, R> for Profile {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        match self.command.clone() {
+            ProfileCommand::List { json } => service.list(ListProfiles { json }),
+            ProfileCommand::Create { name } => service.create(CreateProfile { name }),
+            ProfileCommand::Delete { name, yes } => service.delete(DeleteProfile { name, yes }),
+            ProfileCommand::Copy { selection, to } => service.transfer(
+                TransferWallets {
+                    selection: selection.to_wallet_selection(),
+                    to,
+                    remove: false,
+                },
+                repository,
+            ),
+            ProfileCommand::Move { selection, to } => service.transfer(
+                TransferWallets {
+                    selection: selection.to_wallet_selection(),
+                    to,
+                    remove: true,
+                },
+                repository,
+            ),
+        }
+    }
+}
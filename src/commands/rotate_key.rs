@@ -0,0 +1,25 @@
+use super::{CipherService, Command};
+use crate::{error::Result, services::cipher_service::RotateCipher};
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct RotateKey {
+    #[arg(short, long)]
+    env_file: Option<PathBuf>,
+
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl<S: CipherService<R>, R> Command<S, R> for RotateKey {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.rotate(
+            RotateCipher {
+                env_file: self.env_file.clone(),
+                dry_run: self.dry_run,
+            },
+            repository,
+        )
+    }
+}
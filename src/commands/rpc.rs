@@ -24,6 +24,9 @@ pub enum RpcCommand {
 
         #[arg(short, long)]
         env: Option<AddNetworkEnv>,
+
+        #[arg(short, long, value_parser = RpcUrl::from_str)]
+        ws_url: Option<RpcUrl>,
     },
     Remove {
         #[arg(value_parser = AliasOrUrl::from_str)]
@@ -44,9 +47,20 @@ pub enum RpcCommand {
 impl<S: RpcService<R>, R> Command<S, R> for Rpc {
     fn execute(&self, service: S, repository: R) -> Result<()> {
         match self.command.clone() {
-            RpcCommand::Add { url, alias, env } => {
-                service.create(CreateRpc { url, alias, env }, repository)
-            }
+            RpcCommand::Add {
+                url,
+                alias,
+                env,
+                ws_url,
+            } => service.create(
+                CreateRpc {
+                    url,
+                    alias,
+                    env,
+                    ws_url,
+                },
+                repository,
+            ),
             RpcCommand::Remove { alias_or_url } => {
                 service.remote(RemoveRpc { alias_or_url }, repository)
             }
@@ -0,0 +1,27 @@
+use crate::models::{
+    alias_or_address::AliasOrAddress, tag_filter::TagFilter, wallet_selection::WalletSelection,
+};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args, Clone)]
+pub struct Selection {
+    #[arg(value_parser = AliasOrAddress::from_str)]
+    aliases_or_addresses: Vec<AliasOrAddress>,
+
+    #[arg(short, long, value_parser = TagFilter::from_str)]
+    tags: Option<TagFilter>,
+
+    #[arg(long, conflicts_with = "aliases_or_addresses")]
+    all: bool,
+}
+
+impl Selection {
+    pub fn to_wallet_selection(&self) -> WalletSelection {
+        WalletSelection::new(
+            self.aliases_or_addresses.clone(),
+            self.tags.clone(),
+            self.all,
+        )
+    }
+}
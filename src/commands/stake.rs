@@ -0,0 +1,48 @@
+use super::{Command, StakeService};
+use crate::{
+    error::Result,
+    models::{
+        address_or_name::AddressOrName, alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl,
+        sui_amount::SuiAmount,
+    },
+    services::{rpc_client::DEFAULT_GAS_BUDGET, stake_service::AddStake},
+};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct Stake {
+    #[arg(value_parser = AliasOrAddress::from_str)]
+    alias_or_address: AliasOrAddress,
+
+    #[arg(short, long, value_parser = AddressOrName::from_str)]
+    validator: AddressOrName,
+
+    #[arg(short, long, value_parser = SuiAmount::from_str)]
+    amount: SuiAmount,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long, default_value_t = DEFAULT_GAS_BUDGET)]
+    gas_budget: u64,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: StakeService<R>, R> Command<S, R> for Stake {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.stake(
+            AddStake {
+                alias_or_address: self.alias_or_address.clone(),
+                validator: self.validator.clone(),
+                amount: self.amount,
+                rpc: self.rpc.clone(),
+                gas_budget: self.gas_budget,
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
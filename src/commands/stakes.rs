@@ -0,0 +1,29 @@
+use super::{selection::Selection, Command, StakeService};
+use crate::{error::Result, models::alias_or_url::AliasOrUrl, services::stake_service::ListStakes};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct Stakes {
+    #[command(flatten)]
+    selection: Selection,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: StakeService<R>, R> Command<S, R> for Stakes {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.list_stakes(
+            ListStakes {
+                selection: self.selection.to_wallet_selection(),
+                rpc: self.rpc.clone(),
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
@@ -0,0 +1,28 @@
+use super::{Command, StorageService};
+use crate::{error::Result, services::storage_service::MigrateStorage, storages::WalletStorage};
+use clap::{Args, Subcommand};
+
+#[derive(Debug, Args)]
+pub struct Storage {
+    #[command(subcommand)]
+    pub command: StorageCommand,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum StorageCommand {
+    Migrate {
+        #[arg(long)]
+        to: WalletStorage,
+
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+impl<S: StorageService, R> Command<S, R> for Storage {
+    fn execute(&self, service: S, _repository: R) -> Result<()> {
+        match self.command.clone() {
+            StorageCommand::Migrate { to, force } => service.migrate(MigrateStorage { to, force }),
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use super::{Command, TagService};
+use super::{selection::Selection, Command, TagService};
 use crate::{
     error::Result,
-    models::tag_list::TagList,
-    services::tag_service::{CreateTags, ListTags, RemoveTags},
+    models::{tag::Tag, tag_color::TagColor, tag_list::TagList},
+    services::tag_service::{
+        AssignTags, CreateTags, EditTag, ListTags, RemoveTags, RenameTag, UnassignTags,
+    },
 };
 use clap::{Args, Subcommand};
 use std::str::FromStr;
@@ -18,23 +20,122 @@ pub enum TagCommand {
     Add {
         #[arg(value_parser = TagList::from_str)]
         names: TagList,
+
+        #[arg(short, long)]
+        description: Option<String>,
+
+        #[arg(short, long, value_parser = TagColor::from_str)]
+        color: Option<TagColor>,
+    },
+    Edit {
+        #[arg(value_parser = Tag::new)]
+        name: Tag,
+
+        #[arg(short, long)]
+        description: Option<String>,
+
+        #[arg(short, long, value_parser = TagColor::from_str)]
+        color: Option<TagColor>,
+
+        #[arg(long, conflicts_with = "color")]
+        clear_color: bool,
+    },
+    Rename {
+        #[arg(value_parser = Tag::new)]
+        old: Tag,
+
+        #[arg(value_parser = Tag::new)]
+        new: Tag,
+    },
+    Move {
+        #[arg(value_parser = Tag::new)]
+        name: Tag,
+
+        #[arg(value_parser = Tag::new)]
+        parent: Option<Tag>,
     },
     Remove {
         #[arg(value_parser = TagList::from_str)]
         names: TagList,
+
+        #[arg(short, long)]
+        yes: bool,
     },
     List {
         #[arg(short, long)]
         json: bool,
     },
+    Assign {
+        #[arg(value_parser = TagList::from_str)]
+        names: TagList,
+
+        #[command(flatten)]
+        selection: Selection,
+    },
+    Unassign {
+        #[arg(value_parser = TagList::from_str)]
+        names: TagList,
+
+        #[command(flatten)]
+        selection: Selection,
+    },
 }
 
 impl<S: TagService<R>, R> Command<S, R> for Tag {
     fn execute(&self, service: S, repository: R) -> Result<()> {
         match self.command.clone() {
-            TagCommand::Add { names } => service.create(CreateTags { names }, repository),
-            TagCommand::Remove { names } => service.remote(RemoveTags { names }, repository),
+            TagCommand::Add {
+                names,
+                description,
+                color,
+            } => service.create(
+                CreateTags {
+                    names,
+                    description,
+                    color,
+                },
+                repository,
+            ),
+            TagCommand::Edit {
+                name,
+                description,
+                color,
+                clear_color,
+            } => service.edit(
+                EditTag {
+                    name,
+                    description,
+                    color,
+                    clear_color,
+                },
+                repository,
+            ),
+            TagCommand::Rename { old, new } => service.rename(RenameTag { old, new }, repository),
+            TagCommand::Move { name, parent } => {
+                let new = match parent {
+                    Some(parent) => Tag::new(&format!("{}/{}", parent, name.get_name()))?,
+                    None => Tag::new(name.get_name())?,
+                };
+                service.rename(RenameTag { old: name, new }, repository)
+            }
+            TagCommand::Remove { names, yes } => {
+                service.remote(RemoveTags { names, yes }, repository)
+            }
             TagCommand::List { json } => service.list(ListTags { json }, repository),
+            TagCommand::Assign { names, selection } => service.assign(
+                AssignTags {
+                    names,
+                    selection: selection.to_wallet_selection(),
+                },
+                repository,
+            ),
+            TagCommand::Unassign { names, selection } => service.unassign(
+                UnassignTags {
+                    names,
+                    selection: selection.to_wallet_selection(),
+                },
+                repository,
+            ),
         }
     }
 }
@@ -0,0 +1,135 @@
+use super::{Command, TransactionService};
+use crate::{
+    error::Result,
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, object_filter::ObjectFilter,
+        tag_filter::TagFilter, wallet_selection::WalletSelection,
+    },
+    services::{rpc_client::DEFAULT_GAS_BUDGET, transaction_service::TransferObjects},
+};
+use clap::{ArgGroup, Args};
+use std::str::FromStr;
+use sui_sdk::types::base_types::ObjectID;
+
+/// Sends from `<from>`, or from every wallet matching `--tags`, which then
+/// takes its object ids as `--ids`.
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("by_tags").args(["tags", "tagged_object_ids"]).multiple(true)))]
+pub struct TransferObject {
+    #[arg(
+        value_parser = AliasOrAddress::from_str,
+        required_unless_present = "tags",
+        conflicts_with = "by_tags"
+    )]
+    from: Option<AliasOrAddress>,
+
+    #[arg(value_parser = ObjectID::from_str)]
+    object_ids: Vec<ObjectID>,
+
+    #[arg(long, value_parser = AliasOrAddress::from_str)]
+    to: AliasOrAddress,
+
+    #[arg(short, long, value_parser = TagFilter::from_str)]
+    tags: Option<TagFilter>,
+
+    #[arg(long = "ids", num_args = 1.., value_parser = ObjectID::from_str, requires = "tags")]
+    tagged_object_ids: Vec<ObjectID>,
+
+    #[arg(short, long, value_parser = ObjectFilter::from_str)]
+    filter: Option<ObjectFilter>,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long, default_value_t = DEFAULT_GAS_BUDGET)]
+    gas_budget: u64,
+
+    #[arg(short, long)]
+    yes: bool,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: TransactionService<R>, R> Command<S, R> for TransferObject {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.transfer_object(
+            TransferObjects {
+                selection: WalletSelection::new(
+                    self.from.clone().into_iter().collect(),
+                    self.tags.clone(),
+                    false,
+                ),
+                object_ids: self
+                    .object_ids
+                    .iter()
+                    .chain(self.tagged_object_ids.iter())
+                    .cloned()
+                    .collect(),
+                filter: self.filter.clone(),
+                recipient: self.to.clone(),
+                rpc: self.rpc.clone(),
+                gas_budget: self.gas_budget,
+                yes: self.yes,
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransferObject;
+    use clap::Parser;
+    use sui_sdk::types::base_types::ObjectID;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        transfer_object: TransferObject,
+    }
+
+    fn parse(args: &[&str]) -> Result<TransferObject, clap::Error> {
+        let mut argv = vec!["transfer-object"];
+        argv.extend_from_slice(args);
+        argv.extend_from_slice(&["--to", "bob", "--rpc", "testnet"]);
+
+        TestCli::try_parse_from(argv).map(|cli| cli.transfer_object)
+    }
+
+    #[test]
+    fn test_parse_from_and_object_ids() {
+        let object_id = ObjectID::random();
+        let transfer_object = parse(&["alice", &object_id.to_string()]).unwrap();
+
+        assert_eq!(transfer_object.from.unwrap().to_string(), "alice");
+        assert!(transfer_object.tags.is_none());
+        assert_eq!(transfer_object.object_ids, vec![object_id]);
+    }
+
+    #[test]
+    fn test_parse_tags_and_object_ids() {
+        let object_id = ObjectID::random();
+        let transfer_object = parse(&["--tags", "qa", "--ids", &object_id.to_string()]).unwrap();
+
+        assert!(transfer_object.from.is_none());
+        assert!(transfer_object.tags.is_some());
+        assert!(transfer_object.object_ids.is_empty());
+        assert_eq!(transfer_object.tagged_object_ids, vec![object_id]);
+
+        let transfer_object = parse(&["--tags", "qa", "--filter", "0x2::coin::Coin"]).unwrap();
+        assert!(transfer_object.tagged_object_ids.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let object_id = ObjectID::random().to_string();
+
+        // A positional id alongside --tags would be taken as the sender
+        assert!(parse(&["--tags", "qa", &object_id]).is_err());
+        assert!(parse(&["alice", "--tags", "qa"]).is_err());
+        assert!(parse(&["alice", "--ids", &object_id]).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
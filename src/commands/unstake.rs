@@ -0,0 +1,38 @@
+use super::{Command, StakeService};
+use crate::{
+    error::Result,
+    models::alias_or_url::AliasOrUrl,
+    services::{rpc_client::DEFAULT_GAS_BUDGET, stake_service::WithdrawStake},
+};
+use clap::Args;
+use std::str::FromStr;
+use sui_sdk::types::base_types::ObjectID;
+
+#[derive(Debug, Args)]
+pub struct Unstake {
+    #[arg(value_parser = ObjectID::from_str, required = true)]
+    staked_sui_ids: Vec<ObjectID>,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long, default_value_t = DEFAULT_GAS_BUDGET)]
+    gas_budget: u64,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: StakeService<R>, R> Command<S, R> for Unstake {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.unstake(
+            WithdrawStake {
+                staked_sui_ids: self.staked_sui_ids.clone(),
+                rpc: self.rpc.clone(),
+                gas_budget: self.gas_budget,
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use super::{Command, StakeService};
+use crate::{
+    error::Result, models::alias_or_url::AliasOrUrl, services::stake_service::ListValidators,
+};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct Validators {
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: StakeService<R>, R> Command<S, R> for Validators {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.list_validators(
+            ListValidators {
+                rpc: self.rpc.clone(),
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
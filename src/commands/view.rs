@@ -0,0 +1,48 @@
+use super::{Command, MoveService};
+use crate::{
+    error::Result,
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl,
+        move_function_id::MoveFunctionId, type_argument::TypeArgument,
+    },
+    services::move_service::ViewMove,
+};
+use clap::Args;
+use std::str::FromStr;
+
+#[derive(Debug, Args)]
+pub struct View {
+    #[arg(value_parser = MoveFunctionId::from_str)]
+    function: MoveFunctionId,
+
+    #[arg(long, num_args = 1.., value_parser = TypeArgument::from_str)]
+    type_args: Vec<TypeArgument>,
+
+    #[arg(long, num_args = 1.., allow_hyphen_values = true)]
+    args: Vec<String>,
+
+    #[arg(short, long, value_parser = AliasOrAddress::from_str)]
+    sender: Option<AliasOrAddress>,
+
+    #[arg(short, long, value_parser = AliasOrUrl::from_str)]
+    rpc: AliasOrUrl,
+
+    #[arg(short, long)]
+    json: bool,
+}
+
+impl<S: MoveService<R>, R> Command<S, R> for View {
+    fn execute(&self, service: S, repository: R) -> Result<()> {
+        service.view(
+            ViewMove {
+                function: self.function.clone(),
+                type_args: self.type_args.clone(),
+                args: self.args.clone(),
+                sender: self.sender.clone(),
+                rpc: self.rpc.clone(),
+                json: self.json,
+            },
+            repository,
+        )
+    }
+}
@@ -1,5 +1,8 @@
 use crate::models::{alias::Alias, rpc_url::RpcUrl};
-use sui_sdk::types::{base_types::SuiAddress, error::SuiError};
+use sui_sdk::types::{
+    base_types::{ObjectID, SuiAddress},
+    error::SuiError,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -42,18 +45,131 @@ pub enum Error {
     #[error("Wallet with address {0} not found")]
     WalletAddressNotFound(SuiAddress),
 
+    #[error("Wallet {0} has no credentials. Watch-only wallets cannot sign transactions")]
+    WalletCredentialsNotFound(SuiAddress),
+
+    #[error("Wallets not found: {0}")]
+    WalletsNotFound(String),
+
+    #[error("No wallets selected. Specify aliases or addresses, --tags or --all")]
+    EmptyWalletSelection,
+
     #[error("Invalid Alias or Address: {0}")]
     InvalidAliasOrAddress(String),
 
+    #[error("Invalid alias pattern {0}")]
+    InvalidAliasPattern(String),
+
     #[error("Invalid Alias or URL: {0}")]
     InvalidAliasOrURL(String),
 
+    #[error(
+        "Invalid object filter: {0}. Expected <Package>, <Package>::<Module> or a struct type"
+    )]
+    InvalidObjectFilter(String),
+
+    #[error("Invalid metadata: {0}")]
+    InvalidMetadata(String),
+
+    #[error("Invalid tag filter: {0}")]
+    InvalidTagFilter(String),
+
     #[error("Config Error: {0}")]
     ConfigError(#[from] confy::ConfyError),
 
+    #[error("YAML Error: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+
+    #[error("SQLite Error: {0}")]
+    SqliteError(#[from] rusqlite::Error),
+
+    #[error("JSON Error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("IO Error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to decrypt backup: wrong passphrase or corrupted archive")]
+    BackupDecryptionFailed,
+
+    #[error("Backup is corrupted: {0}")]
+    BackupCorrupted(String),
+
+    #[error("Key pair of wallet {0} no longer derives its address. Nothing was changed")]
+    KeyRotationMismatch(SuiAddress),
+
+    #[error("Wallet store uses schema version {0}, but this version of sui_wallet only supports up to {1}. Upgrade sui_wallet to open it")]
+    SchemaTooNew(u32, u32),
+
+    #[error("Invalid schema version: {0}")]
+    InvalidSchemaVersion(String),
+
+    #[error("Wallet store was modified outside sui_wallet. Review the changes and rerun with --accept-changes to trust them")]
+    VaultTampered,
+
+    #[error("Wallet store was replaced with an older copy (write {0}, last seen {1}). Rerun with --accept-changes if you restored it on purpose")]
+    VaultRolledBack(u64, u64),
+
+    #[error("Wallet store is not signed. Rerun with --accept-changes to sign it with the current cipher key")]
+    VaultNotSigned,
+
+    #[error("Invalid profile {0}. Use lowercase letters, numbers or underscores")]
+    InvalidProfile(String),
+
+    #[error("Profile {0} already exists")]
+    ProfileAlreadyExists(String),
+
+    #[error("Profile {0} not found")]
+    ProfileNotFound(String),
+
+    #[error("Profile {0} is in use")]
+    ProfileInUse(String),
+
+    #[error("Storage Error: {0}")]
+    StorageError(String),
+
+    #[error("Storage {0} not found")]
+    StorageNotFound(String),
+
+    #[error("Storage {0} already exists. Pass --force to overwrite it")]
+    StorageAlreadyExists(String),
+
+    #[error("Contact @{0} already exists")]
+    ContactAlreadyExists(Alias),
+
+    #[error("Contact {0} not found")]
+    ContactNotFound(String),
+
+    #[error("@{0} is a contact, not a wallet")]
+    ContactIsNotAWallet(Alias),
+
+    #[error("Invalid SuiNS name: {0}")]
+    InvalidSuiNsName(String),
+
+    #[error("SuiNS name {0} is not registered")]
+    SuiNsNameNotFound(String),
+
+    #[error("SuiNS name {0} can't be used here; pass a wallet alias or address")]
+    SuiNsNotSupported(String),
+
+    #[error("SuiNS lookup failed: {0}")]
+    SuiNsLookupError(String),
+
+    #[error("Aborted")]
+    Aborted,
+
     #[error("Tag not found")]
     TagNotFound,
 
+    #[error("Tag {0} already exists")]
+    TagAlreadyExists(String),
+
+    #[error("Invalid tag rename: {0}")]
+    InvalidTagRename(String),
+
+    #[error("Invalid tag color: {0}. Expected red, green, yellow, blue, magenta, cyan or white")]
+    InvalidTagColor(String),
+
     #[error("Mnemonic Error: {0}")]
     MnemonicError(#[from] bip39::ErrorKind),
 
@@ -84,6 +200,54 @@ pub enum Error {
     #[error("Network Aliass {0} not found")]
     NetworkAliasNotFound(Alias),
 
+    #[error("Object {0} not found")]
+    ObjectNotFound(ObjectID),
+
+    #[error("Object {0} is not owned by any selected wallet")]
+    ObjectNotOwned(ObjectID),
+
+    #[error("No objects to transfer. Specify object ids or --filter")]
+    NoObjectsToTransfer,
+
+    #[error("Failed to build transaction: {0}")]
+    TransactionBuildError(String),
+
+    #[error("Invalid timestamp: {0}. Expected epoch milliseconds, RFC 3339 or YYYY-MM-DD")]
+    InvalidTimestamp(String),
+
+    #[error("Invalid SUI amount: {0}")]
+    InvalidSuiAmount(String),
+
+    #[error("Invalid address or name: {0}")]
+    InvalidAddressOrName(String),
+
+    #[error("Validator {0} not found in the active validator set")]
+    ValidatorNotFound(String),
+
+    #[error("Invalid Move function: {0}. Expected <Package>::<Module>::<Function>")]
+    InvalidMoveFunction(String),
+
+    #[error("Move function {0} not found")]
+    MoveFunctionNotFound(String),
+
+    #[error("Invalid type argument: {0}")]
+    InvalidTypeArgument(String),
+
+    #[error("Invalid argument {0} for parameter type {1}")]
+    InvalidMoveArgument(String, String),
+
+    #[error("Unsupported Move type: {0}")]
+    UnsupportedMoveType(String),
+
+    #[error("Expected {0} {2}, got {1}")]
+    MoveArgumentCountMismatch(usize, usize, String),
+
+    #[error("Dev inspect failed: {0}")]
+    DevInspectError(String),
+
+    #[error("BCS Error: {0}")]
+    BcsError(String),
+
     #[error("Reqwest Error: {0}")]
     ReqwestError(#[from] reqwest::Error),
 
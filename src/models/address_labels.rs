@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use sui_sdk::types::base_types::SuiAddress;
+
+/// Human readable names for addresses we know: wallet aliases and `@contacts`.
+#[derive(Debug, Clone, Default)]
+pub struct AddressLabels(HashMap<SuiAddress, String>);
+
+impl AddressLabels {
+    pub fn insert(&mut self, address: SuiAddress, label: String) {
+        self.0.insert(address, label);
+    }
+
+    /// Renders an address as `label (address)` when it is known.
+    pub fn get_label(&self, address: &SuiAddress) -> String {
+        match self.0.get(address) {
+            Some(label) => format!("{} ({})", label, address),
+            None => address.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddressLabels;
+    use sui_sdk::types::base_types::SuiAddress;
+
+    #[test]
+    fn test_get_label() {
+        let known = SuiAddress::random_for_testing_only();
+        let unknown = SuiAddress::random_for_testing_only();
+
+        let mut labels = AddressLabels::default();
+        labels.insert(known, "@exchange".to_string());
+
+        assert_eq!(labels.get_label(&known), format!("@exchange ({})", known));
+        assert_eq!(labels.get_label(&unknown), unknown.to_string());
+    }
+}
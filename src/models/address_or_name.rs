@@ -0,0 +1,85 @@
+use crate::error::Error;
+use std::{fmt, str::FromStr};
+use sui_sdk::types::base_types::SuiAddress;
+
+#[derive(Debug, Clone)]
+pub enum AddressOrName {
+    Address(SuiAddress),
+    Name(String),
+}
+
+impl AddressOrName {
+    pub fn matches(&self, address: &SuiAddress, name: &str) -> bool {
+        match self {
+            Self::Address(a) => a == address,
+            Self::Name(n) => n.eq_ignore_ascii_case(name),
+        }
+    }
+}
+
+impl fmt::Display for AddressOrName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{}", address),
+            Self::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl FromStr for AddressOrName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(address) = SuiAddress::from_str(s) {
+            return Ok(Self::Address(address));
+        }
+
+        if s.trim().is_empty() {
+            return Err(Error::InvalidAddressOrName(s.to_string()));
+        }
+
+        Ok(Self::Name(s.trim().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddressOrName;
+    use crate::error::Error;
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::SuiAddress;
+
+    #[test]
+    fn test_from_str() {
+        let address = SuiAddress::random_for_testing_only();
+
+        assert!(matches!(
+            AddressOrName::from_str(&address.to_string()),
+            Ok(AddressOrName::Address(a)) if a == address
+        ));
+        assert!(matches!(
+            AddressOrName::from_str("Mysten Labs"),
+            Ok(AddressOrName::Name(_))
+        ));
+        assert!(matches!(
+            AddressOrName::from_str(" "),
+            Err(Error::InvalidAddressOrName(_))
+        ));
+    }
+
+    #[test]
+    fn test_matches() {
+        let address = SuiAddress::random_for_testing_only();
+
+        assert!(AddressOrName::Address(address).matches(&address, "any"));
+        assert!(
+            !AddressOrName::Address(address).matches(&SuiAddress::random_for_testing_only(), "any")
+        );
+        assert!(AddressOrName::from_str("mysten labs")
+            .unwrap()
+            .matches(&address, "Mysten Labs"));
+        assert!(!AddressOrName::from_str("mysten")
+            .unwrap()
+            .matches(&address, "Mysten Labs"));
+    }
+}
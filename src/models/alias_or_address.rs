@@ -1,12 +1,27 @@
-use super::alias::Alias;
+use super::{alias::Alias, suins_name::SuiNsName};
 use crate::error::Error;
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 use sui_sdk::types::base_types::SuiAddress;
 
 #[derive(Debug, Clone)]
 pub enum AliasOrAddress {
     Address(SuiAddress),
     Alias(Alias),
+    /// `@name`, an entry of the address book rather than a wallet we hold.
+    Contact(Alias),
+    /// `name.sui`, resolved through the RPC in use.
+    SuiNs(SuiNsName),
+}
+
+impl fmt::Display for AliasOrAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{}", address),
+            Self::Alias(alias) => write!(f, "{}", alias),
+            Self::Contact(alias) => write!(f, "@{}", alias),
+            Self::SuiNs(name) => write!(f, "{}", name),
+        }
+    }
 }
 
 impl FromStr for AliasOrAddress {
@@ -17,6 +32,18 @@ impl FromStr for AliasOrAddress {
             return Ok(Self::Address(address));
         }
 
+        if let Some(name) = s.strip_prefix('@') {
+            return Alias::new(name)
+                .map(Self::Contact)
+                .map_err(|_| Error::InvalidAliasOrAddress(s.to_string()));
+        }
+
+        if s.contains('.') {
+            return SuiNsName::from_str(s)
+                .map(Self::SuiNs)
+                .map_err(|_| Error::InvalidAliasOrAddress(s.to_string()));
+        }
+
         if let Ok(alias) = Alias::new(s) {
             return Ok(Self::Alias(alias));
         }
@@ -43,6 +70,31 @@ mod tests {
             AliasOrAddress::from_str("test"),
             Ok(AliasOrAddress::Alias(_))
         ));
+        assert!(matches!(
+            AliasOrAddress::from_str("@exchange"),
+            Ok(AliasOrAddress::Contact(_))
+        ));
+        assert!(matches!(
+            AliasOrAddress::from_str("alice.sui"),
+            Ok(AliasOrAddress::SuiNs(_))
+        ));
+    }
+
+    #[test]
+    fn test_display() {
+        let address = "0x123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0";
+        assert_eq!(
+            AliasOrAddress::from_str(address).unwrap().to_string(),
+            address
+        );
+        assert_eq!(
+            AliasOrAddress::from_str("test").unwrap().to_string(),
+            "test"
+        );
+        assert_eq!(
+            AliasOrAddress::from_str("@exchange").unwrap().to_string(),
+            "@exchange"
+        );
     }
 
     #[test]
@@ -51,5 +103,13 @@ mod tests {
             AliasOrAddress::from_str("0x123@ "),
             Err(Error::InvalidAliasOrAddress(_))
         ));
+        assert!(matches!(
+            AliasOrAddress::from_str("@ex-change"),
+            Err(Error::InvalidAliasOrAddress(_))
+        ));
+        assert!(matches!(
+            AliasOrAddress::from_str("alice.eth"),
+            Err(Error::InvalidAliasOrAddress(_))
+        ));
     }
 }
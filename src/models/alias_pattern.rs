@@ -0,0 +1,87 @@
+use super::alias::Alias;
+use crate::error::{Error, Result};
+use regex::Regex;
+use std::str::FromStr;
+
+/// How `list --alias` matches: `/.../` is a regex, `*` and `?` make a glob
+/// matched against the whole alias, anything else is a substring.
+#[derive(Debug, Clone)]
+pub enum AliasPattern {
+    Contains(String),
+    Regex(Regex),
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut pattern = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            _ => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    pattern
+}
+
+impl AliasPattern {
+    pub fn matches(&self, alias: &Alias) -> bool {
+        match self {
+            Self::Contains(text) => alias.to_string().contains(text.as_str()),
+            Self::Regex(regex) => regex.is_match(&alias.to_string()),
+        }
+    }
+}
+
+impl FromStr for AliasPattern {
+    type Err = Error;
+
+    fn from_str(pattern: &str) -> Result<Self> {
+        let regex = if let Some(regex) = pattern
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+        {
+            regex.to_string()
+        } else if pattern.contains(['*', '?']) {
+            glob_to_regex(pattern)
+        } else {
+            return Ok(Self::Contains(pattern.to_string()));
+        };
+
+        Regex::new(&regex)
+            .map(Self::Regex)
+            .map_err(|e| Error::InvalidAliasPattern(format!("{}: {}", pattern, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AliasPattern;
+    use crate::{error::Error, models::alias::Alias};
+    use std::str::FromStr;
+
+    fn matches(pattern: &str, alias: &str) -> bool {
+        AliasPattern::from_str(pattern)
+            .unwrap()
+            .matches(&Alias::new(alias).unwrap())
+    }
+
+    #[test]
+    fn test_alias_pattern() {
+        assert!(matches("bot", "qa_bot_1"));
+        assert!(!matches("bot", "oracle"));
+
+        assert!(matches("qa_*", "qa_bot_1"));
+        assert!(!matches("qa_*", "prod_qa_bot"));
+        assert!(matches("bot_?", "bot_7"));
+        assert!(!matches("bot_?", "bot_17"));
+
+        assert!(matches("/^bot_[0-9]+$/", "bot_17"));
+        assert!(!matches("/^bot_[0-9]+$/", "bot_x"));
+
+        assert!(matches!(
+            AliasPattern::from_str("/bot_[/"),
+            Err(Error::InvalidAliasPattern(_))
+        ));
+    }
+}
@@ -1,11 +1,18 @@
 use super::cipher_key::CipherKey;
 use super::cipher_nonce::CipherNonce;
+use super::profile::Profile;
 use crate::error::{Error, Result};
 use chacha20poly1305::aead::{Aead, KeyInit};
 use chacha20poly1305::ChaCha20Poly1305;
+use hmac::{Hmac, Mac};
 use serde::Deserialize;
+use sha2::Sha256;
 use std::fmt;
 
+type HmacSha256 = Hmac<Sha256>;
+
+const MAC_KEY_CONTEXT: &[u8] = b"sui_wallet vault mac";
+
 pub trait RandomlyGenerable {
     fn generate_random() -> Self;
 }
@@ -32,9 +39,27 @@ impl fmt::Display for Cipher {
     }
 }
 
+impl RandomlyGenerable for Cipher {
+    fn generate_random() -> Self {
+        let parameter = CipherParameter::default();
+
+        Self {
+            context: Some(ChaCha20Poly1305::new(
+                parameter.cipher_key.get_chacha20poly1305(),
+            )),
+            parameter,
+        }
+    }
+}
+
 impl Cipher {
+    /// Loads the cipher of the active profile.
     pub fn load_from_env() -> Result<Self> {
-        let parameter = envy::from_env::<CipherParameter>()?;
+        Self::load_for_profile(&Profile::from_env()?)
+    }
+
+    pub fn load_for_profile(profile: &Profile) -> Result<Self> {
+        let parameter = envy::prefixed(profile.get_env_prefix()).from_env::<CipherParameter>()?;
 
         Ok(Self {
             context: Some(ChaCha20Poly1305::new(
@@ -44,6 +69,20 @@ impl Cipher {
         })
     }
 
+    /// `CIPHER_KEY` and `CIPHER_NONCE` entries, with a profile's `prefix`.
+    pub fn to_env_entries(&self, prefix: &str) -> [(String, String); 2] {
+        [
+            (
+                format!("{}CIPHER_KEY", prefix),
+                self.parameter.cipher_key.to_string(),
+            ),
+            (
+                format!("{}CIPHER_NONCE", prefix),
+                self.parameter.cipher_nonce.to_string(),
+            ),
+        ]
+    }
+
     pub fn encrypt(&self, target: Vec<u8>) -> Result<Vec<u8>> {
         self.context
             .clone()
@@ -65,11 +104,42 @@ impl Cipher {
             )
             .map_err(Error::CipherCryptoError)
     }
+
+    pub fn mac(&self, target: &[u8]) -> Result<Vec<u8>> {
+        let mut mac = self.mac_context()?;
+        mac.update(target);
+
+        Ok(mac.finalize().into_bytes().to_vec())
+    }
+
+    pub fn verify_mac(&self, target: &[u8], tag: &[u8]) -> Result<bool> {
+        let mut mac = self.mac_context()?;
+        mac.update(target);
+
+        Ok(mac.verify_slice(tag).is_ok())
+    }
+
+    /// HMAC-SHA256 under a key derived from the cipher key, so the key
+    /// itself is only ever used for encryption.
+    fn mac_context(&self) -> Result<HmacSha256> {
+        if self.context.is_none() {
+            return Err(Error::CipherKeyAndNonceNotFound);
+        }
+
+        let mut derive = <HmacSha256 as Mac>::new_from_slice(
+            self.parameter.cipher_key.get_chacha20poly1305().as_slice(),
+        )
+        .map_err(|e| Error::CipherError(e.to_string()))?;
+        derive.update(MAC_KEY_CONTEXT);
+
+        <HmacSha256 as Mac>::new_from_slice(&derive.finalize().into_bytes())
+            .map_err(|e| Error::CipherError(e.to_string()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Cipher;
+    use super::{Cipher, RandomlyGenerable};
     use crate::error::Error;
 
     #[test]
@@ -100,6 +170,36 @@ mod tests {
         assert_eq!(decrypted, original_data);
     }
 
+    #[test]
+    fn test_generate_random() {
+        let cipher = Cipher::generate_random();
+        let other = Cipher::generate_random();
+
+        let encrypted = cipher.encrypt(b"Hello, World!".to_vec()).unwrap();
+        assert_eq!(cipher.decrypt(encrypted.clone()).unwrap(), b"Hello, World!");
+        assert!(other.decrypt(encrypted).is_err());
+
+        let [(key_name, key), (nonce_name, nonce)] = cipher.to_env_entries("WORK_");
+        assert_eq!(key_name, "WORK_CIPHER_KEY");
+        assert_eq!(nonce_name, "WORK_CIPHER_NONCE");
+        assert_eq!(format!("{}\n{}", key, nonce), cipher.to_string());
+    }
+
+    #[test]
+    fn test_mac() {
+        let cipher = Cipher::generate_random();
+        let other = Cipher::generate_random();
+
+        let tag = cipher.mac(b"Hello, World!").unwrap();
+        assert_eq!(tag.len(), 32);
+        assert_eq!(tag, cipher.mac(b"Hello, World!").unwrap());
+
+        assert!(cipher.verify_mac(b"Hello, World!", &tag).unwrap());
+        assert!(!cipher.verify_mac(b"Hello, World?", &tag).unwrap());
+        assert!(!other.verify_mac(b"Hello, World!", &tag).unwrap());
+        assert!(!cipher.verify_mac(b"Hello, World!", &tag[..16]).unwrap());
+    }
+
     #[test]
     fn test_key_and_nonce_not_found() {
         let cipher = Cipher::default();
@@ -112,5 +212,9 @@ mod tests {
             cipher.decrypt(b"Hello, World!".to_vec()),
             Err(Error::CipherKeyAndNonceNotFound)
         ));
+        assert!(matches!(
+            cipher.mac(b"Hello, World!"),
+            Err(Error::CipherKeyAndNonceNotFound)
+        ));
     }
 }
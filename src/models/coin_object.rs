@@ -16,6 +16,10 @@ impl CoinObject {
         &self.1
     }
 
+    pub fn get_total_balance(&self) -> u64 {
+        self.1.iter().map(|object| object.balance).sum()
+    }
+
     pub fn add_object(&mut self, coin: Coin) {
         self.1.push(coin);
     }
@@ -54,6 +58,7 @@ mod tests {
         assert_eq!(coin_object.get_objects(), &vec![]);
         assert_eq!(coin_object.len(), 0);
         assert!(coin_object.is_empty());
+        assert_eq!(coin_object.get_total_balance(), 0);
 
         let dummy_coin = Coin {
             coin_type: "dummy".to_string(),
@@ -70,5 +75,6 @@ mod tests {
         assert_eq!(coin_object.get_objects(), &vec![dummy_coin]);
         assert_eq!(coin_object.len(), 1);
         assert!(!coin_object.is_empty());
+        assert_eq!(coin_object.get_total_balance(), 100);
     }
 }
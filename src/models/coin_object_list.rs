@@ -20,6 +20,14 @@ impl CoinObjectList {
         }
     }
 
+    pub fn extend(&mut self, coins: &Self) {
+        for coin_object in coins.0.values() {
+            for coin in coin_object.get_objects().iter() {
+                self.entry(coin.clone(), coin_object.get_metadata().clone());
+            }
+        }
+    }
+
     pub fn get(&self, coin_type: &str) -> Option<&CoinObject> {
         self.0.get(coin_type)
     }
@@ -70,5 +78,13 @@ mod tests {
         assert_eq!(list.get_coin_objects().len(), 1);
         assert!(list.get("dummy").is_some());
         assert!(list.get("dummy_not_found").is_none());
+
+        let mut merged = CoinObjectList::default();
+        merged.extend(&list);
+        merged.extend(&list);
+
+        assert_eq!(merged.get_coin_objects().len(), 1);
+        assert_eq!(merged.get("dummy").unwrap().len(), 2);
+        assert_eq!(merged.get("dummy").unwrap().get_total_balance(), 200);
     }
 }
@@ -0,0 +1,82 @@
+use super::{alias::Alias, tag_list::TagList};
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use sui_sdk::types::base_types::SuiAddress;
+
+/// An external address we send to but hold no keys for.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Contact {
+    address: SuiAddress,
+    alias: Alias,
+    #[serde(default)]
+    tags: TagList,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+/// Contact names are written `@name` on the command line; the `@` is optional
+/// where only a contact can be meant.
+pub fn parse_contact_alias(s: &str) -> Result<Alias> {
+    Alias::new(s.strip_prefix('@').unwrap_or(s))
+}
+
+impl Contact {
+    pub fn new(address: SuiAddress, alias: Alias, tags: TagList, note: Option<String>) -> Self {
+        Self {
+            address,
+            alias,
+            tags,
+            note,
+        }
+    }
+
+    pub fn get_address(&self) -> &SuiAddress {
+        &self.address
+    }
+
+    pub fn mut_address(&mut self) -> &mut SuiAddress {
+        &mut self.address
+    }
+
+    pub fn get_alias(&self) -> &Alias {
+        &self.alias
+    }
+
+    pub fn mut_alias(&mut self) -> &mut Alias {
+        &mut self.alias
+    }
+
+    pub fn get_tags(&self) -> &TagList {
+        &self.tags
+    }
+
+    pub fn mut_tags(&mut self) -> &mut TagList {
+        &mut self.tags
+    }
+
+    pub fn get_note(&self) -> &Option<String> {
+        &self.note
+    }
+
+    pub fn mut_note(&mut self) -> &mut Option<String> {
+        &mut self.note
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_contact_alias;
+
+    #[test]
+    fn test_parse_contact_alias() {
+        assert_eq!(
+            parse_contact_alias("@binance").unwrap().to_string(),
+            "binance"
+        );
+        assert_eq!(
+            parse_contact_alias("binance").unwrap().to_string(),
+            "binance"
+        );
+        assert!(parse_contact_alias("@bin@ance").is_err());
+    }
+}
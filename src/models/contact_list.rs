@@ -0,0 +1,193 @@
+use super::{alias::Alias, alias_or_address::AliasOrAddress, contact::Contact, tag_list::TagList};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use sui_sdk::types::base_types::SuiAddress;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ContactList(HashMap<Alias, Contact>);
+
+impl ContactList {
+    /// Looks a contact up by name (with or without `@`) or by address.
+    pub fn get(&self, alias_or_address: &AliasOrAddress) -> Result<&Contact> {
+        match alias_or_address {
+            AliasOrAddress::Alias(alias) | AliasOrAddress::Contact(alias) => self.0.get(alias),
+            AliasOrAddress::Address(address) => self.get_by_address(address),
+            AliasOrAddress::SuiNs(_) => None,
+        }
+        .ok_or(Error::ContactNotFound(alias_or_address.to_string()))
+    }
+
+    pub fn get_by_address(&self, address: &SuiAddress) -> Option<&Contact> {
+        self.0
+            .values()
+            .find(|contact| contact.get_address() == address)
+    }
+
+    pub fn add(&mut self, contact: Contact) -> Result<()> {
+        if self.0.contains_key(contact.get_alias()) {
+            return Err(Error::ContactAlreadyExists(contact.get_alias().clone()));
+        }
+
+        self.0.insert(contact.get_alias().clone(), contact);
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, alias_or_address: &AliasOrAddress) -> Result<Contact> {
+        let alias = self.get(alias_or_address)?.get_alias().clone();
+
+        Ok(self.0.remove(&alias).unwrap())
+    }
+
+    pub fn edit(
+        &mut self,
+        alias_or_address: &AliasOrAddress,
+        address: Option<SuiAddress>,
+        alias: Option<Alias>,
+        tags: Option<TagList>,
+        note: Option<String>,
+    ) -> Result<()> {
+        let mut contact = self.get(alias_or_address)?.clone();
+
+        if let Some(alias) = alias {
+            if &alias != contact.get_alias() && self.0.contains_key(&alias) {
+                return Err(Error::ContactAlreadyExists(alias));
+            }
+            self.0.remove(contact.get_alias());
+            *contact.mut_alias() = alias;
+        }
+
+        if let Some(address) = address {
+            *contact.mut_address() = address;
+        }
+
+        if let Some(tags) = tags {
+            *contact.mut_tags() = tags;
+        }
+
+        if let Some(note) = note {
+            *contact.mut_note() = if note.is_empty() { None } else { Some(note) };
+        }
+
+        self.0.insert(contact.get_alias().clone(), contact);
+
+        Ok(())
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Contact> {
+        self.0.values_mut()
+    }
+
+    pub fn get_contacts(&self) -> Vec<Contact> {
+        self.0.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContactList;
+    use crate::{
+        error::Error,
+        models::{
+            alias::Alias, alias_or_address::AliasOrAddress, contact::Contact, tag_list::TagList,
+        },
+    };
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::SuiAddress;
+
+    fn contact(alias: &str) -> Contact {
+        Contact::new(
+            SuiAddress::random_for_testing_only(),
+            Alias::new(alias).unwrap(),
+            TagList::default(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_add_get_remove() {
+        let mut list = ContactList::default();
+        let exchange = contact("exchange");
+        let address = *exchange.get_address();
+
+        list.add(exchange).unwrap();
+        assert!(matches!(
+            list.add(contact("exchange")),
+            Err(Error::ContactAlreadyExists(_))
+        ));
+
+        for key in ["@exchange", "exchange"] {
+            assert_eq!(
+                list.get(&AliasOrAddress::from_str(key).unwrap())
+                    .unwrap()
+                    .get_address(),
+                &address
+            );
+        }
+        assert!(list.get(&AliasOrAddress::Address(address)).is_ok());
+        assert!(list.get_by_address(&address).is_some());
+        assert!(matches!(
+            list.get(&AliasOrAddress::from_str("@partner").unwrap()),
+            Err(Error::ContactNotFound(_))
+        ));
+
+        list.remove(&AliasOrAddress::Address(address)).unwrap();
+        assert!(list.get_contacts().is_empty());
+    }
+
+    #[test]
+    fn test_edit() {
+        let mut list = ContactList::default();
+        list.add(contact("exchange")).unwrap();
+        list.add(contact("partner")).unwrap();
+
+        let new_address = SuiAddress::random_for_testing_only();
+        list.edit(
+            &AliasOrAddress::from_str("@exchange").unwrap(),
+            Some(new_address),
+            Some(Alias::new("cex").unwrap()),
+            Some(TagList::from_str("deposit").unwrap()),
+            Some("memo required".to_string()),
+        )
+        .unwrap();
+
+        let edited = list
+            .get(&AliasOrAddress::from_str("@cex").unwrap())
+            .unwrap();
+        assert_eq!(edited.get_address(), &new_address);
+        assert_eq!(edited.get_note(), &Some("memo required".to_string()));
+        assert!(edited
+            .get_tags()
+            .contains_all(&TagList::from_str("deposit").unwrap()));
+        assert!(list
+            .get(&AliasOrAddress::from_str("@exchange").unwrap())
+            .is_err());
+
+        assert!(matches!(
+            list.edit(
+                &AliasOrAddress::from_str("@cex").unwrap(),
+                None,
+                Some(Alias::new("partner").unwrap()),
+                None,
+                None,
+            ),
+            Err(Error::ContactAlreadyExists(_))
+        ));
+
+        list.edit(
+            &AliasOrAddress::from_str("@cex").unwrap(),
+            None,
+            None,
+            None,
+            Some(String::new()),
+        )
+        .unwrap();
+        assert_eq!(
+            list.get(&AliasOrAddress::from_str("@cex").unwrap())
+                .unwrap()
+                .get_note(),
+            &None
+        );
+    }
+}
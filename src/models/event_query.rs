@@ -0,0 +1,228 @@
+use super::timestamp::Timestamp;
+use move_core_types::{identifier::Identifier, language_storage::StructTag};
+use sui_sdk::{
+    rpc_types::{EventFilter, SuiEvent},
+    types::base_types::{ObjectID, SuiAddress},
+};
+
+/// Event filters given on the command line. The node only accepts a single
+/// filter per query, so the most selective one is sent and the rest are
+/// applied to the returned events.
+#[derive(Debug, Clone, Default)]
+pub struct EventQuery {
+    event_type: Option<StructTag>,
+    package: Option<ObjectID>,
+    module: Option<Identifier>,
+    senders: Vec<SuiAddress>,
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+}
+
+impl EventQuery {
+    pub fn new(
+        event_type: Option<StructTag>,
+        package: Option<ObjectID>,
+        module: Option<Identifier>,
+        senders: Vec<SuiAddress>,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+    ) -> Self {
+        Self {
+            event_type,
+            package,
+            module,
+            senders,
+            since,
+            until,
+        }
+    }
+
+    pub fn to_event_filter(&self) -> EventFilter {
+        if let Some(ref event_type) = self.event_type {
+            return EventFilter::MoveEventType(event_type.clone());
+        }
+
+        match (self.package, &self.module) {
+            (Some(package), Some(module)) => {
+                return EventFilter::MoveModule {
+                    package,
+                    module: module.clone(),
+                }
+            }
+            (Some(package), None) => return EventFilter::Package(package),
+            _ => {}
+        }
+
+        if let [sender] = self.senders.as_slice() {
+            return EventFilter::Sender(*sender);
+        }
+
+        if self.since.is_some() || self.until.is_some() {
+            return EventFilter::TimeRange {
+                start_time: self.since.map_or(0, |since| since.get_millis()),
+                end_time: self.until.map_or(u64::MAX, |until| until.get_millis()),
+            };
+        }
+
+        EventFilter::All(vec![])
+    }
+
+    pub fn matches(&self, event: &SuiEvent) -> bool {
+        if let Some(ref event_type) = self.event_type {
+            if !matches_type(event_type, &event.type_) {
+                return false;
+            }
+        }
+
+        if let Some(package) = self.package {
+            if event.package_id != package {
+                return false;
+            }
+        }
+
+        if let Some(ref module) = self.module {
+            if &event.transaction_module != module {
+                return false;
+            }
+        }
+
+        if !self.senders.is_empty() && !self.senders.contains(&event.sender) {
+            return false;
+        }
+
+        match event.timestamp_ms {
+            Some(timestamp_ms) => {
+                self.since
+                    .map_or(true, |since| since.get_millis() <= timestamp_ms)
+                    && self
+                        .until
+                        .map_or(true, |until| timestamp_ms < until.get_millis())
+            }
+            None => self.since.is_none() && self.until.is_none(),
+        }
+    }
+
+    /// Whether the event is older than `--since`. Used to stop paging backwards.
+    pub fn is_before_range(&self, event: &SuiEvent) -> bool {
+        match (self.since, event.timestamp_ms) {
+            (Some(since), Some(timestamp_ms)) => timestamp_ms < since.get_millis(),
+            _ => false,
+        }
+    }
+}
+
+/// A type without type parameters matches every instantiation of it.
+fn matches_type(expected: &StructTag, actual: &StructTag) -> bool {
+    expected.address == actual.address
+        && expected.module == actual.module
+        && expected.name == actual.name
+        && (expected.type_params.is_empty() || expected.type_params == actual.type_params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventQuery;
+    use crate::models::timestamp::Timestamp;
+    use move_core_types::identifier::Identifier;
+    use std::str::FromStr;
+    use sui_sdk::{
+        rpc_types::{EventFilter, SuiEvent},
+        types::{
+            base_types::{ObjectID, SuiAddress},
+            parse_sui_struct_tag,
+        },
+    };
+
+    fn event(event_type: &str, sender: SuiAddress, timestamp_ms: Option<u64>) -> SuiEvent {
+        let mut event = SuiEvent::random_for_testing();
+        event.type_ = parse_sui_struct_tag(event_type).unwrap();
+        event.package_id = ObjectID::from(event.type_.address);
+        event.transaction_module = event.type_.module.clone();
+        event.sender = sender;
+        event.timestamp_ms = timestamp_ms;
+        event
+    }
+
+    #[test]
+    fn test_to_event_filter() {
+        let sender = SuiAddress::random_for_testing_only();
+        let package = ObjectID::from_str("0x2").unwrap();
+
+        let query = EventQuery::new(
+            Some(parse_sui_struct_tag("0x2::coin::CurrencyCreated").unwrap()),
+            Some(package),
+            None,
+            vec![sender],
+            None,
+            None,
+        );
+        assert!(matches!(
+            query.to_event_filter(),
+            EventFilter::MoveEventType(_)
+        ));
+
+        let query = EventQuery::new(
+            None,
+            Some(package),
+            Some(Identifier::new("coin").unwrap()),
+            vec![sender],
+            None,
+            None,
+        );
+        assert!(matches!(
+            query.to_event_filter(),
+            EventFilter::MoveModule { .. }
+        ));
+
+        let query = EventQuery::new(None, None, None, vec![sender], None, None);
+        assert!(matches!(query.to_event_filter(), EventFilter::Sender(s) if s == sender));
+
+        let query = EventQuery::new(
+            None,
+            None,
+            None,
+            vec![sender, SuiAddress::random_for_testing_only()],
+            Some(Timestamp::from_millis(10)),
+            None,
+        );
+        assert!(matches!(
+            query.to_event_filter(),
+            EventFilter::TimeRange {
+                start_time: 10,
+                end_time: u64::MAX
+            }
+        ));
+
+        let query = EventQuery::default();
+        assert!(matches!(query.to_event_filter(), EventFilter::All(filters) if filters.is_empty()));
+    }
+
+    #[test]
+    fn test_matches() {
+        let sender = SuiAddress::random_for_testing_only();
+        let other = SuiAddress::random_for_testing_only();
+
+        let query = EventQuery::new(
+            Some(parse_sui_struct_tag("0x2::coin::Minted").unwrap()),
+            None,
+            None,
+            vec![sender],
+            Some(Timestamp::from_millis(100)),
+            Some(Timestamp::from_millis(200)),
+        );
+
+        assert!(query.matches(&event(
+            "0x2::coin::Minted<0x2::sui::SUI>",
+            sender,
+            Some(100)
+        )));
+        assert!(!query.matches(&event("0x2::coin::Burned", sender, Some(150))));
+        assert!(!query.matches(&event("0x2::coin::Minted", other, Some(150))));
+        assert!(!query.matches(&event("0x2::coin::Minted", sender, Some(200))));
+        assert!(!query.matches(&event("0x2::coin::Minted", sender, None)));
+
+        assert!(query.is_before_range(&event("0x2::coin::Minted", sender, Some(99))));
+        assert!(!query.is_before_range(&event("0x2::coin::Minted", sender, Some(100))));
+        assert!(EventQuery::default().matches(&event("0x2::coin::Minted", other, None)));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::error::Error;
+
+/// An entry from another vault that was skipped because it clashes with an
+/// existing one.
+#[derive(Debug, Clone)]
+pub struct MergeConflict {
+    kind: &'static str,
+    item: String,
+    reason: String,
+}
+
+impl MergeConflict {
+    pub fn new(kind: &'static str, item: String, error: Error) -> Self {
+        Self {
+            kind,
+            item,
+            reason: error.to_string(),
+        }
+    }
+
+    pub fn get_kind(&self) -> &'static str {
+        self.kind
+    }
+
+    pub fn get_item(&self) -> &String {
+        &self.item
+    }
+
+    pub fn get_reason(&self) -> &String {
+        &self.reason
+    }
+}
@@ -0,0 +1,64 @@
+use super::wallet_metadata::{parse_metadata_key, WalletMetadata};
+use crate::error::{Error, Result};
+use std::str::FromStr;
+
+/// `key` matches wallets that have the key at all, `key=value` only an exact value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataFilter {
+    key: String,
+    value: Option<String>,
+}
+
+impl MetadataFilter {
+    pub fn matches(&self, metadata: &WalletMetadata) -> bool {
+        match (metadata.get(&self.key), &self.value) {
+            (Some(actual), Some(expected)) => actual == expected,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+impl FromStr for MetadataFilter {
+    type Err = Error;
+
+    fn from_str(filter: &str) -> Result<Self> {
+        Ok(match filter.split_once('=') {
+            Some((key, value)) => Self {
+                key: parse_metadata_key(key)?,
+                value: Some(value.to_string()),
+            },
+            None => Self {
+                key: parse_metadata_key(filter)?,
+                value: None,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MetadataFilter;
+    use crate::models::wallet_metadata::WalletMetadata;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_metadata_filter() {
+        let mut metadata = WalletMetadata::default();
+        metadata.insert("owner".to_string(), "ops".to_string());
+
+        assert!(MetadataFilter::from_str("owner")
+            .unwrap()
+            .matches(&metadata));
+        assert!(MetadataFilter::from_str("owner=ops")
+            .unwrap()
+            .matches(&metadata));
+        assert!(!MetadataFilter::from_str("owner=dev")
+            .unwrap()
+            .matches(&metadata));
+        assert!(!MetadataFilter::from_str("purpose")
+            .unwrap()
+            .matches(&metadata));
+        assert!(MetadataFilter::from_str("Owner").is_err());
+    }
+}
@@ -1,18 +1,51 @@
+pub mod address_labels;
+pub mod address_or_name;
 pub mod alias;
 pub mod alias_or_address;
 pub mod alias_or_url;
+pub mod alias_pattern;
 pub mod cipher;
 pub mod cipher_key;
 pub mod cipher_nonce;
 pub mod coin_object;
 pub mod coin_object_list;
+pub mod contact;
+pub mod contact_list;
+pub mod event_query;
+pub mod merge_conflict;
+pub mod metadata_filter;
+pub mod move_argument;
+pub mod move_function_id;
+pub mod move_struct_layouts;
+pub mod move_type;
+pub mod move_value_decoder;
 pub mod network_env;
+pub mod object_filter;
+pub mod profile;
 pub mod rpc_server;
 pub mod rpc_server_list;
 pub mod rpc_url;
+pub mod schema_migration;
+pub mod sui_amount;
+pub mod suins_cache;
+pub mod suins_name;
 pub mod tag;
+pub mod tag_color;
+pub mod tag_details;
+pub mod tag_filter;
 pub mod tag_list;
+pub mod timestamp;
+pub mod type_argument;
+pub mod upgrade_cap;
+pub mod vault_archive;
+pub mod vault_backup;
+pub mod vault_mac;
 pub mod wallet;
+pub mod wallet_coin_object_list;
 pub mod wallet_confy;
 pub mod wallet_credential;
+pub mod wallet_edit;
 pub mod wallet_list;
+pub mod wallet_metadata;
+pub mod wallet_secret;
+pub mod wallet_selection;
@@ -0,0 +1,293 @@
+use super::move_type::MoveType;
+use crate::error::{Error, Result};
+use move_core_types::u256::U256;
+use serde::Serialize;
+use std::str::FromStr;
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MoveArgument {
+    Pure(Vec<u8>),
+    Object(ObjectID),
+}
+
+impl MoveArgument {
+    /// Parses a command line argument against the parameter type it will be
+    /// passed as. `resolve_address` maps wallet aliases and `@contacts` to addresses.
+    pub fn parse<F>(raw: &str, move_type: &MoveType, resolve_address: &F) -> Result<Self>
+    where
+        F: Fn(&str) -> Option<SuiAddress>,
+    {
+        match move_type {
+            MoveType::Reference(inner) | MoveType::MutableReference(inner) => {
+                Self::parse_object(raw, inner)
+            }
+            MoveType::Struct { .. } if !is_pure_struct(move_type) => {
+                Self::parse_object(raw, move_type)
+            }
+            _ => Ok(Self::Pure(encode_pure(raw, move_type, resolve_address)?)),
+        }
+    }
+
+    fn parse_object(raw: &str, move_type: &MoveType) -> Result<Self> {
+        ObjectID::from_str(raw)
+            .map(Self::Object)
+            .map_err(|_| Error::InvalidMoveArgument(raw.to_string(), move_type.to_string()))
+    }
+}
+
+fn is_pure_struct(move_type: &MoveType) -> bool {
+    move_type.is_struct(1, "string", "String")
+        || move_type.is_struct(1, "ascii", "String")
+        || move_type.is_struct(1, "option", "Option")
+        || move_type.is_struct(2, "object", "ID")
+}
+
+fn to_bcs<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    bcs::to_bytes(value).map_err(|e| Error::BcsError(e.to_string()))
+}
+
+fn uleb128(mut value: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn unquote(raw: &str) -> &str {
+    raw.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(raw)
+}
+
+/// Splits `[a, [b, c], "d,e"]` (brackets optional) into its top-level elements.
+fn split_elements(raw: &str) -> Vec<String> {
+    let raw = raw.trim();
+    let inner = raw
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(raw);
+
+    let mut elements = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quoted = false;
+
+    for c in inner.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '[' if !quoted => depth += 1,
+            ']' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                elements.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() || !elements.is_empty() {
+        elements.push(current.trim().to_string());
+    }
+
+    elements
+}
+
+fn encode_pure<F>(raw: &str, move_type: &MoveType, resolve_address: &F) -> Result<Vec<u8>>
+where
+    F: Fn(&str) -> Option<SuiAddress>,
+{
+    let invalid = || Error::InvalidMoveArgument(raw.to_string(), move_type.to_string());
+    let raw = raw.trim();
+
+    match move_type {
+        MoveType::Bool => to_bcs(&bool::from_str(raw).map_err(|_| invalid())?),
+        MoveType::U8 => to_bcs(&u8::from_str(raw).map_err(|_| invalid())?),
+        MoveType::U16 => to_bcs(&u16::from_str(raw).map_err(|_| invalid())?),
+        MoveType::U32 => to_bcs(&u32::from_str(raw).map_err(|_| invalid())?),
+        MoveType::U64 => to_bcs(&u64::from_str(raw).map_err(|_| invalid())?),
+        MoveType::U128 => to_bcs(&u128::from_str(raw).map_err(|_| invalid())?),
+        MoveType::U256 => to_bcs(&U256::from_str(raw).map_err(|_| invalid())?),
+        MoveType::Address => to_bcs(
+            &SuiAddress::from_str(raw)
+                .ok()
+                .or_else(|| resolve_address(raw))
+                .ok_or_else(invalid)?,
+        ),
+        MoveType::Vector(inner) if **inner == MoveType::U8 && raw.starts_with("0x") => {
+            to_bcs(&hex::decode(&raw[2..]).map_err(|_| invalid())?)
+        }
+        MoveType::Vector(inner) => {
+            let elements = split_elements(raw);
+            let mut bytes = uleb128(elements.len());
+            for element in elements.iter() {
+                bytes.extend(encode_pure(element, inner, resolve_address)?);
+            }
+            Ok(bytes)
+        }
+        MoveType::Struct { .. }
+            if move_type.is_struct(1, "string", "String")
+                || move_type.is_struct(1, "ascii", "String") =>
+        {
+            to_bcs(&unquote(raw).to_string())
+        }
+        MoveType::Struct { .. } if move_type.is_struct(2, "object", "ID") => {
+            to_bcs(&ObjectID::from_str(raw).map_err(|_| invalid())?)
+        }
+        MoveType::Struct { type_arguments, .. } if move_type.is_struct(1, "option", "Option") => {
+            if raw == "none" {
+                Ok(vec![0])
+            } else {
+                let inner = type_arguments.first().ok_or_else(invalid)?;
+                let mut bytes = vec![1];
+                bytes.extend(encode_pure(raw, inner, resolve_address)?);
+                Ok(bytes)
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoveArgument;
+    use crate::{error::Error, models::move_type::MoveType};
+    use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+
+    fn std_struct(address: &str, module: &str, name: &str, args: Vec<MoveType>) -> MoveType {
+        MoveType::Struct {
+            address: address.to_string(),
+            module: module.to_string(),
+            name: name.to_string(),
+            type_arguments: args,
+        }
+    }
+
+    fn no_alias(_: &str) -> Option<SuiAddress> {
+        None
+    }
+
+    #[test]
+    fn test_parse_primitives() {
+        assert_eq!(
+            MoveArgument::parse("true", &MoveType::Bool, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![1])
+        );
+        assert_eq!(
+            MoveArgument::parse("258", &MoveType::U16, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![2, 1])
+        );
+        assert_eq!(
+            MoveArgument::parse("1", &MoveType::U64, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![1, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert!(matches!(
+            MoveArgument::parse("256", &MoveType::U8, &no_alias),
+            Err(Error::InvalidMoveArgument(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_vectors() {
+        let vector_u8 = MoveType::Vector(Box::new(MoveType::U8));
+        assert_eq!(
+            MoveArgument::parse("0x0102", &vector_u8, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![2, 1, 2])
+        );
+        assert_eq!(
+            MoveArgument::parse("[1, 2, 3]", &vector_u8, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![3, 1, 2, 3])
+        );
+        assert_eq!(
+            MoveArgument::parse("[]", &vector_u8, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![0])
+        );
+
+        let nested = MoveType::Vector(Box::new(vector_u8));
+        assert_eq!(
+            MoveArgument::parse("[[1],[2,3]]", &nested, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![2, 1, 1, 2, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_parse_structs() {
+        let string = std_struct("0x1", "string", "String", vec![]);
+        assert_eq!(
+            MoveArgument::parse("\"hi\"", &string, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![2, b'h', b'i'])
+        );
+
+        let option = std_struct("0x1", "option", "Option", vec![MoveType::U8]);
+        assert_eq!(
+            MoveArgument::parse("none", &option, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![0])
+        );
+        assert_eq!(
+            MoveArgument::parse("7", &option, &no_alias).unwrap(),
+            MoveArgument::Pure(vec![1, 7])
+        );
+
+        let object_id = ObjectID::random();
+        let coin = std_struct("0x2", "coin", "Coin", vec![MoveType::TypeParameter(0)]);
+        assert_eq!(
+            MoveArgument::parse(&object_id.to_string(), &coin, &no_alias).unwrap(),
+            MoveArgument::Object(object_id)
+        );
+        assert_eq!(
+            MoveArgument::parse(
+                &object_id.to_string(),
+                &MoveType::MutableReference(Box::new(coin.clone())),
+                &no_alias
+            )
+            .unwrap(),
+            MoveArgument::Object(object_id)
+        );
+        assert!(MoveArgument::parse("alice", &coin, &no_alias).is_err());
+    }
+
+    #[test]
+    fn test_parse_generic_parameters() {
+        let coin = std_struct(
+            "0x2",
+            "coin",
+            "Coin",
+            vec![std_struct("0x2", "sui", "SUI", vec![])],
+        );
+        let object_id = ObjectID::random();
+
+        // public_transfer<T>(obj: T, recipient: address) with T = Coin<SUI>
+        let parameter = MoveType::TypeParameter(0).substitute(&[coin]);
+        assert_eq!(
+            MoveArgument::parse(&object_id.to_string(), &parameter, &no_alias).unwrap(),
+            MoveArgument::Object(object_id)
+        );
+
+        let vector = MoveType::Vector(Box::new(MoveType::TypeParameter(0)));
+        assert_eq!(
+            MoveArgument::parse("[1, 2]", &vector.substitute(&[MoveType::U8]), &no_alias).unwrap(),
+            MoveArgument::Pure(vec![2, 1, 2])
+        );
+        assert!(MoveArgument::parse("[1, 2]", &vector, &no_alias).is_err());
+    }
+
+    #[test]
+    fn test_parse_address_alias() {
+        let address = SuiAddress::random_for_testing_only();
+        let resolve = |raw: &str| (raw == "alice").then_some(address);
+
+        assert_eq!(
+            MoveArgument::parse("alice", &MoveType::Address, &resolve).unwrap(),
+            MoveArgument::Pure(address.to_vec())
+        );
+        assert!(MoveArgument::parse("bob", &MoveType::Address, &resolve).is_err());
+    }
+}
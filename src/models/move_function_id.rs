@@ -0,0 +1,86 @@
+use crate::error::Error;
+use move_core_types::identifier::Identifier;
+use std::{fmt, str::FromStr};
+use sui_sdk::types::base_types::ObjectID;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveFunctionId {
+    package: ObjectID,
+    module: Identifier,
+    function: Identifier,
+}
+
+impl MoveFunctionId {
+    pub fn get_package(&self) -> &ObjectID {
+        &self.package
+    }
+
+    pub fn get_module(&self) -> &Identifier {
+        &self.module
+    }
+
+    pub fn get_function(&self) -> &Identifier {
+        &self.function
+    }
+}
+
+impl fmt::Display for MoveFunctionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}::{}", self.package, self.module, self.function)
+    }
+}
+
+impl FromStr for MoveFunctionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidMoveFunction(s.to_string());
+
+        match s.split("::").collect::<Vec<&str>>().as_slice() {
+            [package, module, function] => Ok(Self {
+                package: ObjectID::from_str(package).map_err(|_| invalid())?,
+                module: Identifier::new(*module).map_err(|_| invalid())?,
+                function: Identifier::new(*function).map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoveFunctionId;
+    use crate::error::Error;
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::ObjectID;
+
+    #[test]
+    fn test_from_str() {
+        let function = MoveFunctionId::from_str("0x2::coin::split").unwrap();
+
+        assert_eq!(function.get_package(), &ObjectID::from_single_byte(2));
+        assert_eq!(function.get_module().as_str(), "coin");
+        assert_eq!(function.get_function().as_str(), "split");
+        assert_eq!(
+            MoveFunctionId::from_str(&function.to_string()).unwrap(),
+            function
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let cases = vec![
+            "0x2::coin",
+            "0x2::coin::split::x",
+            "pkg::coin::split",
+            "0x2::1a::b",
+        ];
+
+        for case in cases {
+            assert!(matches!(
+                MoveFunctionId::from_str(case),
+                Err(Error::InvalidMoveFunction(_))
+            ));
+        }
+    }
+}
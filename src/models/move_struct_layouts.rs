@@ -0,0 +1,228 @@
+use super::move_type::MoveType;
+use crate::error::Result;
+use std::collections::{HashMap, HashSet};
+use sui_sdk::{rpc_types::SuiMoveNormalizedModule, types::base_types::ObjectID};
+
+/// Field layouts of Move structs, keyed by `<package>::<module>::<name>`.
+/// Used to decode BCS values of user-defined structs.
+#[derive(Debug, Default)]
+pub struct MoveStructLayouts(HashMap<String, Vec<(String, MoveType)>>);
+
+fn struct_key(package: &ObjectID, module: &str, name: &str) -> String {
+    format!("{}::{}::{}", package, module, name)
+}
+
+fn type_key(move_type: &MoveType) -> Option<String> {
+    match move_type {
+        MoveType::Struct { module, name, .. } => move_type
+            .get_package()
+            .map(|package| struct_key(&package, module, name)),
+        _ => None,
+    }
+}
+
+/// Structs the decoder understands without knowing their fields.
+pub fn is_builtin_struct(move_type: &MoveType) -> bool {
+    move_type.is_struct(1, "string", "String")
+        || move_type.is_struct(1, "ascii", "String")
+        || move_type.is_struct(1, "option", "Option")
+        || move_type.is_struct(2, "object", "ID")
+        || move_type.is_struct(2, "object", "UID")
+}
+
+impl MoveStructLayouts {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub fn add_struct(
+        &mut self,
+        package: &ObjectID,
+        module: &str,
+        name: &str,
+        fields: Vec<(String, MoveType)>,
+    ) {
+        self.0.insert(struct_key(package, module, name), fields);
+    }
+
+    pub fn add_module(
+        &mut self,
+        package: &ObjectID,
+        module_name: &str,
+        module: &SuiMoveNormalizedModule,
+    ) -> Result<()> {
+        for (name, normalized_struct) in module.structs.iter() {
+            let fields = normalized_struct
+                .fields
+                .iter()
+                .map(|field| Ok((field.name.clone(), MoveType::from_normalized(&field.type_)?)))
+                .collect::<Result<Vec<(String, MoveType)>>>()?;
+            self.add_struct(package, module_name, name, fields);
+        }
+        Ok(())
+    }
+
+    /// Fields of a struct type with its type arguments substituted in.
+    pub fn get_fields(&self, move_type: &MoveType) -> Option<Vec<(String, MoveType)>> {
+        let fields = self.0.get(&type_key(move_type)?)?;
+        Some(
+            fields
+                .iter()
+                .map(|(name, field_type)| {
+                    (
+                        name.clone(),
+                        field_type.substitute(move_type.get_type_arguments()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Packages whose struct layouts are still needed to decode `move_types`.
+    pub fn get_missing_packages(&self, move_types: &[MoveType]) -> Vec<ObjectID> {
+        let mut missing = Vec::new();
+        let mut visited = HashSet::new();
+        for move_type in move_types.iter() {
+            self.collect_missing(move_type, &mut missing, &mut visited);
+        }
+        missing
+    }
+
+    fn collect_missing(
+        &self,
+        move_type: &MoveType,
+        missing: &mut Vec<ObjectID>,
+        visited: &mut HashSet<String>,
+    ) {
+        match move_type {
+            MoveType::Vector(inner)
+            | MoveType::Reference(inner)
+            | MoveType::MutableReference(inner) => self.collect_missing(inner, missing, visited),
+            MoveType::Struct { type_arguments, .. } => {
+                for type_argument in type_arguments.iter() {
+                    self.collect_missing(type_argument, missing, visited);
+                }
+
+                if is_builtin_struct(move_type) || !visited.insert(move_type.to_string()) {
+                    return;
+                }
+
+                match self.get_fields(move_type) {
+                    Some(fields) => {
+                        for (_, field_type) in fields.iter() {
+                            self.collect_missing(field_type, missing, visited);
+                        }
+                    }
+                    None => {
+                        if let Some(package) = move_type.get_package() {
+                            if !missing.contains(&package) {
+                                missing.push(package);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoveStructLayouts;
+    use crate::models::move_type::MoveType;
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::ObjectID;
+
+    fn struct_type(address: &str, module: &str, name: &str, args: Vec<MoveType>) -> MoveType {
+        MoveType::Struct {
+            address: address.to_string(),
+            module: module.to_string(),
+            name: name.to_string(),
+            type_arguments: args,
+        }
+    }
+
+    #[test]
+    fn test_get_fields() {
+        let package = ObjectID::from_str("0x2").unwrap();
+        let mut layouts = MoveStructLayouts::new();
+        layouts.add_struct(
+            &package,
+            "balance",
+            "Balance",
+            vec![("value".to_string(), MoveType::U64)],
+        );
+        layouts.add_struct(
+            &package,
+            "coin",
+            "Coin",
+            vec![
+                (
+                    "id".to_string(),
+                    struct_type("0x2", "object", "UID", vec![]),
+                ),
+                (
+                    "balance".to_string(),
+                    struct_type(
+                        "0x2",
+                        "balance",
+                        "Balance",
+                        vec![MoveType::TypeParameter(0)],
+                    ),
+                ),
+            ],
+        );
+
+        let coin = struct_type("0x2", "coin", "Coin", vec![MoveType::U8]);
+        let fields = layouts.get_fields(&coin).unwrap();
+
+        assert_eq!(fields[0].0, "id");
+        assert_eq!(
+            fields[1].1,
+            struct_type("0x2", "balance", "Balance", vec![MoveType::U8])
+        );
+        assert!(layouts
+            .get_fields(&struct_type("0x2", "sui", "SUI", vec![]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_get_missing_packages() {
+        let framework = ObjectID::from_str("0x2").unwrap();
+        let custom = ObjectID::from_str("0xabc").unwrap();
+        let mut layouts = MoveStructLayouts::new();
+        layouts.add_struct(
+            &framework,
+            "balance",
+            "Balance",
+            vec![("value".to_string(), MoveType::U64)],
+        );
+
+        let types = vec![
+            MoveType::Vector(Box::new(struct_type(
+                "0x2",
+                "balance",
+                "Balance",
+                vec![struct_type("0xabc", "token", "TOKEN", vec![])],
+            ))),
+            struct_type("0x1", "string", "String", vec![]),
+            struct_type("0xabc", "pool", "Pool", vec![]),
+        ];
+
+        assert_eq!(layouts.get_missing_packages(&types), vec![custom]);
+
+        layouts.add_struct(&custom, "token", "TOKEN", vec![]);
+        layouts.add_struct(
+            &custom,
+            "pool",
+            "Pool",
+            vec![(
+                "reserve".to_string(),
+                struct_type("0x2", "coin", "Coin", vec![]),
+            )],
+        );
+
+        assert_eq!(layouts.get_missing_packages(&types), vec![framework]);
+    }
+}
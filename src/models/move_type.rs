@@ -0,0 +1,284 @@
+use crate::error::{Error, Result};
+use move_core_types::language_storage::TypeTag;
+use serde::Deserialize;
+use std::{fmt, str::FromStr};
+use sui_sdk::{rpc_types::SuiMoveNormalizedType, types::base_types::ObjectID};
+
+/// Mirror of the RPC's normalized Move type, decoded from its JSON form so the
+/// argument parser and BCS decoder don't depend on the SDK's enum layout.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum MoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    #[serde(rename_all = "camelCase")]
+    Struct {
+        address: String,
+        module: String,
+        name: String,
+        type_arguments: Vec<MoveType>,
+    },
+    Vector(Box<MoveType>),
+    TypeParameter(u16),
+    Reference(Box<MoveType>),
+    MutableReference(Box<MoveType>),
+}
+
+impl MoveType {
+    pub fn from_normalized(normalized: &SuiMoveNormalizedType) -> Result<Self> {
+        serde_json::to_value(normalized)
+            .and_then(serde_json::from_value)
+            .map_err(|e| Error::UnsupportedMoveType(e.to_string()))
+    }
+
+    pub fn from_type_tag(type_tag: &TypeTag) -> Self {
+        match type_tag {
+            TypeTag::Bool => Self::Bool,
+            TypeTag::U8 => Self::U8,
+            TypeTag::U16 => Self::U16,
+            TypeTag::U32 => Self::U32,
+            TypeTag::U64 => Self::U64,
+            TypeTag::U128 => Self::U128,
+            TypeTag::U256 => Self::U256,
+            TypeTag::Address => Self::Address,
+            TypeTag::Signer => Self::Signer,
+            TypeTag::Vector(inner) => Self::Vector(Box::new(Self::from_type_tag(inner))),
+            TypeTag::Struct(struct_tag) => Self::Struct {
+                address: struct_tag.address.to_hex_literal(),
+                module: struct_tag.module.to_string(),
+                name: struct_tag.name.to_string(),
+                type_arguments: struct_tag
+                    .type_params
+                    .iter()
+                    .map(Self::from_type_tag)
+                    .collect(),
+            },
+        }
+    }
+
+    /// Replaces `T0`, `T1`, ... with the given type arguments. Parameters
+    /// without a matching argument are left as is.
+    pub fn substitute(&self, type_arguments: &[MoveType]) -> Self {
+        match self {
+            Self::TypeParameter(index) => type_arguments
+                .get(*index as usize)
+                .cloned()
+                .unwrap_or_else(|| self.clone()),
+            Self::Vector(inner) => Self::Vector(Box::new(inner.substitute(type_arguments))),
+            Self::Reference(inner) => Self::Reference(Box::new(inner.substitute(type_arguments))),
+            Self::MutableReference(inner) => {
+                Self::MutableReference(Box::new(inner.substitute(type_arguments)))
+            }
+            Self::Struct {
+                address,
+                module,
+                name,
+                type_arguments: args,
+            } => Self::Struct {
+                address: address.clone(),
+                module: module.clone(),
+                name: name.clone(),
+                type_arguments: args.iter().map(|t| t.substitute(type_arguments)).collect(),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// The package a struct type is defined in.
+    pub fn get_package(&self) -> Option<ObjectID> {
+        match self {
+            Self::Struct { address, .. } => ObjectID::from_str(address).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn is_struct(&self, address: u8, module: &str, name: &str) -> bool {
+        match self {
+            Self::Struct {
+                address: a,
+                module: m,
+                name: n,
+                ..
+            } => {
+                m == module
+                    && n == name
+                    && ObjectID::from_str(a).ok() == Some(ObjectID::from_single_byte(address))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_tx_context(&self) -> bool {
+        match self {
+            Self::Reference(inner) | Self::MutableReference(inner) => {
+                inner.is_struct(2, "tx_context", "TxContext")
+            }
+            _ => false,
+        }
+    }
+
+    pub fn get_type_arguments(&self) -> &[MoveType] {
+        match self {
+            Self::Struct { type_arguments, .. } => type_arguments,
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for MoveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::U256 => write!(f, "u256"),
+            Self::Address => write!(f, "address"),
+            Self::Signer => write!(f, "signer"),
+            Self::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                let address = address.trim_start_matches("0x").trim_start_matches('0');
+                write!(
+                    f,
+                    "0x{}::{}::{}",
+                    if address.is_empty() { "0" } else { address },
+                    module,
+                    name
+                )?;
+                if !type_arguments.is_empty() {
+                    write!(
+                        f,
+                        "<{}>",
+                        type_arguments
+                            .iter()
+                            .map(|t| t.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            Self::Vector(inner) => write!(f, "vector<{}>", inner),
+            Self::TypeParameter(index) => write!(f, "T{}", index),
+            Self::Reference(inner) => write!(f, "&{}", inner),
+            Self::MutableReference(inner) => write!(f, "&mut {}", inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoveType;
+    use sui_sdk::{rpc_types::SuiMoveNormalizedType, types::parse_sui_type_tag};
+
+    fn tx_context() -> MoveType {
+        MoveType::MutableReference(Box::new(MoveType::Struct {
+            address: "0x2".to_string(),
+            module: "tx_context".to_string(),
+            name: "TxContext".to_string(),
+            type_arguments: vec![],
+        }))
+    }
+
+    #[test]
+    fn test_from_normalized() {
+        let normalized = SuiMoveNormalizedType::Vector(Box::new(SuiMoveNormalizedType::U64));
+        assert_eq!(
+            MoveType::from_normalized(&normalized).unwrap(),
+            MoveType::Vector(Box::new(MoveType::U64))
+        );
+    }
+
+    #[test]
+    fn test_is_struct() {
+        let string = MoveType::Struct {
+            address: "0x0000000000000000000000000000000000000000000000000000000000000001"
+                .to_string(),
+            module: "string".to_string(),
+            name: "String".to_string(),
+            type_arguments: vec![],
+        };
+
+        assert!(string.is_struct(1, "string", "String"));
+        assert!(!string.is_struct(2, "string", "String"));
+        assert!(!string.is_struct(1, "ascii", "String"));
+        assert!(!MoveType::U8.is_struct(1, "string", "String"));
+
+        assert!(tx_context().is_tx_context());
+        assert!(!string.is_tx_context());
+    }
+
+    #[test]
+    fn test_from_type_tag() {
+        let type_tag = parse_sui_type_tag("vector<0x2::coin::Coin<0x2::sui::SUI>>").unwrap();
+        let move_type = MoveType::from_type_tag(&type_tag);
+
+        assert_eq!(
+            move_type.to_string(),
+            "vector<0x2::coin::Coin<0x2::sui::SUI>>"
+        );
+    }
+
+    #[test]
+    fn test_substitute() {
+        let coin = MoveType::Struct {
+            address: "0x2".to_string(),
+            module: "coin".to_string(),
+            name: "Coin".to_string(),
+            type_arguments: vec![MoveType::TypeParameter(0)],
+        };
+        let move_type = MoveType::Vector(Box::new(coin));
+
+        assert_eq!(
+            move_type.substitute(&[MoveType::U8]).to_string(),
+            "vector<0x2::coin::Coin<u8>>"
+        );
+        assert_eq!(move_type.substitute(&[]), move_type);
+        assert_eq!(
+            MoveType::TypeParameter(1).substitute(&[MoveType::U8, MoveType::Bool]),
+            MoveType::Bool
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let string = MoveType::Struct {
+            address: "0x0000000000000000000000000000000000000000000000000000000000000001"
+                .to_string(),
+            module: "string".to_string(),
+            name: "String".to_string(),
+            type_arguments: vec![],
+        };
+        assert_eq!(string.to_string(), "0x1::string::String");
+
+        let coin = MoveType::Struct {
+            address: "0x2".to_string(),
+            module: "coin".to_string(),
+            name: "Coin".to_string(),
+            type_arguments: vec![MoveType::TypeParameter(0)],
+        };
+
+        assert_eq!(coin.to_string(), "0x2::coin::Coin<T0>");
+        assert_eq!(
+            MoveType::Reference(Box::new(coin)).to_string(),
+            "&0x2::coin::Coin<T0>"
+        );
+        assert_eq!(
+            MoveType::Vector(Box::new(MoveType::U8)).to_string(),
+            "vector<u8>"
+        );
+        assert_eq!(tx_context().to_string(), "&mut 0x2::tx_context::TxContext");
+    }
+}
@@ -0,0 +1,256 @@
+use super::{move_struct_layouts::MoveStructLayouts, move_type::MoveType};
+use crate::error::{Error, Result};
+use move_core_types::u256::U256;
+use serde_json::{json, Map, Value};
+use sui_sdk::types::base_types::SuiAddress;
+
+/// Decodes BCS encoded Move values into JSON. Integers wider than 32 bits are
+/// rendered as strings so they survive JSON consumers without losing precision.
+pub struct MoveValueDecoder<'a> {
+    layouts: &'a MoveStructLayouts,
+}
+
+fn take<'b>(cursor: &mut &'b [u8], len: usize) -> Result<&'b [u8]> {
+    if cursor.len() < len {
+        return Err(Error::BcsError("unexpected end of input".to_string()));
+    }
+    let (head, tail) = cursor.split_at(len);
+    *cursor = tail;
+    Ok(head)
+}
+
+fn take_array<const N: usize>(cursor: &mut &[u8]) -> Result<[u8; N]> {
+    let mut array = [0u8; N];
+    array.copy_from_slice(take(cursor, N)?);
+    Ok(array)
+}
+
+fn read_uleb128(cursor: &mut &[u8]) -> Result<usize> {
+    let mut value = 0usize;
+    for shift in (0..64).step_by(7) {
+        let byte = take(cursor, 1)?[0];
+        value |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(Error::BcsError("invalid ULEB128 length".to_string()))
+}
+
+fn read_address(cursor: &mut &[u8]) -> Result<String> {
+    SuiAddress::from_bytes(take(cursor, SuiAddress::LENGTH)?)
+        .map(|address| address.to_string())
+        .map_err(|e| Error::BcsError(e.to_string()))
+}
+
+impl<'a> MoveValueDecoder<'a> {
+    pub fn new(layouts: &'a MoveStructLayouts) -> Self {
+        Self { layouts }
+    }
+
+    pub fn decode(&self, bytes: &[u8], move_type: &MoveType) -> Result<Value> {
+        let mut cursor = bytes;
+        let value = self.read(&mut cursor, move_type)?;
+        if !cursor.is_empty() {
+            return Err(Error::BcsError(format!(
+                "{} trailing bytes after {}",
+                cursor.len(),
+                move_type
+            )));
+        }
+        Ok(value)
+    }
+
+    fn read(&self, cursor: &mut &[u8], move_type: &MoveType) -> Result<Value> {
+        match move_type {
+            MoveType::Bool => match take(cursor, 1)?[0] {
+                0 => Ok(json!(false)),
+                1 => Ok(json!(true)),
+                byte => Err(Error::BcsError(format!("invalid bool {}", byte))),
+            },
+            MoveType::U8 => Ok(json!(take(cursor, 1)?[0])),
+            MoveType::U16 => Ok(json!(u16::from_le_bytes(take_array(cursor)?))),
+            MoveType::U32 => Ok(json!(u32::from_le_bytes(take_array(cursor)?))),
+            MoveType::U64 => Ok(json!(u64::from_le_bytes(take_array(cursor)?).to_string())),
+            MoveType::U128 => Ok(json!(u128::from_le_bytes(take_array(cursor)?).to_string())),
+            MoveType::U256 => Ok(json!(U256::from_le_bytes(&take_array(cursor)?).to_string())),
+            MoveType::Address | MoveType::Signer => Ok(json!(read_address(cursor)?)),
+            MoveType::Vector(inner) if **inner == MoveType::U8 => {
+                let len = read_uleb128(cursor)?;
+                Ok(json!(format!("0x{}", hex::encode(take(cursor, len)?))))
+            }
+            MoveType::Vector(inner) => {
+                let len = read_uleb128(cursor)?;
+                (0..len)
+                    .map(|_| self.read(cursor, inner))
+                    .collect::<Result<Vec<Value>>>()
+                    .map(Value::Array)
+            }
+            MoveType::Reference(inner) | MoveType::MutableReference(inner) => {
+                self.read(cursor, inner)
+            }
+            MoveType::Struct { .. }
+                if move_type.is_struct(1, "string", "String")
+                    || move_type.is_struct(1, "ascii", "String") =>
+            {
+                let len = read_uleb128(cursor)?;
+                String::from_utf8(take(cursor, len)?.to_vec())
+                    .map(Value::String)
+                    .map_err(|e| Error::BcsError(e.to_string()))
+            }
+            MoveType::Struct { .. }
+                if move_type.is_struct(2, "object", "ID")
+                    || move_type.is_struct(2, "object", "UID") =>
+            {
+                Ok(json!(read_address(cursor)?))
+            }
+            MoveType::Struct { type_arguments, .. }
+                if move_type.is_struct(1, "option", "Option") =>
+            {
+                let inner = type_arguments
+                    .first()
+                    .ok_or(Error::UnsupportedMoveType(move_type.to_string()))?;
+                match read_uleb128(cursor)? {
+                    0 => Ok(Value::Null),
+                    1 => self.read(cursor, inner),
+                    len => Err(Error::BcsError(format!("invalid option length {}", len))),
+                }
+            }
+            MoveType::Struct { .. } => {
+                let fields = self
+                    .layouts
+                    .get_fields(move_type)
+                    .ok_or(Error::UnsupportedMoveType(move_type.to_string()))?;
+                let mut object = Map::new();
+                for (name, field_type) in fields.iter() {
+                    object.insert(name.clone(), self.read(cursor, field_type)?);
+                }
+                Ok(Value::Object(object))
+            }
+            MoveType::TypeParameter(_) => Err(Error::UnsupportedMoveType(move_type.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoveValueDecoder;
+    use crate::{
+        error::Error,
+        models::{move_struct_layouts::MoveStructLayouts, move_type::MoveType},
+    };
+    use serde_json::json;
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::ObjectID;
+
+    fn struct_type(address: &str, module: &str, name: &str, args: Vec<MoveType>) -> MoveType {
+        MoveType::Struct {
+            address: address.to_string(),
+            module: module.to_string(),
+            name: name.to_string(),
+            type_arguments: args,
+        }
+    }
+
+    #[test]
+    fn test_decode_primitives() {
+        let layouts = MoveStructLayouts::new();
+        let decoder = MoveValueDecoder::new(&layouts);
+
+        assert_eq!(decoder.decode(&[1], &MoveType::Bool).unwrap(), json!(true));
+        assert_eq!(decoder.decode(&[2, 1], &MoveType::U16).unwrap(), json!(258));
+        assert_eq!(
+            decoder
+                .decode(&u64::MAX.to_le_bytes(), &MoveType::U64)
+                .unwrap(),
+            json!("18446744073709551615")
+        );
+        assert_eq!(
+            decoder.decode(&[0; 32], &MoveType::U256).unwrap(),
+            json!("0")
+        );
+        assert!(matches!(
+            decoder.decode(&[2], &MoveType::Bool),
+            Err(Error::BcsError(_))
+        ));
+        assert!(matches!(
+            decoder.decode(&[1, 0], &MoveType::U32),
+            Err(Error::BcsError(_))
+        ));
+        assert!(matches!(
+            decoder.decode(&[1, 0], &MoveType::U8),
+            Err(Error::BcsError(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_vectors_and_builtins() {
+        let layouts = MoveStructLayouts::new();
+        let decoder = MoveValueDecoder::new(&layouts);
+
+        assert_eq!(
+            decoder
+                .decode(&[2, 0xab, 0xcd], &MoveType::Vector(Box::new(MoveType::U8)))
+                .unwrap(),
+            json!("0xabcd")
+        );
+        assert_eq!(
+            decoder
+                .decode(&[2, 1, 0, 2, 0], &MoveType::Vector(Box::new(MoveType::U16)))
+                .unwrap(),
+            json!([1, 2])
+        );
+        assert_eq!(
+            decoder
+                .decode(
+                    &[3, b's', b'u', b'i'],
+                    &struct_type("0x1", "string", "String", vec![])
+                )
+                .unwrap(),
+            json!("sui")
+        );
+
+        let option = struct_type("0x1", "option", "Option", vec![MoveType::U8]);
+        assert_eq!(decoder.decode(&[0], &option).unwrap(), json!(null));
+        assert_eq!(decoder.decode(&[1, 7], &option).unwrap(), json!(7));
+
+        let mut id = vec![0; 31];
+        id.push(2);
+        assert_eq!(
+            decoder
+                .decode(&id, &struct_type("0x2", "object", "ID", vec![]))
+                .unwrap(),
+            json!(ObjectID::from_str("0x2").unwrap().to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_structs() {
+        let mut layouts = MoveStructLayouts::new();
+        layouts.add_struct(
+            &ObjectID::from_str("0xabc").unwrap(),
+            "pool",
+            "Pool",
+            vec![
+                (
+                    "name".to_string(),
+                    struct_type("0x1", "string", "String", vec![]),
+                ),
+                ("reserve".to_string(), MoveType::TypeParameter(0)),
+            ],
+        );
+        let decoder = MoveValueDecoder::new(&layouts);
+
+        let pool = struct_type("0xabc", "pool", "Pool", vec![MoveType::U8]);
+        assert_eq!(
+            decoder.decode(&[1, b'a', 9], &pool).unwrap(),
+            json!({"name": "a", "reserve": 9})
+        );
+
+        let unknown = struct_type("0xabc", "pool", "Unknown", vec![]);
+        assert!(matches!(
+            decoder.decode(&[], &unknown),
+            Err(Error::UnsupportedMoveType(_))
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::error::Error;
+use move_core_types::{identifier::Identifier, language_storage::StructTag};
+use std::{fmt, str::FromStr};
+use sui_sdk::{
+    rpc_types::SuiObjectDataFilter,
+    types::{base_types::ObjectID, parse_sui_struct_tag},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectFilter {
+    Package(ObjectID),
+    Module(ObjectID, Identifier),
+    StructType(StructTag),
+}
+
+impl ObjectFilter {
+    pub fn to_sui_object_data_filter(&self) -> SuiObjectDataFilter {
+        match self {
+            Self::Package(package) => SuiObjectDataFilter::Package(*package),
+            Self::Module(package, module) => SuiObjectDataFilter::MoveModule {
+                package: *package,
+                module: module.clone(),
+            },
+            Self::StructType(struct_tag) => SuiObjectDataFilter::StructType(struct_tag.clone()),
+        }
+    }
+}
+
+impl fmt::Display for ObjectFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Package(package) => write!(f, "{}", package),
+            Self::Module(package, module) => write!(f, "{}::{}", package, module),
+            Self::StructType(struct_tag) => write!(f, "{}", struct_tag),
+        }
+    }
+}
+
+impl FromStr for ObjectFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidObjectFilter(s.to_string());
+
+        match s.split("::").collect::<Vec<&str>>().as_slice() {
+            [package] => Ok(Self::Package(
+                ObjectID::from_str(package).map_err(|_| invalid())?,
+            )),
+            [package, module] => Ok(Self::Module(
+                ObjectID::from_str(package).map_err(|_| invalid())?,
+                Identifier::new(*module).map_err(|_| invalid())?,
+            )),
+            _ => Ok(Self::StructType(
+                parse_sui_struct_tag(s).map_err(|_| invalid())?,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObjectFilter;
+    use crate::error::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert!(matches!(
+            ObjectFilter::from_str("0x2"),
+            Ok(ObjectFilter::Package(_))
+        ));
+        assert!(matches!(
+            ObjectFilter::from_str("0x2::kiosk"),
+            Ok(ObjectFilter::Module(_, _))
+        ));
+        assert!(matches!(
+            ObjectFilter::from_str("0x2::package::UpgradeCap"),
+            Ok(ObjectFilter::StructType(_))
+        ));
+        assert!(matches!(
+            ObjectFilter::from_str("0x2::coin::Coin<0x2::sui::SUI>"),
+            Ok(ObjectFilter::StructType(_))
+        ));
+    }
+
+    #[test]
+    fn test_display() {
+        let filter = ObjectFilter::from_str("0x2::kiosk").unwrap();
+        assert_eq!(ObjectFilter::from_str(&filter.to_string()).unwrap(), filter);
+
+        let filter = ObjectFilter::from_str("0x2::package::UpgradeCap").unwrap();
+        assert_eq!(ObjectFilter::from_str(&filter.to_string()).unwrap(), filter);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let cases = vec!["not_an_id", "0x2::bad-module", "0x2::coin::"];
+
+        for case in cases {
+            assert!(matches!(
+                ObjectFilter::from_str(case),
+                Err(Error::InvalidObjectFilter(_))
+            ));
+        }
+    }
+}
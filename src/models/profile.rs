@@ -0,0 +1,131 @@
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{env, fmt, str::FromStr};
+
+pub const PROFILE_ENV: &str = "WALLET_PROFILE";
+const DEFAULT_PROFILE: &str = "default";
+const CONFIG_NAME: &str = "wallets";
+
+/// A separate vault with its own cipher settings.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Hash)]
+pub struct Profile(String);
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self(DEFAULT_PROFILE.to_string())
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Profile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl Profile {
+    pub fn new(name: &str) -> Result<Self> {
+        if name.len() > 20 {
+            return Err(Error::NameTooLong(20));
+        }
+
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(Error::InvalidProfile(name.to_string()));
+        }
+
+        Ok(Self(name.to_string()))
+    }
+
+    /// The profile selected by `--profile` or `WALLET_PROFILE`.
+    pub fn from_env() -> Result<Self> {
+        match env::var(PROFILE_ENV) {
+            Ok(name) if !name.is_empty() => Self::new(&name),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.0 == DEFAULT_PROFILE
+    }
+
+    /// Prefix of the profile's cipher variables: `CIPHER_KEY` for the default
+    /// profile, `<NAME>_CIPHER_KEY` for the others.
+    pub fn get_env_prefix(&self) -> String {
+        if self.is_default() {
+            String::new()
+        } else {
+            format!("{}_", self.0.to_uppercase())
+        }
+    }
+
+    /// Config file name without extension: `wallets` for the default profile,
+    /// `wallets-<name>` for the others.
+    pub fn get_config_name(&self) -> String {
+        if self.is_default() {
+            CONFIG_NAME.to_string()
+        } else {
+            format!("{}-{}", CONFIG_NAME, self.0)
+        }
+    }
+
+    pub fn from_config_name(name: &str) -> Option<Self> {
+        if name == CONFIG_NAME {
+            return Some(Self::default());
+        }
+
+        name.strip_prefix(CONFIG_NAME)?
+            .strip_prefix('-')
+            .and_then(|name| Self::new(name).ok())
+            .filter(|profile| !profile.is_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profile;
+    use crate::error::Error;
+
+    #[test]
+    fn test_new() {
+        assert!(Profile::new("work").is_ok());
+        assert!(Profile::new("ci_2").is_ok());
+        assert!(matches!(Profile::new(""), Err(Error::InvalidProfile(_))));
+        assert!(matches!(
+            Profile::new("Work"),
+            Err(Error::InvalidProfile(_))
+        ));
+        assert!(matches!(
+            Profile::new("../work"),
+            Err(Error::InvalidProfile(_))
+        ));
+    }
+
+    #[test]
+    fn test_names() {
+        let default = Profile::default();
+        assert!(default.is_default());
+        assert_eq!(default.get_env_prefix(), "");
+        assert_eq!(default.get_config_name(), "wallets");
+
+        let work = Profile::new("work").unwrap();
+        assert_eq!(work.get_env_prefix(), "WORK_");
+        assert_eq!(work.get_config_name(), "wallets-work");
+
+        assert_eq!(Profile::from_config_name("wallets"), Some(default));
+        assert_eq!(Profile::from_config_name("wallets-work"), Some(work));
+        assert_eq!(Profile::from_config_name("wallets-default"), None);
+        assert_eq!(Profile::from_config_name("wallets-Bad"), None);
+        assert_eq!(Profile::from_config_name("other"), None);
+    }
+}
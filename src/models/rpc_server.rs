@@ -2,11 +2,20 @@ use super::{alias::Alias, network_env::NetworkEnv, rpc_url::RpcUrl};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct RpcServer(RpcUrl, Alias, NetworkEnv);
+pub struct RpcServer(
+    RpcUrl,
+    Alias,
+    NetworkEnv,
+    #[serde(default, skip_serializing_if = "Option::is_none")] Option<RpcUrl>,
+);
 
 impl RpcServer {
     pub fn new(url: RpcUrl, alias: Alias, kind: NetworkEnv) -> Self {
-        Self(url, alias, kind)
+        Self(url, alias, kind, None)
+    }
+    pub fn with_ws_url(mut self, ws_url: Option<RpcUrl>) -> Self {
+        self.3 = ws_url;
+        self
     }
     pub fn get_url(&self) -> &RpcUrl {
         &self.0
@@ -17,6 +26,9 @@ impl RpcServer {
     pub fn get_env(&self) -> &NetworkEnv {
         &self.2
     }
+    pub fn get_ws_url(&self) -> &Option<RpcUrl> {
+        &self.3
+    }
 }
 
 #[cfg(test)]
@@ -36,5 +48,19 @@ mod tests {
         assert_eq!(server.get_url(), &url);
         assert_eq!(server.get_alias(), &alias);
         assert_eq!(server.get_env(), &env);
+        assert_eq!(server.get_ws_url(), &None);
+
+        let ws_url = RpcUrl::from_str("ws://localhost:9001").unwrap();
+        let server = server.with_ws_url(Some(ws_url.clone()));
+        assert_eq!(server.get_ws_url(), &Some(ws_url));
+    }
+
+    #[test]
+    fn test_deserialize_without_ws_url() {
+        let server: RpcServer =
+            serde_json::from_str(r#"["http://localhost:8545", "test", "Mainnet"]"#).unwrap();
+
+        assert_eq!(server.get_url().to_string(), "http://localhost:8545");
+        assert_eq!(server.get_ws_url(), &None);
     }
 }
@@ -1,4 +1,4 @@
-use super::{alias::Alias, rpc_server::RpcServer, rpc_url::RpcUrl};
+use super::{alias::Alias, alias_or_url::AliasOrUrl, rpc_server::RpcServer, rpc_url::RpcUrl};
 use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -14,6 +14,22 @@ impl RpcServerList {
         self.aliasses.get(alias)
     }
 
+    pub fn resolve_url(&self, alias_or_url: &AliasOrUrl) -> Result<RpcUrl> {
+        match alias_or_url {
+            AliasOrUrl::Url(url) => Ok(url.clone()),
+            AliasOrUrl::Alias(alias) => self
+                .get_url_by_alias(alias)
+                .cloned()
+                .ok_or(Error::NetworkAliasNotFound(alias.clone())),
+        }
+    }
+
+    /// WebSocket endpoint registered for `url`, if any.
+    pub fn get_ws_url(&self, url: &RpcUrl) -> Option<RpcUrl> {
+        self.get_by_key(url)
+            .and_then(|server| server.get_ws_url().clone())
+    }
+
     pub fn get_by_key(&self, url: &RpcUrl) -> Option<&RpcServer> {
         self.rpc_servers.get(url)
     }
@@ -58,7 +74,8 @@ impl RpcServerList {
 mod tests {
     use super::RpcServerList;
     use crate::models::{
-        alias::Alias, network_env::NetworkEnv, rpc_server::RpcServer, rpc_url::RpcUrl,
+        alias::Alias, alias_or_url::AliasOrUrl, network_env::NetworkEnv, rpc_server::RpcServer,
+        rpc_url::RpcUrl,
     };
     use std::str::FromStr;
 
@@ -85,6 +102,17 @@ mod tests {
             assert_eq!(server.get_env(), &env);
         }
         assert_eq!(list.get_url_by_alias(&alias), Some(&url));
+        assert_eq!(
+            list.resolve_url(&AliasOrUrl::Alias(alias.clone())).unwrap(),
+            url
+        );
+        assert_eq!(
+            list.resolve_url(&AliasOrUrl::Url(url.clone())).unwrap(),
+            url
+        );
+        assert!(list
+            .resolve_url(&AliasOrUrl::Alias(Alias::new("none").unwrap()))
+            .is_err());
         assert!(list.contains_key(&url));
         assert!(list.contains_alias_key(&alias));
 
@@ -0,0 +1,116 @@
+use crate::error::{Error, Result};
+use serde_json::{json, Map, Value};
+
+/// Layout version written by this binary.
+pub const SCHEMA_VERSION: u32 = 2;
+const VERSION_KEY: &str = "version";
+
+type Migration = fn(&mut Map<String, Value>) -> Result<()>;
+
+/// `MIGRATIONS[n]` upgrades a version `n` layout to version `n + 1`.
+const MIGRATIONS: [Migration; SCHEMA_VERSION as usize] = [migrate_v0, migrate_v1];
+
+/// Upgrades a stored config in place. Returns the version it was stored with
+/// if it had to be upgraded.
+pub fn migrate(value: &mut Value) -> Result<Option<u32>> {
+    let Value::Object(config) = value else {
+        return Err(Error::InvalidSchemaVersion(
+            "config is not a mapping".to_string(),
+        ));
+    };
+
+    let version = match config.get(VERSION_KEY) {
+        None => 0,
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| Error::InvalidSchemaVersion(version.to_string()))?,
+    };
+
+    if version > SCHEMA_VERSION {
+        return Err(Error::SchemaTooNew(version, SCHEMA_VERSION));
+    }
+    if version == SCHEMA_VERSION {
+        return Ok(None);
+    }
+
+    for migration in MIGRATIONS[version as usize..].iter() {
+        migration(config)?;
+    }
+    config.insert(VERSION_KEY.to_string(), Value::from(SCHEMA_VERSION));
+
+    Ok(Some(version))
+}
+
+/// Unversioned files may predate sections that are now required.
+fn migrate_v0(config: &mut Map<String, Value>) -> Result<()> {
+    for section in ["wallets", "rpc_servers"] {
+        config
+            .entry(section)
+            .or_insert_with(|| json!({ section: {}, "aliasses": {} }));
+    }
+    config.entry("tags").or_insert_with(|| json!([]));
+
+    Ok(())
+}
+
+/// The SuiNS cache moved out of the vault into its own file.
+fn migrate_v1(config: &mut Map<String, Value>) -> Result<()> {
+    config.remove("suins_cache");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migrate, SCHEMA_VERSION};
+    use crate::{error::Error, models::wallet_confy::WalletConfy};
+    use serde_json::json;
+
+    #[test]
+    fn test_migrate_unversioned() {
+        let mut value = json!({ "tags": ["ops"] });
+
+        assert_eq!(migrate(&mut value).unwrap(), Some(0));
+        assert_eq!(value["version"], json!(SCHEMA_VERSION));
+        assert_eq!(value["wallets"], json!({ "wallets": {}, "aliasses": {} }));
+        assert_eq!(value["tags"], json!(["ops"]));
+        assert!(serde_json::from_value::<WalletConfy>(value).is_ok());
+    }
+
+    #[test]
+    fn test_migrate_suins_cache() {
+        let mut value = serde_json::to_value(WalletConfy::default()).unwrap();
+        value["version"] = json!(1);
+        value["suins_cache"] = json!({ "addresses": {}, "names": {} });
+
+        assert_eq!(migrate(&mut value).unwrap(), Some(1));
+        assert_eq!(value.get("suins_cache"), None);
+        assert_eq!(value, serde_json::to_value(WalletConfy::default()).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_current() {
+        let mut value = serde_json::to_value(WalletConfy::default()).unwrap();
+        let expected = value.clone();
+
+        assert_eq!(migrate(&mut value).unwrap(), None);
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_migrate_invalid() {
+        let mut value = json!({ "version": SCHEMA_VERSION + 1 });
+        assert!(matches!(
+            migrate(&mut value),
+            Err(Error::SchemaTooNew(found, supported))
+                if found == SCHEMA_VERSION + 1 && supported == SCHEMA_VERSION
+        ));
+
+        let mut value = json!({ "version": "one" });
+        assert!(matches!(
+            migrate(&mut value),
+            Err(Error::InvalidSchemaVersion(_))
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::Error;
+use std::{fmt, str::FromStr};
+
+const SUI_DECIMALS: u32 = 9;
+
+/// An amount of SUI entered in whole units (e.g. `1.5`) and held in MIST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SuiAmount(u64);
+
+impl SuiAmount {
+    pub fn from_mist(mist: u64) -> Self {
+        Self(mist)
+    }
+
+    pub fn get_mist(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for SuiAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = 10_u64.pow(SUI_DECIMALS);
+        let fraction = format!("{:0width$}", self.0 % unit, width = SUI_DECIMALS as usize);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            write!(f, "{}", self.0 / unit)
+        } else {
+            write!(f, "{}.{}", self.0 / unit, fraction)
+        }
+    }
+}
+
+impl FromStr for SuiAmount {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidSuiAmount(s.to_string());
+
+        let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+            || fraction.len() > SUI_DECIMALS as usize
+        {
+            return Err(invalid());
+        }
+
+        let whole = if whole.is_empty() {
+            0
+        } else {
+            whole.parse::<u64>().map_err(|_| invalid())?
+        };
+        let fraction = format!("{:0<width$}", fraction, width = SUI_DECIMALS as usize)
+            .parse::<u64>()
+            .map_err(|_| invalid())?;
+
+        whole
+            .checked_mul(10_u64.pow(SUI_DECIMALS))
+            .and_then(|mist| mist.checked_add(fraction))
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SuiAmount;
+    use crate::error::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        let cases = vec![
+            ("1", 1_000_000_000),
+            ("1.5", 1_500_000_000),
+            ("0.000000001", 1),
+            (".25", 250_000_000),
+            ("10.", 10_000_000_000),
+        ];
+
+        for (input, mist) in cases {
+            assert_eq!(SuiAmount::from_str(input).unwrap().get_mist(), mist);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(SuiAmount::from_mist(1_500_000_000).to_string(), "1.5");
+        assert_eq!(SuiAmount::from_mist(2_000_000_000).to_string(), "2");
+        assert_eq!(SuiAmount::from_mist(1).to_string(), "0.000000001");
+    }
+
+    #[test]
+    fn test_invalid() {
+        let cases = vec![
+            "",
+            ".",
+            "abc",
+            "1.2.3",
+            "-1",
+            "0.0000000001",
+            "99999999999999999",
+        ];
+
+        for case in cases {
+            assert!(matches!(
+                SuiAmount::from_str(case),
+                Err(Error::InvalidSuiAmount(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use super::{rpc_url::RpcUrl, suins_name::SuiNsName, timestamp::Timestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use sui_sdk::types::base_types::SuiAddress;
+
+/// How long a SuiNS lookup, including a negative one, is trusted.
+pub const SUINS_CACHE_TTL_MS: u64 = 60 * 60 * 1000;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+struct CacheEntry<T> {
+    value: Option<T>,
+    expires_at: Timestamp,
+}
+
+/// SuiNS lookups per RPC endpoint, since names differ between networks.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SuiNsCache {
+    addresses: HashMap<RpcUrl, HashMap<SuiNsName, CacheEntry<SuiAddress>>>,
+    names: HashMap<RpcUrl, HashMap<SuiAddress, CacheEntry<SuiNsName>>>,
+    #[serde(skip)]
+    changed: bool,
+}
+
+fn get_fresh<K, T>(
+    entries: &HashMap<RpcUrl, HashMap<K, CacheEntry<T>>>,
+    url: &RpcUrl,
+    key: &K,
+    now: Timestamp,
+) -> Option<Option<T>>
+where
+    K: Eq + std::hash::Hash,
+    T: Clone,
+{
+    entries
+        .get(url)
+        .and_then(|entries| entries.get(key))
+        .filter(|entry| now < entry.expires_at)
+        .map(|entry| entry.value.clone())
+}
+
+impl SuiNsCache {
+    /// `Some(None)` is a cached "name not registered".
+    pub fn get_address(
+        &self,
+        url: &RpcUrl,
+        name: &SuiNsName,
+        now: Timestamp,
+    ) -> Option<Option<SuiAddress>> {
+        get_fresh(&self.addresses, url, name, now)
+    }
+
+    pub fn insert_address(
+        &mut self,
+        url: &RpcUrl,
+        name: SuiNsName,
+        address: Option<SuiAddress>,
+        now: Timestamp,
+    ) {
+        self.changed = true;
+        self.addresses.entry(url.clone()).or_default().insert(
+            name,
+            CacheEntry {
+                value: address,
+                expires_at: Timestamp::from_millis(now.get_millis() + SUINS_CACHE_TTL_MS),
+            },
+        );
+    }
+
+    /// `Some(None)` is a cached "no primary name".
+    pub fn get_name(
+        &self,
+        url: &RpcUrl,
+        address: &SuiAddress,
+        now: Timestamp,
+    ) -> Option<Option<SuiNsName>> {
+        get_fresh(&self.names, url, address, now)
+    }
+
+    pub fn insert_name(
+        &mut self,
+        url: &RpcUrl,
+        address: SuiAddress,
+        name: Option<SuiNsName>,
+        now: Timestamp,
+    ) {
+        self.changed = true;
+        self.names.entry(url.clone()).or_default().insert(
+            address,
+            CacheEntry {
+                value: name,
+                expires_at: Timestamp::from_millis(now.get_millis() + SUINS_CACHE_TTL_MS),
+            },
+        );
+    }
+
+    /// Whether entries were added or pruned since loading, i.e. it needs
+    /// storing.
+    pub fn is_changed(&self) -> bool {
+        self.changed
+    }
+
+    pub fn clear_changed(&mut self) {
+        self.changed = false;
+    }
+
+    /// Drops expired entries so the cache file doesn't grow without bound.
+    pub fn prune(&mut self, now: Timestamp) {
+        let len = self.len();
+
+        for entries in self.addresses.values_mut() {
+            entries.retain(|_, entry| now < entry.expires_at);
+        }
+        for entries in self.names.values_mut() {
+            entries.retain(|_, entry| now < entry.expires_at);
+        }
+        self.addresses.retain(|_, entries| !entries.is_empty());
+        self.names.retain(|_, entries| !entries.is_empty());
+        self.changed |= self.len() != len;
+    }
+
+    fn len(&self) -> usize {
+        self.addresses.values().map(HashMap::len).sum::<usize>()
+            + self.names.values().map(HashMap::len).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SuiNsCache, SUINS_CACHE_TTL_MS};
+    use crate::models::{rpc_url::RpcUrl, suins_name::SuiNsName, timestamp::Timestamp};
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::SuiAddress;
+
+    #[test]
+    fn test_address_expiry() {
+        let mainnet = RpcUrl::from_str("https://fullnode.mainnet.sui.io:443").unwrap();
+        let testnet = RpcUrl::from_str("https://fullnode.testnet.sui.io:443").unwrap();
+        let name = SuiNsName::from_str("alice.sui").unwrap();
+        let address = SuiAddress::random_for_testing_only();
+        let now = Timestamp::from_millis(1_000);
+
+        let mut cache = SuiNsCache::default();
+        assert_eq!(cache.get_address(&mainnet, &name, now), None);
+
+        cache.insert_address(&mainnet, name.clone(), Some(address), now);
+        assert_eq!(cache.get_address(&mainnet, &name, now), Some(Some(address)));
+        assert_eq!(cache.get_address(&testnet, &name, now), None);
+
+        let expired = Timestamp::from_millis(1_000 + SUINS_CACHE_TTL_MS);
+        assert_eq!(cache.get_address(&mainnet, &name, expired), None);
+
+        cache.insert_address(&testnet, name.clone(), None, now);
+        assert_eq!(cache.get_address(&testnet, &name, now), Some(None));
+
+        cache.prune(expired);
+        assert_eq!(cache.get_address(&mainnet, &name, now), None);
+    }
+
+    #[test]
+    fn test_changed() {
+        let url = RpcUrl::from_str("https://fullnode.mainnet.sui.io:443").unwrap();
+        let now = Timestamp::from_millis(1_000);
+
+        let mut cache = SuiNsCache::default();
+        assert!(!cache.is_changed());
+
+        cache.insert_name(&url, SuiAddress::random_for_testing_only(), None, now);
+        assert!(cache.is_changed());
+
+        cache.clear_changed();
+        cache.prune(now);
+        assert!(!cache.is_changed());
+
+        cache.prune(Timestamp::from_millis(1_000 + SUINS_CACHE_TTL_MS));
+        assert!(cache.is_changed());
+
+        let yaml = serde_yaml::to_string(&cache).unwrap();
+        assert!(!serde_yaml::from_str::<SuiNsCache>(&yaml)
+            .unwrap()
+            .is_changed());
+    }
+
+    #[test]
+    fn test_name_expiry() {
+        let url = RpcUrl::from_str("https://fullnode.mainnet.sui.io:443").unwrap();
+        let name = SuiNsName::from_str("alice.sui").unwrap();
+        let address = SuiAddress::random_for_testing_only();
+        let now = Timestamp::from_millis(1_000);
+
+        let mut cache = SuiNsCache::default();
+        cache.insert_name(&url, address, Some(name.clone()), now);
+
+        assert_eq!(cache.get_name(&url, &address, now), Some(Some(name)));
+        assert_eq!(
+            cache.get_name(
+                &url,
+                &address,
+                Timestamp::from_millis(1_000 + SUINS_CACHE_TTL_MS)
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A SuiNS domain such as `alice.sui` or `pay.alice.sui`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SuiNsName(String);
+
+impl fmt::Display for SuiNsName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for SuiNsName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase();
+        let labels = name
+            .strip_suffix(".sui")
+            .ok_or(Error::InvalidSuiNsName(s.to_string()))?
+            .split('.')
+            .collect::<Vec<&str>>();
+
+        let valid = labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        });
+
+        if valid {
+            Ok(Self(name))
+        } else {
+            Err(Error::InvalidSuiNsName(s.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SuiNsName;
+    use crate::error::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        let cases = vec![
+            ("alice.sui", "alice.sui"),
+            ("Alice.SUI", "alice.sui"),
+            ("pay.alice.sui", "pay.alice.sui"),
+            ("my-name1.sui", "my-name1.sui"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(SuiNsName::from_str(input).unwrap().to_string(), expected);
+        }
+
+        for input in [
+            "alice",
+            ".sui",
+            "alice..sui",
+            "-alice.sui",
+            "al_ice.sui",
+            "alice.eth",
+        ] {
+            assert!(matches!(
+                SuiNsName::from_str(input),
+                Err(Error::InvalidSuiNsName(_))
+            ));
+        }
+    }
+}
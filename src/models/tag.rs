@@ -2,25 +2,93 @@ use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+/// Tags may be namespaced as `parent/child`; each segment follows the rules
+/// of a flat tag, so existing flat tags stay valid.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Tag(String);
 
+pub const TAG_SEPARATOR: char = '/';
+
+fn validate_segment(segment: &str) -> Result<()> {
+    if segment.len() > 10 {
+        return Err(Error::NameTooLong(10));
+    }
+
+    if !segment
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(Error::NameInvalidCharacters(
+            "lowercase letters, numbers, or underscores".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 impl Tag {
     pub fn new(name: &str) -> Result<Self> {
-        if name.len() > 10 {
-            return Err(Error::NameTooLong(10));
+        if name.contains(TAG_SEPARATOR) {
+            for segment in name.split(TAG_SEPARATOR) {
+                if segment.is_empty() {
+                    return Err(Error::NameInvalidCharacters(
+                        "non-empty segments between '/' separators".to_string(),
+                    ));
+                }
+                validate_segment(segment)?;
+            }
+        } else {
+            validate_segment(name)?;
         }
 
-        if !name
-            .chars()
-            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
-        {
-            return Err(Error::NameInvalidCharacters(
-                "lowercase letters, numbers, or underscores".to_string(),
-            ));
+        Ok(Self(name.to_string()))
+    }
+
+    /// Last path segment, e.g. `qa` for `team_a/qa`.
+    pub fn get_name(&self) -> &str {
+        self.0
+            .rsplit_once(TAG_SEPARATOR)
+            .map_or(self.0.as_str(), |(_, name)| name)
+    }
+
+    pub fn get_parent(&self) -> Option<Tag> {
+        self.0
+            .rsplit_once(TAG_SEPARATOR)
+            .map(|(parent, _)| Self(parent.to_string()))
+    }
+
+    /// Parents from the root down, excluding the tag itself.
+    pub fn get_ancestors(&self) -> Vec<Tag> {
+        let mut ancestors = Vec::new();
+        let mut parent = self.get_parent();
+        while let Some(tag) = parent {
+            parent = tag.get_parent();
+            ancestors.push(tag);
         }
+        ancestors.reverse();
+        ancestors
+    }
 
-        Ok(Self(name.to_string()))
+    pub fn get_depth(&self) -> usize {
+        self.0.matches(TAG_SEPARATOR).count()
+    }
+
+    /// Whether this tag is `ancestor` itself or one of its descendants.
+    pub fn is_within(&self, ancestor: &Tag) -> bool {
+        self == ancestor
+            || self
+                .0
+                .strip_prefix(ancestor.0.as_str())
+                .is_some_and(|rest| rest.starts_with(TAG_SEPARATOR))
+    }
+
+    /// Moves this tag from the subtree at `from` to the same place under `to`.
+    pub fn reparent(&self, from: &Tag, to: &Tag) -> Option<Tag> {
+        if self.is_within(from) {
+            Some(Self(format!("{}{}", to.0, &self.0[from.0.len()..])))
+        } else {
+            None
+        }
     }
 }
 
@@ -50,6 +118,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_hierarchical_tags() {
+        let tag = Tag::new("team_a/qa/bots").unwrap();
+        assert_eq!(tag.get_name(), "bots");
+        assert_eq!(tag.get_depth(), 2);
+        assert_eq!(tag.get_parent(), Some(Tag::new("team_a/qa").unwrap()));
+        assert_eq!(
+            tag.get_ancestors(),
+            vec![Tag::new("team_a").unwrap(), Tag::new("team_a/qa").unwrap()]
+        );
+        assert_eq!(Tag::new("flat").unwrap().get_parent(), None);
+
+        assert!(tag.is_within(&Tag::new("team_a").unwrap()));
+        assert!(tag.is_within(&tag));
+        assert!(!tag.is_within(&Tag::new("team").unwrap()));
+        assert!(!Tag::new("team_a").unwrap().is_within(&tag));
+
+        assert_eq!(
+            tag.reparent(&Tag::new("team_a").unwrap(), &Tag::new("squad/b").unwrap()),
+            Some(Tag::new("squad/b/qa/bots").unwrap())
+        );
+        assert_eq!(
+            tag.reparent(&Tag::new("team_b").unwrap(), &Tag::new("x").unwrap()),
+            None
+        );
+
+        assert!(matches!(
+            Tag::new("team_a//qa"),
+            Err(Error::NameInvalidCharacters(_))
+        ));
+        assert!(matches!(
+            Tag::new("team_a/"),
+            Err(Error::NameInvalidCharacters(_))
+        ));
+        assert!(matches!(
+            Tag::new("team_a/too_long_name"),
+            Err(Error::NameTooLong(10))
+        ));
+    }
+
     #[test]
     fn test_invalid_characters() {
         let invalid_inputs = vec![
@@ -0,0 +1,60 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagColor {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl TagColor {
+    /// prettytable foreground style for this color.
+    pub fn get_style_spec(&self) -> &'static str {
+        match self {
+            TagColor::Red => "Fr",
+            TagColor::Green => "Fg",
+            TagColor::Yellow => "Fy",
+            TagColor::Blue => "Fb",
+            TagColor::Magenta => "Fm",
+            TagColor::Cyan => "Fc",
+            TagColor::White => "Fw",
+        }
+    }
+}
+
+impl fmt::Display for TagColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagColor::Red => write!(f, "red"),
+            TagColor::Green => write!(f, "green"),
+            TagColor::Yellow => write!(f, "yellow"),
+            TagColor::Blue => write!(f, "blue"),
+            TagColor::Magenta => write!(f, "magenta"),
+            TagColor::Cyan => write!(f, "cyan"),
+            TagColor::White => write!(f, "white"),
+        }
+    }
+}
+
+impl FromStr for TagColor {
+    type Err = Error;
+
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        match color.to_lowercase().as_str() {
+            "red" => Ok(TagColor::Red),
+            "green" => Ok(TagColor::Green),
+            "yellow" => Ok(TagColor::Yellow),
+            "blue" => Ok(TagColor::Blue),
+            "magenta" => Ok(TagColor::Magenta),
+            "cyan" => Ok(TagColor::Cyan),
+            "white" => Ok(TagColor::White),
+            _ => Err(Error::InvalidTagColor(color.to_string())),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::tag_color::TagColor;
+use serde::{Deserialize, Serialize};
+
+/// Optional presentation metadata for a registered tag.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagDetails {
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    color: Option<TagColor>,
+}
+
+impl TagDetails {
+    pub fn new(description: Option<String>, color: Option<TagColor>) -> Self {
+        Self { description, color }
+    }
+
+    pub fn get_description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    pub fn mut_description(&mut self) -> &mut Option<String> {
+        &mut self.description
+    }
+
+    pub fn get_color(&self) -> &Option<TagColor> {
+        &self.color
+    }
+
+    pub fn mut_color(&mut self) -> &mut Option<TagColor> {
+        &mut self.color
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.color.is_none()
+    }
+}
@@ -0,0 +1,247 @@
+use super::{
+    tag::{Tag, TAG_SEPARATOR},
+    tag_list::TagList,
+};
+use crate::error::{Error, Result};
+use std::{fmt, iter::Peekable, str::CharIndices, str::FromStr};
+
+/// Boolean expression over wallet tags, as accepted by `--tags`.
+///
+/// `,` is AND, `|` is OR, `!` is NOT and parentheses group; AND binds
+/// tighter than OR, so `qa,bot|prod` means `(qa,bot)|prod`. A tag also
+/// matches its descendants, so `team_a` matches `team_a/qa`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagFilter {
+    Tag(Tag),
+    Not(Box<TagFilter>),
+    And(Vec<TagFilter>),
+    Or(Vec<TagFilter>),
+}
+
+impl TagFilter {
+    pub fn matches(&self, tags: &TagList) -> bool {
+        match self {
+            Self::Tag(tag) => tags.contains_within(tag),
+            Self::Not(filter) => !filter.matches(tags),
+            Self::And(filters) => filters.iter().all(|filter| filter.matches(tags)),
+            Self::Or(filters) => filters.iter().any(|filter| filter.matches(tags)),
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.char_indices().peekable(),
+        }
+    }
+
+    fn error(&self, message: &str, position: usize) -> Error {
+        Error::InvalidTagFilter(format!(
+            "{} at position {} in '{}'",
+            message,
+            position + 1,
+            self.input
+        ))
+    }
+
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while let Some((_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+        self.chars.peek().copied()
+    }
+
+    fn parse(mut self) -> Result<TagFilter> {
+        let filter = self.parse_or()?;
+        match self.peek() {
+            None => Ok(filter),
+            Some((position, ')')) => Err(self.error("unmatched ')'", position)),
+            Some((position, c)) => Err(self.error(&format!("unexpected '{}'", c), position)),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<TagFilter> {
+        let mut filters = vec![self.parse_and()?];
+        while let Some((_, '|')) = self.peek() {
+            self.chars.next();
+            filters.push(self.parse_and()?);
+        }
+        Ok(if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            TagFilter::Or(filters)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<TagFilter> {
+        let mut filters = vec![self.parse_unary()?];
+        while let Some((_, ',')) = self.peek() {
+            self.chars.next();
+            filters.push(self.parse_unary()?);
+        }
+        Ok(if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            TagFilter::And(filters)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<TagFilter> {
+        match self.peek() {
+            Some((_, '!')) => {
+                self.chars.next();
+                Ok(TagFilter::Not(Box::new(self.parse_unary()?)))
+            }
+            Some((open, '(')) => {
+                self.chars.next();
+                let filter = self.parse_or()?;
+                match self.peek() {
+                    Some((_, ')')) => {
+                        self.chars.next();
+                        Ok(filter)
+                    }
+                    _ => Err(self.error("unclosed '('", open)),
+                }
+            }
+            Some((start, c)) if c.is_ascii_alphanumeric() || c == '_' => {
+                let mut end = start;
+                while let Some(&(position, c)) = self.chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_' || c == TAG_SEPARATOR) {
+                        break;
+                    }
+                    end = position + c.len_utf8();
+                    self.chars.next();
+                }
+                Tag::new(&self.input[start..end])
+                    .map(TagFilter::Tag)
+                    .map_err(|e| self.error(&e.to_string(), start))
+            }
+            Some((position, c)) => {
+                Err(self.error(&format!("expected a tag but found '{}'", c), position))
+            }
+            None => Err(self.error("expected a tag", self.input.len())),
+        }
+    }
+}
+
+impl FromStr for TagFilter {
+    type Err = Error;
+
+    fn from_str(filter: &str) -> Result<Self> {
+        Parser::new(filter).parse()
+    }
+}
+
+impl fmt::Display for TagFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tag(tag) => write!(f, "{}", tag),
+            Self::Not(filter) => match filter.as_ref() {
+                Self::Tag(_) | Self::Not(_) => write!(f, "!{}", filter),
+                _ => write!(f, "!({})", filter),
+            },
+            Self::And(filters) => {
+                let parts = filters
+                    .iter()
+                    .map(|filter| match filter {
+                        Self::Or(_) => format!("({})", filter),
+                        _ => filter.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{}", parts.join(","))
+            }
+            Self::Or(filters) => {
+                let parts = filters.iter().map(|filter| filter.to_string());
+                write!(f, "{}", parts.collect::<Vec<_>>().join("|"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TagFilter;
+    use crate::{error::Error, models::tag_list::TagList};
+    use std::str::FromStr;
+
+    fn matches(filter: &str, tags: &str) -> bool {
+        TagFilter::from_str(filter)
+            .unwrap()
+            .matches(&TagList::from_str(tags).unwrap())
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("qa,bot", "qa,bot,testnet"));
+        assert!(!matches("qa,bot", "qa"));
+        assert!(matches("qa|prod", "prod"));
+        assert!(!matches("qa|prod", "bot"));
+        assert!(matches("!archived", "qa"));
+        assert!(!matches("!archived", "qa,archived"));
+        assert!(matches("testnet,bot,!archived", "testnet,bot"));
+        assert!(!matches("testnet,bot,!archived", "testnet,bot,archived"));
+        assert!(matches("(qa|prod),!archived", "prod"));
+        assert!(!matches("!(qa|prod)", "qa"));
+        assert!(matches("team_a", "team_a/qa"));
+        assert!(matches("team_a/qa", "team_a/qa/bots"));
+        assert!(!matches("team_a/qa", "team_a/prod"));
+        assert!(matches("team_a,!team_a/prod", "team_a/qa"));
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(
+            TagFilter::from_str("qa,bot|prod").unwrap(),
+            TagFilter::from_str("(qa,bot)|prod").unwrap()
+        );
+        assert!(matches("qa,bot|prod", "prod"));
+        assert!(!matches("qa,(bot|prod)", "prod"));
+        assert!(matches("!!qa", "qa"));
+    }
+
+    #[test]
+    fn test_display() {
+        for filter in [
+            "qa,bot",
+            "qa|prod",
+            "!archived",
+            "(qa|prod),!(bot,x)",
+            "a,b|c",
+        ] {
+            assert_eq!(TagFilter::from_str(filter).unwrap().to_string(), filter);
+        }
+        assert_eq!(
+            TagFilter::from_str(" qa , ( bot ) ").unwrap().to_string(),
+            "qa,bot"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |filter: &str| match TagFilter::from_str(filter) {
+            Err(Error::InvalidTagFilter(message)) => message,
+            _ => panic!("expected InvalidTagFilter for {}", filter),
+        };
+
+        assert_eq!(error(""), "expected a tag at position 1 in ''");
+        assert_eq!(
+            error("qa,|bot"),
+            "expected a tag but found '|' at position 4 in 'qa,|bot'"
+        );
+        assert_eq!(error("(qa|bot"), "unclosed '(' at position 1 in '(qa|bot'");
+        assert_eq!(error("qa)"), "unmatched ')' at position 3 in 'qa)'");
+        assert_eq!(error("qa bot"), "unexpected 'b' at position 4 in 'qa bot'");
+        assert!(error("QA").contains("position 1"));
+        assert!(error("qa,way_too_long_tag").contains("position 4"));
+    }
+}
@@ -1,11 +1,21 @@
 use super::tag::Tag;
 use crate::error::Error;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use std::{collections::HashSet, str::FromStr};
 
-#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct TagList(HashSet<Tag>);
 
+/// Serialized in sorted order, so the stored vault is stable across writes.
+impl Serialize for TagList {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut tags = self.0.iter().collect::<Vec<_>>();
+        tags.sort();
+
+        serializer.collect_seq(tags)
+    }
+}
+
 impl TagList {
     pub fn iter(&self) -> impl Iterator<Item = &Tag> {
         self.0.iter()
@@ -14,6 +24,11 @@ impl TagList {
         self.0.contains(tag)
     }
 
+    /// Whether any tag is `ancestor` or nested under it.
+    pub fn contains_within(&self, ancestor: &Tag) -> bool {
+        self.0.iter().any(|tag| tag.is_within(ancestor))
+    }
+
     pub fn contains_all(&self, tags: &Self) -> bool {
         tags.iter().all(|tag| self.contains(tag))
     }
@@ -25,6 +40,10 @@ impl TagList {
         list.join(separator)
     }
 
+    pub fn insert(&mut self, tag: Tag) -> bool {
+        self.0.insert(tag)
+    }
+
     pub fn extend(&mut self, tags: &Self) {
         self.0.extend(tags.iter().cloned());
     }
@@ -35,6 +54,16 @@ impl TagList {
         }
     }
 
+    /// Swaps `old` for `new` if present, returning whether anything changed.
+    pub fn rename(&mut self, old: &Tag, new: &Tag) -> bool {
+        if self.0.remove(old) {
+            self.0.insert(new.clone());
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -86,5 +115,25 @@ mod tests {
         assert!(list.contains(&Tag::new("tag1").unwrap()));
         assert!(!list.contains(&Tag::new("tag2").unwrap()));
         assert!(!list.contains(&Tag::new("tag3").unwrap()));
+
+        assert!(list.rename(&Tag::new("tag1").unwrap(), &Tag::new("tag5").unwrap()));
+        assert!(!list.rename(&Tag::new("tag1").unwrap(), &Tag::new("tag6").unwrap()));
+        assert_eq!(list.join(","), "tag5".to_string());
+
+        let list = TagList::from_str("team_a/qa,flat").unwrap();
+        assert!(list.contains_within(&Tag::new("team_a").unwrap()));
+        assert!(list.contains_within(&Tag::new("team_a/qa").unwrap()));
+        assert!(!list.contains_within(&Tag::new("team_a/prod").unwrap()));
+        assert!(!list.contains(&Tag::new("team_a").unwrap()));
+    }
+
+    #[test]
+    fn test_serialize_sorted() {
+        let list = TagList::from_str("zeta,alpha,team_a/qa,beta").unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&list).unwrap(),
+            r#"["alpha","beta","team_a/qa","zeta"]"#
+        );
     }
 }
@@ -0,0 +1,103 @@
+use crate::error::Error;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Milliseconds since the Unix epoch, as used by checkpoints and events.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    pub fn from_millis(millis: u64) -> Self {
+        Self(millis)
+    }
+
+    pub fn now() -> Self {
+        Self(Utc::now().timestamp_millis() as u64)
+    }
+
+    pub fn get_millis(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match DateTime::<Utc>::from_timestamp_millis(self.0 as i64) {
+            Some(datetime) => write!(
+                f,
+                "{}",
+                datetime.to_rfc3339_opts(SecondsFormat::Millis, true)
+            ),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    /// Accepts epoch milliseconds, RFC 3339 (`2024-01-31T12:00:00Z`) or a UTC date (`2024-01-31`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(millis) = u64::from_str(s) {
+            return Ok(Self(millis));
+        }
+
+        let millis = DateTime::parse_from_rfc3339(s)
+            .map(|datetime| datetime.timestamp_millis())
+            .or_else(|_| {
+                NaiveDate::parse_from_str(s, "%Y-%m-%d").map(|date| {
+                    date.and_hms_opt(0, 0, 0)
+                        .unwrap()
+                        .and_utc()
+                        .timestamp_millis()
+                })
+            })
+            .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
+
+        u64::try_from(millis)
+            .map(Self)
+            .map_err(|_| Error::InvalidTimestamp(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timestamp;
+    use crate::error::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        let cases = vec![
+            ("1706702400000", 1_706_702_400_000),
+            ("2024-01-31T12:00:00Z", 1_706_702_400_000),
+            ("2024-01-31T21:00:00+09:00", 1_706_702_400_000),
+            ("2024-01-31", 1_706_659_200_000),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                Timestamp::from_str(input).unwrap().get_millis(),
+                expected,
+                "{}",
+                input
+            );
+        }
+
+        for input in ["", "yesterday", "2024-13-01", "1969-12-31"] {
+            assert!(matches!(
+                Timestamp::from_str(input),
+                Err(Error::InvalidTimestamp(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Timestamp::from_millis(1_706_702_400_123).to_string(),
+            "2024-01-31T12:00:00.123Z"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::Error;
+use move_core_types::language_storage::TypeTag;
+use std::{fmt, str::FromStr};
+use sui_sdk::types::parse_sui_type_tag;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeArgument(TypeTag);
+
+impl TypeArgument {
+    pub fn get_type_tag(&self) -> &TypeTag {
+        &self.0
+    }
+}
+
+impl fmt::Display for TypeArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TypeArgument {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_sui_type_tag(s)
+            .map(Self)
+            .map_err(|_| Error::InvalidTypeArgument(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypeArgument;
+    use crate::error::Error;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        let cases = vec![
+            "u64",
+            "address",
+            "0x2::sui::SUI",
+            "vector<0x2::coin::Coin<0x2::sui::SUI>>",
+        ];
+
+        for case in cases {
+            let type_argument = TypeArgument::from_str(case).unwrap();
+            assert_eq!(
+                TypeArgument::from_str(&type_argument.to_string()).unwrap(),
+                type_argument
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            TypeArgument::from_str("0x2::sui::"),
+            Err(Error::InvalidTypeArgument(_))
+        ));
+    }
+}
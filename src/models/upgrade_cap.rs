@@ -0,0 +1,76 @@
+use std::str::FromStr;
+use sui_sdk::{rpc_types::SuiObjectData, types::base_types::ObjectID};
+
+/// `0x2::package::UpgradeCap` as read from an owned object's parsed content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpgradeCap {
+    object_id: ObjectID,
+    package: ObjectID,
+    version: u64,
+    policy: u8,
+}
+
+impl UpgradeCap {
+    pub fn new(object_id: ObjectID, package: ObjectID, version: u64, policy: u8) -> Self {
+        Self {
+            object_id,
+            package,
+            version,
+            policy,
+        }
+    }
+
+    pub fn from_object_data(object: &SuiObjectData) -> Option<Self> {
+        let content = serde_json::to_value(object.content.as_ref()?).ok()?;
+        let fields = content.get("fields")?;
+
+        Some(Self::new(
+            object.object_id,
+            ObjectID::from_str(fields.get("package")?.as_str()?).ok()?,
+            u64::from_str(fields.get("version")?.as_str()?).ok()?,
+            u8::try_from(fields.get("policy")?.as_u64()?).ok()?,
+        ))
+    }
+
+    pub fn get_object_id(&self) -> &ObjectID {
+        &self.object_id
+    }
+
+    pub fn get_package(&self) -> &ObjectID {
+        &self.package
+    }
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn get_policy_name(&self) -> String {
+        match self.policy {
+            0 => "compatible".to_string(),
+            128 => "additive".to_string(),
+            192 => "dep_only".to_string(),
+            policy => policy.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpgradeCap;
+    use sui_sdk::types::base_types::ObjectID;
+
+    #[test]
+    fn test_get_policy_name() {
+        let cases = vec![
+            (0, "compatible"),
+            (128, "additive"),
+            (192, "dep_only"),
+            (255, "255"),
+        ];
+
+        for (policy, expected) in cases {
+            let upgrade_cap = UpgradeCap::new(ObjectID::random(), ObjectID::random(), 1, policy);
+            assert_eq!(upgrade_cap.get_policy_name(), expected);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::error::{Error, Result};
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use rand::RngCore;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+const ARCHIVE_VERSION: u32 = 1;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// A payload encrypted under a passphrase, independent of `CIPHER_KEY`.
+///
+/// The key is derived with Argon2id from the passphrase and a random salt; the
+/// version and salt are authenticated alongside the ciphertext.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VaultArchive {
+    version: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl VaultArchive {
+    pub fn seal<T: Serialize>(payload: &T, passphrase: &str) -> Result<Self> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let mut archive = Self {
+            version: ARCHIVE_VERSION,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: String::new(),
+        };
+        let ciphertext = derive_cipher(passphrase, &salt)?
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &serde_json::to_vec(payload)?,
+                    aad: archive.get_header().as_bytes(),
+                },
+            )
+            .map_err(Error::CipherCryptoError)?;
+        archive.ciphertext = hex::encode(ciphertext);
+
+        Ok(archive)
+    }
+
+    pub fn open<T: DeserializeOwned>(&self, passphrase: &str) -> Result<T> {
+        if self.version != ARCHIVE_VERSION {
+            return Err(Error::BackupCorrupted(format!(
+                "unsupported archive version {}",
+                self.version
+            )));
+        }
+
+        let nonce = hex::decode(&self.nonce)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(Error::BackupCorrupted("invalid nonce".to_string()));
+        }
+
+        let plaintext = derive_cipher(passphrase, &hex::decode(&self.salt)?)?
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &hex::decode(&self.ciphertext)?,
+                    aad: self.get_header().as_bytes(),
+                },
+            )
+            .map_err(|_| Error::BackupDecryptionFailed)?;
+
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    fn get_header(&self) -> String {
+        format!("sui_wallet_backup:{}:{}", self.version, self.salt)
+    }
+}
+
+fn derive_cipher(passphrase: &str, salt: &[u8]) -> Result<ChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| Error::BackupCorrupted(e.to_string()))?;
+
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VaultArchive;
+    use crate::error::Error;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_vault_archive() {
+        let payload = json!({ "wallets": ["0x1", "0x2"] });
+        let archive = VaultArchive::seal(&payload, "correct horse").unwrap();
+
+        assert_eq!(archive.open::<Value>("correct horse").unwrap(), payload);
+        assert!(matches!(
+            archive.open::<Value>("wrong horse"),
+            Err(Error::BackupDecryptionFailed)
+        ));
+
+        let other = VaultArchive::seal(&payload, "correct horse").unwrap();
+        assert_ne!(archive.salt, other.salt);
+        assert_ne!(archive.ciphertext, other.ciphertext);
+    }
+
+    #[test]
+    fn test_vault_archive_tampered() {
+        let archive = VaultArchive::seal(&json!({ "tags": ["a"] }), "passphrase").unwrap();
+
+        let mut tampered = archive.clone();
+        let mut ciphertext = hex::decode(&tampered.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        tampered.ciphertext = hex::encode(ciphertext);
+        assert!(matches!(
+            tampered.open::<Value>("passphrase"),
+            Err(Error::BackupDecryptionFailed)
+        ));
+
+        let mut tampered = archive.clone();
+        tampered.version = 2;
+        assert!(matches!(
+            tampered.open::<Value>("passphrase"),
+            Err(Error::BackupCorrupted(_))
+        ));
+    }
+}
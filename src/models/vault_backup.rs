@@ -0,0 +1,195 @@
+use super::{
+    cipher::Cipher, schema_migration, timestamp::Timestamp, wallet_confy::WalletConfy,
+    wallet_secret::WalletSecret,
+};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use sui_sdk::types::base_types::SuiAddress;
+
+/// Everything in the vault, with credentials decrypted out of the wallets so
+/// the backup can be restored under a different `CIPHER_KEY`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VaultBackup {
+    created_at: Timestamp,
+    confy: WalletConfy,
+    secrets: HashMap<SuiAddress, WalletSecret>,
+}
+
+impl VaultBackup {
+    pub fn new(mut confy: WalletConfy, cipher: &Cipher, created_at: Timestamp) -> Result<Self> {
+        let mut secrets = HashMap::new();
+        for wallet in confy.mut_wallets().values_mut() {
+            if let Some(secret) = wallet.get_secret(cipher)? {
+                secrets.insert(*wallet.get_address(), secret);
+            }
+            wallet.clear_credentials();
+        }
+
+        Ok(Self {
+            created_at,
+            confy,
+            secrets,
+        })
+    }
+
+    /// Reads a decrypted backup, upgrading the vault inside it to the current
+    /// schema like a stored one.
+    pub fn from_value(mut value: Value) -> Result<Self> {
+        let confy = value
+            .get_mut("confy")
+            .ok_or_else(|| Error::BackupCorrupted("missing vault".to_string()))?;
+        schema_migration::migrate(confy)?;
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    pub fn get_created_at(&self) -> &Timestamp {
+        &self.created_at
+    }
+
+    pub fn get_confy(&self) -> &WalletConfy {
+        &self.confy
+    }
+
+    pub fn get_secret_count(&self) -> usize {
+        self.secrets.len()
+    }
+
+    /// Checks that every secret belongs to a wallet in the backup and derives
+    /// that wallet's address.
+    pub fn verify(&self) -> Result<()> {
+        for (address, secret) in self.secrets.iter() {
+            if self.confy.get_wallets().get_by_key(address).is_none() {
+                return Err(Error::BackupCorrupted(format!(
+                    "credentials for unknown wallet {}",
+                    address
+                )));
+            }
+            if secret.get_address()? != *address {
+                return Err(Error::BackupCorrupted(format!(
+                    "credentials for {} belong to a different address",
+                    address
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Re-encrypts the credentials under `cipher`.
+    pub fn into_confy(self, cipher: &Cipher) -> Result<WalletConfy> {
+        self.verify()?;
+
+        let mut confy = self.confy;
+        for wallet in confy.mut_wallets().values_mut() {
+            if let Some(secret) = self.secrets.get(wallet.get_address()) {
+                wallet.set_secret(secret, cipher)?;
+            }
+        }
+
+        Ok(confy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VaultBackup;
+    use crate::{
+        error::Error,
+        models::{
+            alias::Alias, cipher::Cipher, schema_migration::SCHEMA_VERSION, tag_list::TagList,
+            timestamp::Timestamp, wallet::Wallet, wallet_confy::WalletConfy,
+        },
+    };
+    use serde_json::json;
+    use sui_keys::key_derive::generate_new_key;
+    use sui_sdk::types::{base_types::SuiAddress, crypto::SignatureScheme};
+
+    #[test]
+    fn test_vault_backup() {
+        unsafe {
+            std::env::set_var(
+                "CIPHER_KEY",
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            );
+            std::env::set_var("CIPHER_NONCE", "000102030405060708090a0b");
+        }
+
+        let (address, key_pair, scheme, phrase) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+        let watched = SuiAddress::random_for_testing_only();
+
+        let mut confy = WalletConfy::default();
+        confy
+            .add_wallet(
+                Wallet::new(
+                    address,
+                    Some(Alias::new("main").unwrap()),
+                    TagList::default(),
+                )
+                .with_credentials(key_pair.copy(), scheme, phrase.clone())
+                .unwrap(),
+            )
+            .unwrap();
+        confy
+            .add_wallet(Wallet::new(watched, None, TagList::default()))
+            .unwrap();
+
+        let cipher = Cipher::load_from_env().unwrap();
+        let backup = VaultBackup::new(confy, &cipher, Timestamp::from_millis(0)).unwrap();
+        assert_eq!(backup.get_secret_count(), 1);
+        assert!(!backup
+            .get_confy()
+            .get_wallets()
+            .get_by_key(&address)
+            .unwrap()
+            .has_credentials());
+        assert!(backup.verify().is_ok());
+
+        let restored = backup.clone().into_confy(&cipher).unwrap();
+        let wallet = restored.get_wallets().get_by_key(&address).unwrap();
+        assert_eq!(wallet.get_key_pair(), Some(key_pair));
+        assert_eq!(wallet.get_phrase(), Some(phrase));
+        assert!(!restored
+            .get_wallets()
+            .get_by_key(&watched)
+            .unwrap()
+            .has_credentials());
+
+        let mut tampered = backup;
+        let secret = tampered.secrets.remove(&address).unwrap();
+        tampered.secrets.insert(watched, secret);
+        assert!(matches!(tampered.verify(), Err(Error::BackupCorrupted(_))));
+    }
+
+    #[test]
+    fn test_from_value() {
+        let backup = json!({
+            "created_at": 0,
+            "confy": { "tags": ["ops"] },
+            "secrets": {}
+        });
+        let backup = VaultBackup::from_value(backup).unwrap();
+        assert_eq!(
+            serde_json::to_value(backup.get_confy()).unwrap()["version"],
+            json!(SCHEMA_VERSION)
+        );
+
+        let backup = json!({
+            "created_at": 0,
+            "confy": { "version": SCHEMA_VERSION + 1 },
+            "secrets": {}
+        });
+        assert!(matches!(
+            VaultBackup::from_value(backup),
+            Err(Error::SchemaTooNew(_, _))
+        ));
+
+        assert!(matches!(
+            VaultBackup::from_value(json!({ "created_at": 0, "secrets": {} })),
+            Err(Error::BackupCorrupted(_))
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+use super::cipher::Cipher;
+use crate::error::{Error, Result};
+use serde_json::{Map, Value};
+
+const MAC: &str = "mac";
+const GENERATION: &str = "generation";
+
+/// Stores a MAC over the rest of the config under `mac`. Everything outside
+/// the credentials is plaintext, so this is what ties aliases, tags and
+/// addresses to the cipher key.
+///
+/// `generation` counts the writes and is signed along, so an older copy of
+/// the config can be told apart from the latest one.
+pub fn sign(value: &mut Value, cipher: &Cipher, generation: u64) -> Result<()> {
+    let Value::Object(config) = value else {
+        return Err(Error::StorageError("unexpected config layout".to_string()));
+    };
+    config.insert(GENERATION.to_string(), Value::from(generation));
+
+    let mac = hex::encode(cipher.mac(&canonical_bytes(value))?);
+    if let Value::Object(config) = value {
+        config.insert(MAC.to_string(), Value::String(mac));
+    }
+
+    Ok(())
+}
+
+/// Returns the signed generation, 0 for configs signed before it existed.
+pub fn verify(value: &Value, cipher: &Cipher) -> Result<u64> {
+    let mac = match value.get(MAC) {
+        Some(Value::String(mac)) => hex::decode(mac).map_err(|_| Error::VaultTampered)?,
+        Some(_) => return Err(Error::VaultTampered),
+        None => return Err(Error::VaultNotSigned),
+    };
+
+    if !cipher.verify_mac(&canonical_bytes(value), &mac)? {
+        return Err(Error::VaultTampered);
+    }
+
+    match value.get(GENERATION) {
+        Some(generation) => generation.as_u64().ok_or(Error::VaultTampered),
+        None => Ok(0),
+    }
+}
+
+/// JSON with object keys sorted and the MAC itself left out, so the result
+/// doesn't depend on how the backend laid the config out.
+fn canonical_bytes(value: &Value) -> Vec<u8> {
+    let mut out = String::new();
+    match value {
+        Value::Object(config) => {
+            let mut config = config.clone();
+            config.remove(MAC);
+            write_object(&config, &mut out);
+        }
+        value => write_canonical(value, &mut out),
+    }
+
+    out.into_bytes()
+}
+
+fn write_object(object: &Map<String, Value>, out: &mut String) {
+    let mut keys = object.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    out.push('{');
+    for (index, key) in keys.into_iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(&Value::String(key.clone()).to_string());
+        out.push(':');
+        write_canonical(&object[key], out);
+    }
+    out.push('}');
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(object) => write_object(object, out),
+        Value::Array(values) => {
+            out.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out);
+            }
+            out.push(']');
+        }
+        value => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign, verify};
+    use crate::{
+        error::Error,
+        models::{
+            cipher::{Cipher, RandomlyGenerable},
+            wallet_confy::WalletConfy,
+        },
+    };
+    use serde_json::{json, Value};
+
+    fn signed(cipher: &Cipher) -> Value {
+        signed_as(cipher, 1)
+    }
+
+    fn signed_as(cipher: &Cipher, generation: u64) -> Value {
+        let mut value = json!({
+            "version": 1,
+            "wallets": {
+                "wallets": {},
+                "aliasses": { "treasury": "0x1" }
+            },
+            "tags": ["ops"]
+        });
+        sign(&mut value, cipher, generation).unwrap();
+        value
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let cipher = Cipher::generate_random();
+        let value = signed(&cipher);
+
+        assert!(value["mac"].is_string());
+        assert_eq!(verify(&value, &cipher).unwrap(), 1);
+
+        // Layout and key order of the stored file don't matter
+        let yaml = serde_yaml::to_string(&value).unwrap();
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(verify(&value, &cipher).unwrap(), 1);
+
+        let mut resigned = value.clone();
+        sign(&mut resigned, &cipher, 1).unwrap();
+        assert_eq!(resigned, value);
+    }
+
+    #[test]
+    fn test_verify_generation() {
+        let cipher = Cipher::generate_random();
+        assert_eq!(verify(&signed_as(&cipher, 7), &cipher).unwrap(), 7);
+
+        // The counter can't be bumped without the key
+        let mut value = signed_as(&cipher, 7);
+        value["generation"] = json!(8);
+        assert!(matches!(verify(&value, &cipher), Err(Error::VaultTampered)));
+
+        // Configs signed before the counter existed
+        let mut value = signed(&cipher);
+        let config = value.as_object_mut().unwrap();
+        config.remove("generation");
+        config.remove("mac");
+        let mac = hex::encode(cipher.mac(&super::canonical_bytes(&value)).unwrap());
+        value["mac"] = json!(mac);
+        assert_eq!(verify(&value, &cipher).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_verify_tampered() {
+        let cipher = Cipher::generate_random();
+
+        let mut value = signed(&cipher);
+        value["wallets"]["aliasses"]["treasury"] = json!("0x2");
+        assert!(matches!(verify(&value, &cipher), Err(Error::VaultTampered)));
+
+        let mut value = signed(&cipher);
+        value["tags"] = json!(["ops", "dev"]);
+        assert!(matches!(verify(&value, &cipher), Err(Error::VaultTampered)));
+
+        let mut value = signed(&cipher);
+        value["mac"] = json!("not hex");
+        assert!(matches!(verify(&value, &cipher), Err(Error::VaultTampered)));
+
+        let value = signed(&cipher);
+        assert!(matches!(
+            verify(&value, &Cipher::generate_random()),
+            Err(Error::VaultTampered)
+        ));
+    }
+
+    #[test]
+    fn test_verify_not_signed() {
+        let cipher = Cipher::generate_random();
+        let value = serde_json::to_value(WalletConfy::default()).unwrap();
+
+        assert!(matches!(
+            verify(&value, &cipher),
+            Err(Error::VaultNotSigned)
+        ));
+    }
+
+    #[test]
+    fn test_signed_config_deserializes() {
+        let cipher = Cipher::generate_random();
+        let mut value = serde_json::to_value(WalletConfy::default()).unwrap();
+        sign(&mut value, &cipher, 1).unwrap();
+
+        assert!(serde_json::from_value::<WalletConfy>(value).is_ok());
+    }
+}
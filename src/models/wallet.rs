@@ -1,9 +1,15 @@
-use super::{alias::Alias, tag_list::TagList, wallet_credential::WalletCredentials};
-use crate::error::Result;
+use super::{
+    alias::Alias, cipher::Cipher, tag_list::TagList, timestamp::Timestamp,
+    wallet_credential::WalletCredentials, wallet_metadata::WalletMetadata,
+    wallet_secret::WalletSecret,
+};
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
+use shared_crypto::intent::{Intent, IntentMessage};
 use sui_sdk::types::{
     base_types::SuiAddress,
-    crypto::{SignatureScheme, SuiKeyPair},
+    crypto::{Signature, SignatureScheme, SuiKeyPair},
+    transaction::{Transaction, TransactionData},
 };
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -12,6 +18,16 @@ pub struct Wallet {
     credentials: Option<WalletCredentials>,
     alias: Option<Alias>,
     tags: TagList,
+    #[serde(default)]
+    note: Option<String>,
+    #[serde(default)]
+    metadata: WalletMetadata,
+    #[serde(default)]
+    created_at: Option<Timestamp>,
+    #[serde(default)]
+    imported_at: Option<Timestamp>,
+    #[serde(default)]
+    last_used_at: Option<Timestamp>,
 }
 
 impl Wallet {
@@ -21,9 +37,24 @@ impl Wallet {
             address,
             credentials: None,
             tags,
+            note: None,
+            metadata: WalletMetadata::default(),
+            created_at: None,
+            imported_at: None,
+            last_used_at: None,
         }
     }
 
+    pub fn with_created_at(mut self, created_at: Timestamp) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn with_imported_at(mut self, imported_at: Timestamp) -> Self {
+        self.imported_at = Some(imported_at);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         key_pair: SuiKeyPair,
@@ -54,10 +85,65 @@ impl Wallet {
         &mut self.alias
     }
 
+    pub fn get_note(&self) -> &Option<String> {
+        &self.note
+    }
+
+    pub fn mut_note(&mut self) -> &mut Option<String> {
+        &mut self.note
+    }
+
+    pub fn get_metadata(&self) -> &WalletMetadata {
+        &self.metadata
+    }
+
+    pub fn mut_metadata(&mut self) -> &mut WalletMetadata {
+        &mut self.metadata
+    }
+
+    pub fn get_created_at(&self) -> &Option<Timestamp> {
+        &self.created_at
+    }
+
+    pub fn get_imported_at(&self) -> &Option<Timestamp> {
+        &self.imported_at
+    }
+
+    pub fn get_last_used_at(&self) -> &Option<Timestamp> {
+        &self.last_used_at
+    }
+
+    pub fn mut_last_used_at(&mut self) -> &mut Option<Timestamp> {
+        &mut self.last_used_at
+    }
+
+    pub fn get_label(&self) -> String {
+        self.alias
+            .as_ref()
+            .map_or(self.address.to_string(), |alias| alias.to_string())
+    }
+
     pub fn contains_alias(&self, alias: &Alias) -> bool {
         self.alias.clone().map_or(false, |a| a.contains(alias))
     }
 
+    /// `None` for watch-only wallets imported by address.
+    pub fn get_key_scheme(&self) -> Option<&SignatureScheme> {
+        self.credentials
+            .as_ref()
+            .map(|credentials| credentials.get_key_scheme())
+    }
+
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_some()
+    }
+
+    pub fn has_mnemonic(&self) -> bool {
+        self.credentials
+            .as_ref()
+            .is_some_and(|credentials| credentials.has_mnemonic())
+    }
+
     pub fn get_key_pair(&self) -> Option<SuiKeyPair> {
         if let Some(ref credentials) = self.credentials {
             credentials.get_key_pair().ok()
@@ -66,6 +152,38 @@ impl Wallet {
         }
     }
 
+    pub fn sign_transaction(&self, transaction_data: TransactionData) -> Result<Transaction> {
+        let key_pair = self
+            .credentials
+            .as_ref()
+            .ok_or(Error::WalletCredentialsNotFound(self.address))?
+            .get_key_pair()?;
+
+        let signature = Signature::new_secure(
+            &IntentMessage::new(Intent::sui_transaction(), &transaction_data),
+            &key_pair,
+        );
+
+        Ok(Transaction::from_data(transaction_data, vec![signature]))
+    }
+
+    /// Decrypts the credentials with `cipher`; `None` for watch-only wallets.
+    pub fn get_secret(&self, cipher: &Cipher) -> Result<Option<WalletSecret>> {
+        self.credentials
+            .as_ref()
+            .map(|credentials| credentials.get_secret(cipher))
+            .transpose()
+    }
+
+    pub fn set_secret(&mut self, secret: &WalletSecret, cipher: &Cipher) -> Result<()> {
+        self.credentials = Some(WalletCredentials::from_secret(secret, cipher)?);
+        Ok(())
+    }
+
+    pub fn clear_credentials(&mut self) {
+        self.credentials = None;
+    }
+
     pub fn get_phrase(&self) -> Option<String> {
         self.credentials
             .as_ref()
@@ -81,10 +199,17 @@ impl Wallet {
 #[cfg(test)]
 mod tests {
     use super::Wallet;
-    use crate::models::{alias::Alias, tag_list::TagList};
+    use crate::{
+        error::Error,
+        models::{alias::Alias, tag_list::TagList, timestamp::Timestamp},
+    };
     use std::str::FromStr;
     use sui_keys::key_derive::generate_new_key;
-    use sui_sdk::types::crypto::SignatureScheme;
+    use sui_sdk::types::{
+        base_types::{random_object_ref, SuiAddress},
+        crypto::SignatureScheme,
+        transaction::TransactionData,
+    };
 
     #[test]
     fn test_new_wallet() {
@@ -99,6 +224,7 @@ mod tests {
         assert_eq!(wallet.get_address(), &address);
         assert!(wallet.get_tags().contains_all(&tag_list));
         assert!(wallet.get_alias().is_none());
+        assert_eq!(wallet.get_label(), address.to_string());
         assert!(!wallet.contains_alias(&alias));
         assert!(wallet.get_key_pair().is_none());
 
@@ -108,6 +234,7 @@ mod tests {
 
         assert!(wallet.get_tags().contains_all(&tag_list));
         assert_eq!(wallet.get_alias(), &Some(alias.clone()));
+        assert_eq!(wallet.get_label(), "test");
         assert!(wallet.contains_alias(&alias));
         assert!(!wallet.contains_alias(&Alias::new("test2").unwrap()));
 
@@ -118,4 +245,65 @@ mod tests {
         assert!(wallet.get_key_pair().is_none());
         assert_eq!(wallet_with_creds.get_key_pair(), Some(key_pair));
     }
+
+    #[test]
+    fn test_wallet_defaults_for_older_configs() {
+        let address = SuiAddress::random_for_testing_only();
+
+        let wallet: Wallet = serde_json::from_value(serde_json::json!({
+            "address": address,
+            "credentials": null,
+            "alias": "old",
+            "tags": [],
+        }))
+        .unwrap();
+
+        assert_eq!(wallet.get_address(), &address);
+        assert!(wallet.get_note().is_none());
+        assert!(wallet.get_metadata().is_empty());
+        assert!(wallet.get_created_at().is_none());
+        assert!(wallet.get_imported_at().is_none());
+        assert!(wallet.get_last_used_at().is_none());
+
+        let wallet = Wallet::new(address, None, TagList::default())
+            .with_imported_at(Timestamp::from_millis(1_700_000_000_000));
+        assert_eq!(
+            wallet.get_imported_at(),
+            &Some(Timestamp::from_millis(1_700_000_000_000))
+        );
+        assert!(wallet.get_created_at().is_none());
+    }
+
+    #[test]
+    fn test_sign_transaction() {
+        unsafe {
+            std::env::set_var(
+                "CIPHER_KEY",
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            );
+            std::env::set_var("CIPHER_NONCE", "000102030405060708090a0b");
+        }
+
+        let (address, key_pair, scheme, phrase) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+
+        let transaction_data = TransactionData::new_transfer_sui(
+            SuiAddress::random_for_testing_only(),
+            address,
+            Some(1),
+            random_object_ref(),
+            1_000_000,
+            1_000,
+        );
+
+        let wallet = Wallet::new(address, None, TagList::default());
+        assert!(matches!(
+            wallet.sign_transaction(transaction_data.clone()),
+            Err(Error::WalletCredentialsNotFound(_))
+        ));
+
+        let wallet = wallet.with_credentials(key_pair, scheme, phrase).unwrap();
+        let transaction = wallet.sign_transaction(transaction_data).unwrap();
+        assert_eq!(transaction.data().tx_signatures().len(), 1);
+    }
 }
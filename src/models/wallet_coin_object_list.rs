@@ -0,0 +1,107 @@
+use super::{coin_object_list::CoinObjectList, wallet::Wallet};
+
+#[derive(Default, Clone)]
+pub struct WalletCoinObjectList(Vec<(Wallet, CoinObjectList)>);
+
+impl WalletCoinObjectList {
+    pub fn add(&mut self, wallet: Wallet, coins: CoinObjectList) {
+        self.0.push((wallet, coins));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(Wallet, CoinObjectList)> {
+        self.0.iter()
+    }
+
+    pub fn merge(&self) -> CoinObjectList {
+        let mut merged = CoinObjectList::default();
+        for (_, coins) in self.0.iter() {
+            merged.extend(coins);
+        }
+        merged
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WalletCoinObjectList;
+    use crate::models::{coin_object_list::CoinObjectList, tag_list::TagList, wallet::Wallet};
+    use sui_json_rpc_types::{Coin, SuiCoinMetadata};
+    use sui_sdk::types::{
+        base_types::{ObjectID, SequenceNumber, SuiAddress},
+        digests::{ObjectDigest, TransactionDigest},
+    };
+
+    fn dummy_coin(coin_type: &str, balance: u64) -> Coin {
+        Coin {
+            coin_type: coin_type.to_string(),
+            coin_object_id: ObjectID::random(),
+            version: SequenceNumber::new(),
+            digest: ObjectDigest::new([0; 32]),
+            balance,
+            previous_transaction: TransactionDigest::default(),
+        }
+    }
+
+    #[test]
+    fn test_wallet_coin_object_list() {
+        let mut list = WalletCoinObjectList::default();
+        assert!(list.is_empty());
+
+        let dummy_metadata = SuiCoinMetadata {
+            decimals: 1,
+            name: "dummy".to_string(),
+            symbol: "DUM".to_string(),
+            description: "dummy".to_string(),
+            icon_url: None,
+            id: None,
+        };
+
+        let mut coins1 = CoinObjectList::default();
+        coins1.entry(dummy_coin("dummy", 100), dummy_metadata.clone());
+        coins1.entry(dummy_coin("other", 5), dummy_metadata.clone());
+
+        let mut coins2 = CoinObjectList::default();
+        coins2.entry(dummy_coin("dummy", 50), dummy_metadata.clone());
+
+        list.add(
+            Wallet::new(
+                SuiAddress::random_for_testing_only(),
+                None,
+                TagList::default(),
+            ),
+            coins1,
+        );
+        list.add(
+            Wallet::new(
+                SuiAddress::random_for_testing_only(),
+                None,
+                TagList::default(),
+            ),
+            coins2,
+        );
+        list.add(
+            Wallet::new(
+                SuiAddress::random_for_testing_only(),
+                None,
+                TagList::default(),
+            ),
+            CoinObjectList::default(),
+        );
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().count(), 3);
+
+        let merged = list.merge();
+        assert_eq!(merged.get_coin_objects().len(), 2);
+        assert_eq!(merged.get("dummy").unwrap().get_total_balance(), 150);
+        assert_eq!(merged.get("other").unwrap().get_total_balance(), 5);
+    }
+}
@@ -1,15 +1,41 @@
-use super::{rpc_server_list::RpcServerList, wallet::Wallet};
+use super::{
+    address_labels::AddressLabels, alias_or_address::AliasOrAddress, cipher::Cipher,
+    contact::Contact, contact_list::ContactList, merge_conflict::MergeConflict,
+    rpc_server_list::RpcServerList, schema_migration::SCHEMA_VERSION, tag::Tag,
+    tag_details::TagDetails, timestamp::Timestamp, wallet::Wallet, wallet_edit::WalletEdit,
+};
 use crate::{
     error::{Error, Result},
     models::{tag_list::TagList, wallet_list::WalletList},
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use sui_sdk::types::base_types::SuiAddress;
 
-#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct WalletConfy {
+    #[serde(default)]
+    version: u32,
     wallets: WalletList,
     tags: TagList,
     rpc_servers: RpcServerList,
+    #[serde(default)]
+    contacts: ContactList,
+    #[serde(default)]
+    tag_details: HashMap<Tag, TagDetails>,
+}
+
+impl Default for WalletConfy {
+    fn default() -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            wallets: WalletList::default(),
+            tags: TagList::default(),
+            rpc_servers: RpcServerList::default(),
+            contacts: ContactList::default(),
+            tag_details: HashMap::default(),
+        }
+    }
 }
 
 impl WalletConfy {
@@ -21,6 +47,225 @@ impl WalletConfy {
         self.wallets.add(wallet)
     }
 
+    pub fn add_contact(&mut self, contact: Contact) -> Result<()> {
+        if !self.tags.contains_all(contact.get_tags()) {
+            return Err(Error::TagNotFound);
+        }
+
+        self.contacts.add(contact)
+    }
+
+    /// Every tag the edit adds must be registered; removing any tag is allowed.
+    pub fn edit_wallet(
+        &mut self,
+        alias_or_address: &AliasOrAddress,
+        edit: &WalletEdit,
+    ) -> Result<()> {
+        if !self.tags.contains_all(&edit.get_added_tags()) {
+            return Err(Error::TagNotFound);
+        }
+
+        let address = *self
+            .wallets
+            .get_by_alias_or_address(alias_or_address)?
+            .get_address();
+
+        self.wallets.edit(address, edit)
+    }
+
+    /// Renames or moves a tag together with its whole subtree, on every wallet
+    /// and contact that carries one of them.
+    pub fn rename_tag(&mut self, old: &Tag, new: &Tag) -> Result<()> {
+        if new.is_within(old) {
+            return Err(Error::InvalidTagRename(format!(
+                "{} can't be moved under itself",
+                old
+            )));
+        }
+
+        let renames = self
+            .tags
+            .iter()
+            .filter_map(|tag| tag.reparent(old, new).map(|renamed| (tag.clone(), renamed)))
+            .collect::<Vec<_>>();
+        if renames.is_empty() {
+            return Err(Error::TagNotFound);
+        }
+        if let Some((_, renamed)) = renames
+            .iter()
+            .find(|(_, renamed)| self.tags.contains(renamed))
+        {
+            return Err(Error::TagAlreadyExists(renamed.to_string()));
+        }
+
+        for (from, to) in renames.iter() {
+            self.tags.rename(from, to);
+            for wallet in self.wallets.values_mut() {
+                wallet.mut_tags().rename(from, to);
+            }
+            for contact in self.contacts.values_mut() {
+                contact.mut_tags().rename(from, to);
+            }
+            if let Some(details) = self.tag_details.remove(from) {
+                self.tag_details.insert(to.clone(), details);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unregisters `tags` and strips them from every wallet and contact.
+    pub fn remove_tags(&mut self, tags: &TagList) {
+        for wallet in self.wallets.values_mut() {
+            wallet.mut_tags().remove(tags);
+        }
+        for contact in self.contacts.values_mut() {
+            contact.mut_tags().remove(tags);
+        }
+        for tag in tags.iter() {
+            self.tag_details.remove(tag);
+        }
+        self.tags.remove(tags);
+    }
+
+    /// Registers `tags` as they are in `source`: along with their ancestors
+    /// registered there, and their details unless this vault has its own.
+    pub fn import_tags(&mut self, tags: &TagList, source: &WalletConfy) {
+        for tag in tags.iter() {
+            let mut lineage = tag
+                .get_ancestors()
+                .into_iter()
+                .filter(|ancestor| source.tags.contains(ancestor))
+                .collect::<Vec<_>>();
+            lineage.push(tag.clone());
+
+            for tag in lineage {
+                if let Some(details) = source.tag_details.get(&tag) {
+                    self.tag_details
+                        .entry(tag.clone())
+                        .or_insert_with(|| details.clone());
+                }
+                self.tags.insert(tag);
+            }
+        }
+    }
+
+    /// Adds everything from `other` that doesn't clash with this vault and
+    /// reports what was skipped. Existing tag details win over incoming ones.
+    pub fn merge(&mut self, other: WalletConfy) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+
+        self.tags.extend(&other.tags);
+        for (tag, details) in other.tag_details {
+            self.tag_details.entry(tag).or_insert(details);
+        }
+
+        for wallet in other.wallets.get_wallets() {
+            let label = wallet.get_label();
+            if let Err(e) = self.wallets.add(wallet) {
+                conflicts.push(MergeConflict::new("wallet", label, e));
+            }
+        }
+
+        for contact in other.contacts.get_contacts() {
+            let label = format!("@{}", contact.get_alias());
+            if let Err(e) = self.contacts.add(contact) {
+                conflicts.push(MergeConflict::new("contact", label, e));
+            }
+        }
+
+        for rpc_server in other.rpc_servers.get_rpc_servers() {
+            let label = rpc_server.get_alias().to_string();
+            if let Err(e) = self.rpc_servers.add(rpc_server) {
+                conflicts.push(MergeConflict::new("rpc", label, e));
+            }
+        }
+
+        conflicts
+    }
+
+    /// Re-encrypts every wallet's credentials from `old` to `new`, checking
+    /// each key pair still derives its wallet's address before and after.
+    /// Leaves the vault untouched on error.
+    pub fn rotate_cipher(&mut self, old: &Cipher, new: &Cipher) -> Result<usize> {
+        let mut wallets = self.wallets.clone();
+        let mut count = 0;
+
+        for wallet in wallets.values_mut() {
+            let address = *wallet.get_address();
+            let Some(secret) = wallet.get_secret(old)? else {
+                continue;
+            };
+            if secret.get_address()? != address {
+                return Err(Error::KeyRotationMismatch(address));
+            }
+
+            wallet.set_secret(&secret, new)?;
+            let rotated = wallet
+                .get_secret(new)?
+                .ok_or(Error::KeyRotationMismatch(address))?;
+            if rotated.get_address()? != address || rotated.get_mnemonic() != secret.get_mnemonic()
+            {
+                return Err(Error::KeyRotationMismatch(address));
+            }
+
+            count += 1;
+        }
+
+        self.wallets = wallets;
+        Ok(count)
+    }
+
+    /// Stamps `last_used_at` on the wallets that just signed a transaction.
+    pub fn mark_used(&mut self, addresses: &[SuiAddress], now: Timestamp) {
+        for wallet in self.wallets.values_mut() {
+            if addresses.contains(wallet.get_address()) {
+                *wallet.mut_last_used_at() = Some(now);
+            }
+        }
+    }
+
+    /// Number of wallets carrying each registered tag or one of its
+    /// descendants. Unregistered parents of nested tags are included.
+    pub fn get_tag_usage(&self) -> HashMap<Tag, usize> {
+        let wallets = self.wallets.get_wallets();
+        self.tags
+            .iter()
+            .flat_map(|tag| tag.get_ancestors().into_iter().chain([tag.clone()]))
+            .map(|tag| {
+                let count = wallets
+                    .iter()
+                    .filter(|wallet| wallet.get_tags().contains_within(&tag))
+                    .count();
+                (tag, count)
+            })
+            .collect()
+    }
+
+    /// Resolves wallets and raw addresses like `WalletList`, plus `@contacts`.
+    pub fn resolve_address(&self, alias_or_address: &AliasOrAddress) -> Result<SuiAddress> {
+        match alias_or_address {
+            AliasOrAddress::Contact(_) => self
+                .contacts
+                .get(alias_or_address)
+                .map(|contact| *contact.get_address()),
+            _ => self.wallets.resolve_address(alias_or_address),
+        }
+    }
+
+    pub fn get_address_labels(&self) -> AddressLabels {
+        let mut labels = AddressLabels::default();
+        for contact in self.contacts.get_contacts() {
+            labels.insert(*contact.get_address(), format!("@{}", contact.get_alias()));
+        }
+        for wallet in self.wallets.get_wallets() {
+            if let Some(alias) = wallet.get_alias() {
+                labels.insert(*wallet.get_address(), alias.to_string());
+            }
+        }
+        labels
+    }
+
     pub fn get_wallets(&self) -> &WalletList {
         &self.wallets
     }
@@ -44,4 +289,414 @@ impl WalletConfy {
     pub fn mut_rpc_servers(&mut self) -> &mut RpcServerList {
         &mut self.rpc_servers
     }
+
+    pub fn get_contacts(&self) -> &ContactList {
+        &self.contacts
+    }
+
+    pub fn mut_contacts(&mut self) -> &mut ContactList {
+        &mut self.contacts
+    }
+
+    pub fn get_tag_details(&self) -> &HashMap<Tag, TagDetails> {
+        &self.tag_details
+    }
+
+    /// Empty details are dropped so untouched tags don't clutter the config.
+    pub fn set_tag_details(&mut self, tag: Tag, details: TagDetails) -> Result<()> {
+        if !self.tags.contains(&tag) {
+            return Err(Error::TagNotFound);
+        }
+
+        if details.is_empty() {
+            self.tag_details.remove(&tag);
+        } else {
+            self.tag_details.insert(tag, details);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WalletConfy;
+    use crate::{
+        error::Error,
+        models::{
+            alias::Alias,
+            alias_or_address::AliasOrAddress,
+            cipher::{Cipher, RandomlyGenerable},
+            contact::Contact,
+            tag::Tag,
+            tag_color::TagColor,
+            tag_details::TagDetails,
+            tag_list::TagList,
+            wallet::Wallet,
+            wallet_edit::WalletEdit,
+        },
+    };
+    use std::str::FromStr;
+    use sui_keys::key_derive::generate_new_key;
+    use sui_sdk::types::{base_types::SuiAddress, crypto::SignatureScheme};
+
+    #[test]
+    fn test_contacts() {
+        let mut confy = WalletConfy::default();
+        let wallet = SuiAddress::random_for_testing_only();
+        let exchange = SuiAddress::random_for_testing_only();
+
+        confy
+            .add_wallet(Wallet::new(
+                wallet,
+                Some(Alias::new("exchange").unwrap()),
+                TagList::default(),
+            ))
+            .unwrap();
+        confy
+            .add_contact(Contact::new(
+                exchange,
+                Alias::new("exchange").unwrap(),
+                TagList::default(),
+                None,
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            confy.add_contact(Contact::new(
+                exchange,
+                Alias::new("tagged").unwrap(),
+                TagList::from_str("unknown").unwrap(),
+                None,
+            )),
+            Err(Error::TagNotFound)
+        ));
+
+        assert_eq!(
+            confy
+                .resolve_address(&AliasOrAddress::from_str("exchange").unwrap())
+                .unwrap(),
+            wallet
+        );
+        assert_eq!(
+            confy
+                .resolve_address(&AliasOrAddress::from_str("@exchange").unwrap())
+                .unwrap(),
+            exchange
+        );
+        assert!(matches!(
+            confy
+                .get_wallets()
+                .resolve_address(&AliasOrAddress::from_str("@exchange").unwrap()),
+            Err(Error::ContactIsNotAWallet(_))
+        ));
+
+        let labels = confy.get_address_labels();
+        assert_eq!(labels.get_label(&wallet), format!("exchange ({})", wallet));
+        assert_eq!(
+            labels.get_label(&exchange),
+            format!("@exchange ({})", exchange)
+        );
+    }
+
+    #[test]
+    fn test_edit_wallet_validates_tags() {
+        let mut confy = WalletConfy::default();
+        let address = SuiAddress::random_for_testing_only();
+
+        confy
+            .mut_tags()
+            .extend(&TagList::from_str("qa,bot").unwrap());
+        confy
+            .add_wallet(Wallet::new(
+                address,
+                Some(Alias::new("alice").unwrap()),
+                TagList::from_str("qa").unwrap(),
+            ))
+            .unwrap();
+        let alice = AliasOrAddress::from_str("alice").unwrap();
+
+        assert!(matches!(
+            confy.edit_wallet(
+                &alice,
+                &WalletEdit::new(None, false, Some(TagList::from_str("unknown").unwrap()))
+            ),
+            Err(Error::TagNotFound)
+        ));
+        assert!(matches!(
+            confy.edit_wallet(
+                &alice,
+                &WalletEdit::default().with_add_tags(TagList::from_str("bot,unknown").unwrap())
+            ),
+            Err(Error::TagNotFound)
+        ));
+        assert!(matches!(
+            confy.edit_wallet(
+                &AliasOrAddress::from_str("@alice").unwrap(),
+                &WalletEdit::default()
+            ),
+            Err(Error::ContactIsNotAWallet(_))
+        ));
+
+        confy
+            .edit_wallet(
+                &alice,
+                &WalletEdit::default()
+                    .with_add_tags(TagList::from_str("bot").unwrap())
+                    .with_remove_tags(TagList::from_str("qa,stale").unwrap()),
+            )
+            .unwrap();
+        let tags = confy.get_wallets().get_by_key(&address).unwrap().get_tags();
+        assert!(tags.contains(&Tag::new("bot").unwrap()));
+        assert!(!tags.contains(&Tag::new("qa").unwrap()));
+    }
+
+    #[test]
+    fn test_rename_and_remove_tags() {
+        let mut confy = WalletConfy::default();
+        let address = SuiAddress::random_for_testing_only();
+        let contact = SuiAddress::random_for_testing_only();
+        let (qa, bot, ops) = (
+            Tag::new("qa").unwrap(),
+            Tag::new("bot").unwrap(),
+            Tag::new("ops").unwrap(),
+        );
+
+        confy
+            .mut_tags()
+            .extend(&TagList::from_str("qa,bot").unwrap());
+        confy
+            .add_wallet(Wallet::new(
+                address,
+                None,
+                TagList::from_str("qa,bot").unwrap(),
+            ))
+            .unwrap();
+        confy
+            .add_contact(Contact::new(
+                contact,
+                Alias::new("exchange").unwrap(),
+                TagList::from_str("qa").unwrap(),
+                None,
+            ))
+            .unwrap();
+        confy
+            .set_tag_details(
+                qa.clone(),
+                TagDetails::new(Some("QA".to_string()), Some(TagColor::Green)),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            confy.set_tag_details(ops.clone(), TagDetails::default()),
+            Err(Error::TagNotFound)
+        ));
+        assert!(matches!(
+            confy.rename_tag(&ops, &qa),
+            Err(Error::TagNotFound)
+        ));
+        assert!(matches!(
+            confy.rename_tag(&qa, &bot),
+            Err(Error::TagAlreadyExists(_))
+        ));
+
+        assert!(matches!(
+            confy.rename_tag(&qa, &Tag::new("qa/sub").unwrap()),
+            Err(Error::InvalidTagRename(_))
+        ));
+
+        confy.rename_tag(&qa, &ops).unwrap();
+        assert!(confy.get_tags().contains(&ops));
+        assert!(!confy.get_tags().contains(&qa));
+        let wallet = confy.get_wallets().get_by_key(&address).unwrap();
+        assert!(wallet.get_tags().contains(&ops));
+        assert!(!wallet.get_tags().contains(&qa));
+        let exchange = confy
+            .get_contacts()
+            .get(&AliasOrAddress::from_str("@exchange").unwrap())
+            .unwrap();
+        assert!(exchange.get_tags().contains(&ops));
+        assert_eq!(
+            confy.get_tag_details().get(&ops).unwrap().get_color(),
+            &Some(TagColor::Green)
+        );
+        assert_eq!(confy.get_tag_usage().get(&ops), Some(&1));
+
+        confy.remove_tags(&TagList::from_str("ops").unwrap());
+        assert!(!confy.get_tags().contains(&ops));
+        assert!(confy.get_tag_details().is_empty());
+        let wallet = confy.get_wallets().get_by_key(&address).unwrap();
+        assert_eq!(wallet.get_tags().join(","), "bot");
+        assert_eq!(confy.get_tag_usage().get(&bot), Some(&1));
+    }
+
+    #[test]
+    fn test_rename_tag_subtree() {
+        let mut confy = WalletConfy::default();
+        let address = SuiAddress::random_for_testing_only();
+        let tag = |name: &str| Tag::new(name).unwrap();
+
+        confy
+            .mut_tags()
+            .extend(&TagList::from_str("team_a/qa,team_a/prod,team_ab,squad/qa").unwrap());
+        confy
+            .add_wallet(Wallet::new(
+                address,
+                None,
+                TagList::from_str("team_a/qa,team_ab").unwrap(),
+            ))
+            .unwrap();
+
+        let usage = confy.get_tag_usage();
+        assert_eq!(usage.get(&tag("team_a")), Some(&1));
+        assert_eq!(usage.get(&tag("team_a/prod")), Some(&0));
+        assert_eq!(usage.get(&tag("squad")), Some(&0));
+
+        assert!(matches!(
+            confy.rename_tag(&tag("team_a"), &tag("squad")),
+            Err(Error::TagAlreadyExists(_))
+        ));
+
+        confy.rename_tag(&tag("team_a"), &tag("squad/a")).unwrap();
+        assert_eq!(
+            confy.get_tags().join(","),
+            "squad/a/prod,squad/a/qa,squad/qa,team_ab"
+        );
+        let wallet = confy.get_wallets().get_by_key(&address).unwrap();
+        assert_eq!(wallet.get_tags().join(","), "squad/a/qa,team_ab");
+
+        confy.rename_tag(&tag("squad/a/qa"), &tag("qa")).unwrap();
+        let wallet = confy.get_wallets().get_by_key(&address).unwrap();
+        assert_eq!(wallet.get_tags().join(","), "qa,team_ab");
+    }
+
+    #[test]
+    fn test_merge() {
+        let shared = SuiAddress::random_for_testing_only();
+        let mut confy = WalletConfy::default();
+        confy.mut_tags().extend(&TagList::from_str("ops").unwrap());
+        confy
+            .add_wallet(Wallet::new(
+                shared,
+                Some(Alias::new("main").unwrap()),
+                TagList::default(),
+            ))
+            .unwrap();
+
+        let mut other = WalletConfy::default();
+        other
+            .mut_tags()
+            .extend(&TagList::from_str("ops,team").unwrap());
+        other
+            .add_wallet(Wallet::new(shared, None, TagList::default()))
+            .unwrap();
+        other
+            .add_wallet(Wallet::new(
+                SuiAddress::random_for_testing_only(),
+                Some(Alias::new("main").unwrap()),
+                TagList::default(),
+            ))
+            .unwrap();
+        let added = SuiAddress::random_for_testing_only();
+        other
+            .add_wallet(Wallet::new(
+                added,
+                Some(Alias::new("new").unwrap()),
+                TagList::from_str("team").unwrap(),
+            ))
+            .unwrap();
+
+        let conflicts = confy.merge(other);
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts
+            .iter()
+            .all(|conflict| conflict.get_kind() == "wallet"));
+        assert_eq!(confy.get_tags().join(","), "ops,team");
+        assert_eq!(confy.get_wallets().get_wallets().len(), 2);
+        assert!(confy.get_wallets().get_by_key(&added).is_some());
+    }
+
+    #[test]
+    fn test_import_tags() {
+        let mut source = WalletConfy::default();
+        source
+            .mut_tags()
+            .extend(&TagList::from_str("team_a,team_a/qa,ops").unwrap());
+        source
+            .set_tag_details(
+                Tag::new("team_a").unwrap(),
+                TagDetails::new(Some("Team A".to_string()), None),
+            )
+            .unwrap();
+        source
+            .set_tag_details(
+                Tag::new("team_a/qa").unwrap(),
+                TagDetails::new(Some("QA".to_string()), Some(TagColor::Green)),
+            )
+            .unwrap();
+
+        let mut target = WalletConfy::default();
+        target
+            .mut_tags()
+            .extend(&TagList::from_str("team_a").unwrap());
+        target
+            .set_tag_details(
+                Tag::new("team_a").unwrap(),
+                TagDetails::new(Some("Ours".to_string()), None),
+            )
+            .unwrap();
+
+        target.import_tags(&TagList::from_str("team_a/qa").unwrap(), &source);
+
+        assert_eq!(target.get_tags().join(","), "team_a,team_a/qa");
+        assert_eq!(
+            target.get_tag_details()[&Tag::new("team_a/qa").unwrap()].get_color(),
+            &Some(TagColor::Green)
+        );
+        assert_eq!(
+            target.get_tag_details()[&Tag::new("team_a").unwrap()].get_description(),
+            &Some("Ours".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rotate_cipher() {
+        unsafe {
+            std::env::set_var(
+                "CIPHER_KEY",
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            );
+            std::env::set_var("CIPHER_NONCE", "000102030405060708090a0b");
+        }
+
+        let (address, key_pair, scheme, phrase) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+        let mut confy = WalletConfy::default();
+        confy
+            .add_wallet(
+                Wallet::new(address, None, TagList::default())
+                    .with_credentials(key_pair.copy(), scheme, phrase.clone())
+                    .unwrap(),
+            )
+            .unwrap();
+        confy
+            .add_wallet(Wallet::new(
+                SuiAddress::random_for_testing_only(),
+                None,
+                TagList::default(),
+            ))
+            .unwrap();
+
+        let old = Cipher::load_from_env().unwrap();
+        let new = Cipher::generate_random();
+
+        assert!(confy.rotate_cipher(&new, &old).is_err());
+        assert_eq!(confy.rotate_cipher(&old, &new).unwrap(), 1);
+
+        let wallet = confy.get_wallets().get_by_key(&address).unwrap();
+        let secret = wallet.get_secret(&new).unwrap().unwrap();
+        assert_eq!(secret.get_key_pair().unwrap(), key_pair);
+        assert_eq!(secret.get_mnemonic(), &Some(phrase));
+        assert!(wallet.get_secret(&old).is_err());
+    }
 }
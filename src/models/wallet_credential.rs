@@ -1,5 +1,5 @@
-use super::cipher::Cipher;
-use crate::error::Result;
+use super::{cipher::Cipher, wallet_secret::WalletSecret};
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 use sui_sdk::types::crypto::{PublicKey, SignatureScheme, SuiKeyPair};
 
@@ -23,6 +23,41 @@ impl WalletCredentials {
         })
     }
 
+    /// Encrypts a decrypted secret under `cipher`.
+    pub fn from_secret(secret: &WalletSecret, cipher: &Cipher) -> Result<Self> {
+        let key_pair = secret.get_key_pair()?;
+
+        Ok(Self {
+            public_key: key_pair.public(),
+            encrypted_private_key: hex::encode(cipher.encrypt(key_pair.to_bytes())?),
+            key_scheme: *secret.get_key_scheme(),
+            encrypted_mnemonic: match secret.get_mnemonic() {
+                Some(phrase) => hex::encode(cipher.encrypt(phrase.clone().into_bytes())?),
+                None => String::new(),
+            },
+        })
+    }
+
+    pub fn get_secret(&self, cipher: &Cipher) -> Result<WalletSecret> {
+        let private_key = cipher.decrypt(hex::decode(&self.encrypted_private_key)?)?;
+        let mnemonic = if self.has_mnemonic() {
+            let phrase = cipher.decrypt(hex::decode(&self.encrypted_mnemonic)?)?;
+            Some(String::from_utf8(phrase).map_err(|e| Error::CipherError(e.to_string()))?)
+        } else {
+            None
+        };
+
+        Ok(WalletSecret::new(self.key_scheme, &private_key, mnemonic))
+    }
+
+    pub fn get_key_scheme(&self) -> &SignatureScheme {
+        &self.key_scheme
+    }
+
+    pub fn has_mnemonic(&self) -> bool {
+        !self.encrypted_mnemonic.is_empty()
+    }
+
     pub fn get_key_pair(&self) -> Result<SuiKeyPair> {
         let cipher = Cipher::load_from_env()?;
         let private_key = cipher.decrypt(hex::decode(&self.encrypted_private_key)?)?;
@@ -0,0 +1,153 @@
+use super::{alias::Alias, tag_list::TagList, wallet_metadata::WalletMetadata};
+
+/// Changes applied to a single wallet by `edit` and `tag assign/unassign`.
+/// Tags are replaced first, then added, then removed.
+#[derive(Debug, Clone, Default)]
+pub struct WalletEdit {
+    alias: Option<Alias>,
+    clear_alias: bool,
+    tags: Option<TagList>,
+    add_tags: TagList,
+    remove_tags: TagList,
+    note: Option<String>,
+    set_metadata: Vec<(String, String)>,
+    unset_metadata: Vec<String>,
+}
+
+impl WalletEdit {
+    pub fn new(alias: Option<Alias>, clear_alias: bool, tags: Option<TagList>) -> Self {
+        Self {
+            alias,
+            clear_alias,
+            tags,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_add_tags(mut self, tags: TagList) -> Self {
+        self.add_tags = tags;
+        self
+    }
+
+    pub fn with_remove_tags(mut self, tags: TagList) -> Self {
+        self.remove_tags = tags;
+        self
+    }
+
+    /// An empty note clears the current one.
+    pub fn with_note(mut self, note: Option<String>) -> Self {
+        self.note = note;
+        self
+    }
+
+    pub fn with_set_metadata(mut self, entries: Vec<(String, String)>) -> Self {
+        self.set_metadata = entries;
+        self
+    }
+
+    pub fn with_unset_metadata(mut self, keys: Vec<String>) -> Self {
+        self.unset_metadata = keys;
+        self
+    }
+
+    pub fn get_alias(&self) -> &Option<Alias> {
+        &self.alias
+    }
+
+    pub fn is_clear_alias(&self) -> bool {
+        self.clear_alias
+    }
+
+    /// Tags this edit would put on a wallet, which must all be registered.
+    pub fn get_added_tags(&self) -> TagList {
+        let mut tags = self.tags.clone().unwrap_or_default();
+        tags.extend(&self.add_tags);
+        tags
+    }
+
+    pub fn apply_note(&self, current: &Option<String>) -> Option<String> {
+        match self.note {
+            Some(ref note) if note.is_empty() => None,
+            Some(ref note) => Some(note.clone()),
+            None => current.clone(),
+        }
+    }
+
+    pub fn apply_metadata(&self, current: &WalletMetadata) -> WalletMetadata {
+        let mut metadata = current.clone();
+        for (key, value) in self.set_metadata.iter() {
+            metadata.insert(key.clone(), value.clone());
+        }
+        for key in self.unset_metadata.iter() {
+            metadata.remove(key);
+        }
+        metadata
+    }
+
+    pub fn apply_tags(&self, current: &TagList) -> TagList {
+        let mut tags = self.tags.clone().unwrap_or_else(|| current.clone());
+        tags.extend(&self.add_tags);
+        tags.remove(&self.remove_tags);
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WalletEdit;
+    use crate::models::{tag::Tag, tag_list::TagList, wallet_metadata::WalletMetadata};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_apply_tags() {
+        let current = TagList::from_str("qa,bot").unwrap();
+
+        let edit = WalletEdit::default()
+            .with_add_tags(TagList::from_str("prod").unwrap())
+            .with_remove_tags(TagList::from_str("bot").unwrap());
+        let tags = edit.apply_tags(&current);
+        assert_eq!(tags.join(","), "prod,qa");
+        assert_eq!(edit.get_added_tags().join(","), "prod");
+
+        let edit = WalletEdit::new(None, false, Some(TagList::from_str("ops").unwrap()))
+            .with_add_tags(TagList::from_str("qa").unwrap())
+            .with_remove_tags(TagList::from_str("ops").unwrap());
+        let tags = edit.apply_tags(&current);
+        assert_eq!(tags.join(","), "qa");
+        assert!(!tags.contains(&Tag::new("bot").unwrap()));
+        assert_eq!(edit.get_added_tags().join(","), "ops,qa");
+
+        assert_eq!(
+            WalletEdit::default().apply_tags(&current).join(","),
+            "bot,qa"
+        );
+    }
+
+    #[test]
+    fn test_apply_note_and_metadata() {
+        let note = Some("cold storage".to_string());
+        assert_eq!(WalletEdit::default().apply_note(&note), note);
+        assert_eq!(
+            WalletEdit::default()
+                .with_note(Some("hot".to_string()))
+                .apply_note(&note),
+            Some("hot".to_string())
+        );
+        assert_eq!(
+            WalletEdit::default()
+                .with_note(Some("".to_string()))
+                .apply_note(&note),
+            None
+        );
+
+        let mut current = WalletMetadata::default();
+        current.insert("owner".to_string(), "ops".to_string());
+        current.insert("purpose".to_string(), "oracle".to_string());
+
+        let metadata = WalletEdit::default()
+            .with_set_metadata(vec![("owner".to_string(), "dev".to_string())])
+            .with_unset_metadata(vec!["purpose".to_string()])
+            .apply_metadata(&current);
+        assert_eq!(metadata.join(","), "owner=dev");
+    }
+}
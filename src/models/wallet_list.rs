@@ -1,4 +1,6 @@
-use super::{alias::Alias, tag_list::TagList, wallet::Wallet};
+use super::{
+    alias::Alias, alias_or_address::AliasOrAddress, wallet::Wallet, wallet_edit::WalletEdit,
+};
 use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,6 +21,36 @@ impl WalletList {
         self.wallets.get(address)
     }
 
+    pub fn get_by_alias_or_address(&self, alias_or_address: &AliasOrAddress) -> Result<&Wallet> {
+        match alias_or_address {
+            AliasOrAddress::Address(address) => self
+                .get_by_key(address)
+                .ok_or(Error::WalletAddressNotFound(*address)),
+            AliasOrAddress::Alias(alias) => {
+                let address = self
+                    .get_address_by_alias(alias)
+                    .ok_or(Error::WalletAliasNotFound(alias.clone()))?;
+
+                self.get_by_key(address)
+                    .ok_or(Error::WalletAddressNotFound(*address))
+            }
+            AliasOrAddress::Contact(alias) => Err(Error::ContactIsNotAWallet(alias.clone())),
+            AliasOrAddress::SuiNs(name) => Err(Error::SuiNsNotSupported(name.to_string())),
+        }
+    }
+
+    pub fn resolve_address(&self, alias_or_address: &AliasOrAddress) -> Result<SuiAddress> {
+        match alias_or_address {
+            AliasOrAddress::Address(address) => Ok(*address),
+            AliasOrAddress::Alias(alias) => self
+                .get_address_by_alias(alias)
+                .copied()
+                .ok_or(Error::WalletAliasNotFound(alias.clone())),
+            AliasOrAddress::Contact(alias) => Err(Error::ContactIsNotAWallet(alias.clone())),
+            AliasOrAddress::SuiNs(name) => Err(Error::SuiNsNotSupported(name.to_string())),
+        }
+    }
+
     pub fn contains_key(&self, address: &SuiAddress) -> bool {
         self.wallets.contains_key(address)
     }
@@ -45,22 +77,30 @@ impl WalletList {
         Ok(())
     }
 
-    pub fn edit(
-        &mut self,
-        address: SuiAddress,
-        alias: Option<Alias>,
-        tags: Option<TagList>,
-    ) -> Result<()> {
+    pub fn edit(&mut self, address: SuiAddress, edit: &WalletEdit) -> Result<()> {
+        if let Some(alias) = edit.get_alias() {
+            if self
+                .aliasses
+                .get(alias)
+                .is_some_and(|owner| owner != &address)
+            {
+                return Err(Error::WalletAliasAlreadyExists(alias.clone()));
+            }
+        }
+
         if let Some(wallet) = self.wallets.get_mut(&address) {
-            *wallet.mut_tags() = match tags {
-                Some(tags) => tags,
-                None => wallet.get_tags().clone(),
-            };
+            *wallet.mut_tags() = edit.apply_tags(wallet.get_tags());
+            *wallet.mut_note() = edit.apply_note(wallet.get_note());
+            *wallet.mut_metadata() = edit.apply_metadata(wallet.get_metadata());
 
-            if let Some(alias) = alias {
+            if edit.is_clear_alias() || edit.get_alias().is_some() {
                 if let Some(ref old_alias) = wallet.get_alias() {
                     self.aliasses.remove(old_alias);
                 }
+                *wallet.mut_alias() = None;
+            }
+
+            if let Some(alias) = edit.get_alias() {
                 self.aliasses.insert(alias.clone(), *wallet.get_address());
 
                 *wallet.mut_alias() = Some(alias.clone());
@@ -72,6 +112,15 @@ impl WalletList {
         }
     }
 
+    pub fn remove(&mut self, address: &SuiAddress) -> Option<Wallet> {
+        let wallet = self.wallets.remove(address)?;
+        if let Some(alias) = wallet.get_alias() {
+            self.aliasses.remove(alias);
+        }
+
+        Some(wallet)
+    }
+
     pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Wallet> {
         self.wallets.values_mut()
     }
@@ -85,8 +134,11 @@ impl WalletList {
 mod tests {
     use super::WalletList;
     use crate::models::alias::Alias;
+    use crate::models::alias_or_address::AliasOrAddress;
     use crate::models::tag_list::TagList;
     use crate::models::wallet::Wallet;
+    use crate::models::wallet_edit::WalletEdit;
+    use crate::{error::Error, models::tag::Tag};
     use std::str::FromStr;
     use sui_keys::key_derive::generate_new_key;
     use sui_sdk::types::crypto::SignatureScheme;
@@ -119,12 +171,36 @@ mod tests {
             .contains_all(&TagList::default()));
         assert!(list.contains_key(&address));
         assert!(list.contains_alias_key(&Alias::new("test1").unwrap()));
+        assert!(list
+            .get_by_alias_or_address(&AliasOrAddress::Alias(Alias::new("test1").unwrap()))
+            .is_ok());
+        assert!(list
+            .get_by_alias_or_address(&AliasOrAddress::Address(address))
+            .is_ok());
+        assert!(list
+            .get_by_alias_or_address(&AliasOrAddress::Alias(Alias::new("none").unwrap()))
+            .is_err());
+        assert_eq!(
+            list.resolve_address(&AliasOrAddress::Alias(Alias::new("test1").unwrap()))
+                .unwrap(),
+            address
+        );
+        let (other, _, _, _) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+        assert_eq!(
+            list.resolve_address(&AliasOrAddress::Address(other))
+                .unwrap(),
+            other
+        );
 
         assert!(list
             .edit(
                 address,
-                Some(Alias::new("test2").unwrap()),
-                Some(TagList::from_str("tag1,tag2").unwrap())
+                &WalletEdit::new(
+                    Some(Alias::new("test2").unwrap()),
+                    false,
+                    Some(TagList::from_str("tag1,tag2").unwrap())
+                )
             )
             .is_ok());
         assert_eq!(list.get_wallets().len(), 1);
@@ -139,5 +215,63 @@ mod tests {
             .get_tags()
             .contains_all(&TagList::from_str("tag1,tag2").unwrap()));
         assert!(list.contains_alias_key(&Alias::new("test2").unwrap()));
+
+        assert!(list.remove(&address).is_some());
+        assert!(list.remove(&address).is_none());
+        assert!(list.get_wallets().is_empty());
+        assert!(!list.contains_alias_key(&Alias::new("test2").unwrap()));
+    }
+
+    #[test]
+    fn test_edit_wallet() {
+        let mut list = WalletList::default();
+        let (address1, _, _, _) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+        let (address2, _, _, _) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+
+        list.add(Wallet::new(
+            address1,
+            Some(Alias::new("alice").unwrap()),
+            TagList::from_str("qa,bot").unwrap(),
+        ))
+        .unwrap();
+        list.add(Wallet::new(
+            address2,
+            Some(Alias::new("bob").unwrap()),
+            TagList::default(),
+        ))
+        .unwrap();
+
+        list.edit(
+            address1,
+            &WalletEdit::default()
+                .with_add_tags(TagList::from_str("prod").unwrap())
+                .with_remove_tags(TagList::from_str("bot").unwrap()),
+        )
+        .unwrap();
+        let tags = list.get_by_key(&address1).unwrap().get_tags();
+        assert!(tags.contains(&Tag::new("qa").unwrap()));
+        assert!(tags.contains(&Tag::new("prod").unwrap()));
+        assert!(!tags.contains(&Tag::new("bot").unwrap()));
+
+        assert!(matches!(
+            list.edit(
+                address1,
+                &WalletEdit::new(Some(Alias::new("bob").unwrap()), false, None)
+            ),
+            Err(Error::WalletAliasAlreadyExists(_))
+        ));
+        assert!(list
+            .edit(
+                address1,
+                &WalletEdit::new(Some(Alias::new("alice").unwrap()), false, None)
+            )
+            .is_ok());
+
+        list.edit(address1, &WalletEdit::new(None, true, None))
+            .unwrap();
+        assert!(list.get_by_key(&address1).unwrap().get_alias().is_none());
+        assert!(!list.contains_alias_key(&Alias::new("alice").unwrap()));
     }
 }
@@ -0,0 +1,111 @@
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Free-form `key=value` pairs attached to a wallet, e.g. `owner=ops`.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WalletMetadata(BTreeMap<String, String>);
+
+pub fn parse_metadata_key(key: &str) -> Result<String> {
+    if key.is_empty()
+        || key.len() > 32
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_-.".contains(c))
+    {
+        return Err(Error::InvalidMetadata(format!(
+            "{}. Keys are up to 32 lowercase letters, numbers, '_', '-' or '.'",
+            key
+        )));
+    }
+
+    Ok(key.to_string())
+}
+
+pub fn parse_metadata_entry(entry: &str) -> Result<(String, String)> {
+    let (key, value) = entry.split_once('=').ok_or(Error::InvalidMetadata(format!(
+        "{}. Expected key=value",
+        entry
+    )))?;
+
+    Ok((parse_metadata_key(key)?, value.to_string()))
+}
+
+impl WalletMetadata {
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.0.get(key)
+    }
+
+    pub fn insert(&mut self, key: String, value: String) {
+        self.0.insert(key, value);
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        self.0.remove(key);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter()
+    }
+
+    pub fn join(&self, separator: &str) -> String {
+        self.0
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_metadata_entry, parse_metadata_key, WalletMetadata};
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse_metadata() {
+        assert_eq!(
+            parse_metadata_entry("owner=ops").unwrap(),
+            ("owner".to_string(), "ops".to_string())
+        );
+        assert_eq!(
+            parse_metadata_entry("url=https://x.io/?a=b").unwrap(),
+            ("url".to_string(), "https://x.io/?a=b".to_string())
+        );
+        assert_eq!(
+            parse_metadata_entry("note=").unwrap(),
+            ("note".to_string(), "".to_string())
+        );
+        assert!(matches!(
+            parse_metadata_entry("owner"),
+            Err(Error::InvalidMetadata(_))
+        ));
+        assert!(matches!(
+            parse_metadata_entry("=ops"),
+            Err(Error::InvalidMetadata(_))
+        ));
+        assert!(matches!(
+            parse_metadata_key("Owner"),
+            Err(Error::InvalidMetadata(_))
+        ));
+        assert!(parse_metadata_key("team.cost-center_1").is_ok());
+    }
+
+    #[test]
+    fn test_wallet_metadata() {
+        let mut metadata = WalletMetadata::default();
+        assert!(metadata.is_empty());
+
+        metadata.insert("purpose".to_string(), "oracle".to_string());
+        metadata.insert("owner".to_string(), "ops".to_string());
+        assert_eq!(metadata.get("owner"), Some(&"ops".to_string()));
+        assert_eq!(metadata.join(", "), "owner=ops, purpose=oracle");
+
+        metadata.remove("owner");
+        assert_eq!(metadata.join(", "), "purpose=oracle");
+    }
+}
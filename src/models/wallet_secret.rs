@@ -0,0 +1,59 @@
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use sui_sdk::types::{
+    base_types::SuiAddress,
+    crypto::{SignatureScheme, SuiKeyPair},
+};
+
+/// Decrypted key material. Only ever held in memory or inside an encrypted
+/// backup archive, never written to the wallet store.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WalletSecret {
+    key_scheme: SignatureScheme,
+    private_key: String,
+    mnemonic: Option<String>,
+}
+
+impl WalletSecret {
+    pub fn new(key_scheme: SignatureScheme, private_key: &[u8], mnemonic: Option<String>) -> Self {
+        Self {
+            key_scheme,
+            private_key: hex::encode(private_key),
+            mnemonic,
+        }
+    }
+
+    pub fn get_key_scheme(&self) -> &SignatureScheme {
+        &self.key_scheme
+    }
+
+    pub fn get_key_pair(&self) -> Result<SuiKeyPair> {
+        Ok(SuiKeyPair::from_bytes(&hex::decode(&self.private_key)?)?)
+    }
+
+    pub fn get_mnemonic(&self) -> &Option<String> {
+        &self.mnemonic
+    }
+
+    pub fn get_address(&self) -> Result<SuiAddress> {
+        Ok(SuiAddress::from(&self.get_key_pair()?.public()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WalletSecret;
+    use sui_keys::key_derive::generate_new_key;
+    use sui_sdk::types::crypto::SignatureScheme;
+
+    #[test]
+    fn test_wallet_secret() {
+        let (address, key_pair, scheme, phrase) =
+            generate_new_key(SignatureScheme::ED25519, None, Some("word24".to_string())).unwrap();
+
+        let secret = WalletSecret::new(scheme, &key_pair.to_bytes(), Some(phrase.clone()));
+        assert_eq!(secret.get_key_pair().unwrap(), key_pair);
+        assert_eq!(secret.get_address().unwrap(), address);
+        assert_eq!(secret.get_mnemonic(), &Some(phrase));
+    }
+}
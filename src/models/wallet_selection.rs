@@ -0,0 +1,191 @@
+use super::{
+    alias_or_address::AliasOrAddress, tag_filter::TagFilter, wallet::Wallet,
+    wallet_list::WalletList,
+};
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, Default)]
+pub struct WalletSelection {
+    aliases_or_addresses: Vec<AliasOrAddress>,
+    tags: Option<TagFilter>,
+    all: bool,
+}
+
+impl WalletSelection {
+    pub fn new(
+        aliases_or_addresses: Vec<AliasOrAddress>,
+        tags: Option<TagFilter>,
+        all: bool,
+    ) -> Self {
+        Self {
+            aliases_or_addresses,
+            tags,
+            all,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aliases_or_addresses.is_empty() && self.tags.is_none() && !self.all
+    }
+
+    pub fn select(&self, wallets: &WalletList) -> Result<Vec<Wallet>> {
+        if self.is_empty() {
+            return Err(Error::EmptyWalletSelection);
+        }
+
+        let mut selected = if self.all || self.aliases_or_addresses.is_empty() {
+            let mut all_wallets = wallets.get_wallets();
+            all_wallets.sort_by(|a, b| a.get_address().cmp(b.get_address()));
+            all_wallets
+        } else {
+            let mut found = Vec::new();
+            let mut not_found = Vec::new();
+
+            for alias_or_address in self.aliases_or_addresses.iter() {
+                match wallets.get_by_alias_or_address(alias_or_address) {
+                    Ok(wallet) => {
+                        if !found
+                            .iter()
+                            .any(|w: &Wallet| w.get_address() == wallet.get_address())
+                        {
+                            found.push(wallet.clone());
+                        }
+                    }
+                    Err(_) => not_found.push(alias_or_address.to_string()),
+                }
+            }
+
+            if !not_found.is_empty() {
+                return Err(Error::WalletsNotFound(not_found.join(", ")));
+            }
+
+            found
+        };
+
+        if let Some(ref tags) = self.tags {
+            selected.retain(|wallet| tags.matches(wallet.get_tags()));
+        }
+
+        Ok(selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WalletSelection;
+    use crate::{
+        error::Error,
+        models::{
+            alias::Alias, alias_or_address::AliasOrAddress, tag_filter::TagFilter,
+            tag_list::TagList, wallet::Wallet, wallet_list::WalletList,
+        },
+    };
+    use std::str::FromStr;
+    use sui_sdk::types::base_types::SuiAddress;
+
+    fn wallet_list() -> (WalletList, SuiAddress, SuiAddress) {
+        let mut list = WalletList::default();
+        let address1 = SuiAddress::random_for_testing_only();
+        let address2 = SuiAddress::random_for_testing_only();
+
+        list.add(Wallet::new(
+            address1,
+            Some(Alias::new("alice").unwrap()),
+            TagList::from_str("qa,bot").unwrap(),
+        ))
+        .unwrap();
+        list.add(Wallet::new(
+            address2,
+            Some(Alias::new("bob").unwrap()),
+            TagList::from_str("prod").unwrap(),
+        ))
+        .unwrap();
+
+        (list, address1, address2)
+    }
+
+    #[test]
+    fn test_select_by_alias_or_address() {
+        let (list, address1, address2) = wallet_list();
+
+        let selection = WalletSelection::new(
+            vec![
+                AliasOrAddress::from_str("alice").unwrap(),
+                AliasOrAddress::Address(address2),
+                AliasOrAddress::Address(address1),
+            ],
+            None,
+            false,
+        );
+        let wallets = selection.select(&list).unwrap();
+        assert_eq!(wallets.len(), 2);
+        assert_eq!(wallets[0].get_address(), &address1);
+        assert_eq!(wallets[1].get_address(), &address2);
+
+        let selection = WalletSelection::new(
+            vec![
+                AliasOrAddress::from_str("alice").unwrap(),
+                AliasOrAddress::from_str("bob").unwrap(),
+            ],
+            Some(TagFilter::from_str("qa").unwrap()),
+            false,
+        );
+        let wallets = selection.select(&list).unwrap();
+        assert_eq!(wallets.len(), 1);
+        assert_eq!(wallets[0].get_address(), &address1);
+    }
+
+    #[test]
+    fn test_select_by_tags_or_all() {
+        let (list, _, address2) = wallet_list();
+
+        let selection =
+            WalletSelection::new(vec![], Some(TagFilter::from_str("prod").unwrap()), false);
+        let wallets = selection.select(&list).unwrap();
+        assert_eq!(wallets.len(), 1);
+        assert_eq!(wallets[0].get_address(), &address2);
+
+        let selection = WalletSelection::new(vec![], None, true);
+        assert_eq!(selection.select(&list).unwrap().len(), 2);
+
+        let selection =
+            WalletSelection::new(vec![], Some(TagFilter::from_str("qa").unwrap()), true);
+        assert_eq!(selection.select(&list).unwrap().len(), 1);
+
+        let selection =
+            WalletSelection::new(vec![], Some(TagFilter::from_str("prod|bot").unwrap()), true);
+        assert_eq!(selection.select(&list).unwrap().len(), 2);
+
+        let selection =
+            WalletSelection::new(vec![], Some(TagFilter::from_str("!bot").unwrap()), true);
+        let wallets = selection.select(&list).unwrap();
+        assert_eq!(wallets.len(), 1);
+        assert_eq!(wallets[0].get_address(), &address2);
+    }
+
+    #[test]
+    fn test_select_errors() {
+        let (list, _, _) = wallet_list();
+
+        let selection = WalletSelection::default();
+        assert!(selection.is_empty());
+        assert!(matches!(
+            selection.select(&list),
+            Err(Error::EmptyWalletSelection)
+        ));
+
+        let selection = WalletSelection::new(
+            vec![
+                AliasOrAddress::from_str("alice").unwrap(),
+                AliasOrAddress::from_str("carol").unwrap(),
+                AliasOrAddress::from_str("dave").unwrap(),
+            ],
+            None,
+            false,
+        );
+        match selection.select(&list) {
+            Err(Error::WalletsNotFound(missing)) => assert_eq!(missing, "carol, dave"),
+            _ => panic!("expected WalletsNotFound"),
+        }
+    }
+}
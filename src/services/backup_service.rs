@@ -0,0 +1,155 @@
+use crate::{
+    commands::{BackupService, WalletRepository},
+    error::{Error, Result},
+    models::{
+        cipher::Cipher, timestamp::Timestamp, vault_archive::VaultArchive,
+        vault_backup::VaultBackup, wallet_confy::WalletConfy,
+    },
+    storages::file::write_atomic,
+    views::backup_view::{BackupSummaryView, MergeConflictListView},
+};
+use clap::ValueEnum;
+use dialoguer::{Confirm, Password};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const PASSPHRASE_ENV: &str = "BACKUP_PASSPHRASE";
+
+#[derive(Default)]
+pub struct BackupServiceImpl;
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+pub enum RestoreMode {
+    Merge,
+    Replace,
+}
+
+pub struct CreateBackup {
+    pub file: PathBuf,
+}
+
+pub struct RestoreBackup {
+    pub file: PathBuf,
+    pub mode: RestoreMode,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+pub struct VerifyBackup {
+    pub file: PathBuf,
+    pub json: bool,
+}
+
+impl BackupServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Reads the passphrase from `BACKUP_PASSPHRASE` for scripts, otherwise
+    /// prompts for it.
+    fn get_passphrase(&self, confirm: bool) -> Result<String> {
+        if let Ok(passphrase) = env::var(PASSPHRASE_ENV) {
+            return Ok(passphrase);
+        }
+
+        let mut prompt = Password::new().with_prompt("Backup passphrase");
+        if confirm {
+            prompt = prompt.with_confirmation("Repeat passphrase", "Passphrases don't match");
+        }
+
+        Ok(prompt.interact()?)
+    }
+
+    fn read_backup(&self, file: &Path) -> Result<VaultBackup> {
+        let archive: VaultArchive = serde_json::from_str(&fs::read_to_string(file)?)?;
+        let backup = VaultBackup::from_value(archive.open(&self.get_passphrase(false)?)?)?;
+        backup.verify()?;
+
+        Ok(backup)
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> BackupService<R> for BackupServiceImpl {
+    fn create(&self, create_backup: CreateBackup, repository: R) -> Result<()> {
+        let wallet_confy = repository.load()?;
+        repository.release();
+        let backup = VaultBackup::new(wallet_confy, &Cipher::load_from_env()?, Timestamp::now())?;
+        let archive = VaultArchive::seal(&backup, &self.get_passphrase(true)?)?;
+
+        write_atomic(
+            &create_backup.file,
+            serde_json::to_string_pretty(&archive)?.as_bytes(),
+        )?;
+
+        BackupSummaryView::from_backup(&backup)
+            .to_table()
+            .printstd();
+        println!("Backup written to {}", create_backup.file.display());
+
+        Ok(())
+    }
+
+    fn restore(&self, restore_backup: RestoreBackup, repository: R) -> Result<()> {
+        let backup = self.read_backup(&restore_backup.file)?;
+        let mut wallet_confy = repository.load()?;
+
+        let conflicts = match restore_backup.mode {
+            RestoreMode::Merge => wallet_confy.merge(backup.into_confy(&Cipher::load_from_env()?)?),
+            RestoreMode::Replace => {
+                BackupSummaryView::from_backup(&backup)
+                    .to_table()
+                    .printstd();
+
+                if !restore_backup.dry_run
+                    && !restore_backup.yes
+                    && !Confirm::new()
+                        .with_prompt(format!(
+                            "Replace all {} wallet(s) with this backup?",
+                            wallet_confy.get_wallets().get_wallets().len()
+                        ))
+                        .default(false)
+                        .interact()?
+                {
+                    return Err(Error::Aborted);
+                }
+
+                wallet_confy = backup.into_confy(&Cipher::load_from_env()?)?;
+                Vec::new()
+            }
+        };
+
+        if !conflicts.is_empty() {
+            MergeConflictListView::from_conflicts(&conflicts)
+                .to_table()
+                .printstd();
+            println!("Skipped {} conflicting entries", conflicts.len());
+        }
+
+        if restore_backup.dry_run {
+            println!("Dry run, nothing was restored");
+            return Ok(());
+        }
+
+        repository.store(wallet_confy)?;
+
+        println!("Backup restored successfully");
+
+        Ok(())
+    }
+
+    fn verify(&self, verify_backup: VerifyBackup) -> Result<()> {
+        let backup = self.read_backup(&verify_backup.file)?;
+        let view = BackupSummaryView::from_backup(&backup);
+
+        if verify_backup.json {
+            println!("{}", view.to_json_string());
+        } else {
+            view.to_table().printstd();
+            println!("Backup is intact");
+        }
+
+        Ok(())
+    }
+}
@@ -1,17 +1,122 @@
-use crate::{commands::CipherService, error::Result, models::cipher::Cipher};
+use crate::{
+    commands::{CipherService, WalletRepository},
+    error::Result,
+    models::{
+        cipher::{Cipher, RandomlyGenerable},
+        profile::Profile,
+        wallet_confy::WalletConfy,
+    },
+    storages::file::{remove_backups, rotate_backups, write_atomic},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 #[derive(Default)]
 pub struct CipherServiceImpl;
 
+pub struct RotateCipher {
+    pub env_file: Option<PathBuf>,
+    pub dry_run: bool,
+}
+
 impl CipherServiceImpl {
     pub fn new() -> Self {
         Self
     }
+
+    /// Replaces the cipher entries in a dotenv file, appending them if missing.
+    /// The previous file is kept as `.bak.1`.
+    fn update_env_file(&self, path: &Path, entries: &[(String, String)]) -> Result<()> {
+        let contents = if path.exists() {
+            fs::read_to_string(path)?
+        } else {
+            String::new()
+        };
+
+        let mut lines = contents.lines().map(str::to_string).collect::<Vec<_>>();
+        for (name, value) in entries.iter() {
+            let entry = format!("{}={}", name, value);
+            match lines.iter_mut().find(|line| {
+                line.trim_start()
+                    .strip_prefix(name.as_str())
+                    .is_some_and(|rest| rest.trim_start().starts_with('='))
+            }) {
+                Some(line) => *line = entry,
+                None => lines.push(entry),
+            }
+        }
+
+        rotate_backups(path, 1)?;
+        write_atomic(path, format!("{}\n", lines.join("\n")).as_bytes())
+    }
 }
 
-impl CipherService for CipherServiceImpl {
+impl<R: WalletRepository<WalletConfy>> CipherService<R> for CipherServiceImpl {
     fn create(&self) -> Result<()> {
         println!("{}", Cipher::default());
         Ok(())
     }
+
+    fn rotate(&self, rotate_cipher: RotateCipher, repository: R) -> Result<()> {
+        let profile = Profile::from_env()?;
+        let old = Cipher::load_for_profile(&profile)?;
+        let new = Cipher::generate_random();
+
+        let mut wallet_confy = repository.load()?;
+        let count = wallet_confy.rotate_cipher(&old, &new)?;
+
+        if rotate_cipher.dry_run {
+            println!("Verified {} wallet(s), nothing was changed", count);
+            return Ok(());
+        }
+
+        let entries = new.to_env_entries(&profile.get_env_prefix());
+
+        // Persist the new key before the vault is rewritten so it can't be
+        // lost. The env file keeps the old key in `.bak.1` meanwhile.
+        match &rotate_cipher.env_file {
+            Some(env_file) => self.update_env_file(env_file, &entries)?,
+            None => print_env_entries(&entries),
+        }
+
+        if let Err(e) = repository.store_with_cipher(wallet_confy, &new) {
+            // The vault is still encrypted with the old key
+            if let Some(env_file) = &rotate_cipher.env_file {
+                let old_entries = old.to_env_entries(&profile.get_env_prefix());
+                if self.update_env_file(env_file, &old_entries).is_err() {
+                    eprintln!(
+                        "Failed to restore {}, set the old key manually:",
+                        env_file.display()
+                    );
+                    print_env_entries(&old_entries);
+                }
+            }
+            return Err(e);
+        }
+
+        if let Some(env_file) = rotate_cipher.env_file {
+            // The old key is no longer needed once the vault is rewritten
+            remove_backups(&env_file)?;
+            println!(
+                "Rotated {} wallet(s), new key written to {}",
+                count,
+                env_file.display()
+            );
+        } else {
+            println!(
+                "Rotated {} wallet(s). Replace the cipher settings above before the next command",
+                count
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn print_env_entries(entries: &[(String, String)]) {
+    for (name, value) in entries.iter() {
+        println!("{}={}", name, value);
+    }
 }
@@ -0,0 +1,112 @@
+use crate::{
+    commands::{ContactService, WalletRepository},
+    error::{Error, Result},
+    models::{
+        alias::Alias, alias_or_address::AliasOrAddress, contact::Contact, tag_filter::TagFilter,
+        tag_list::TagList, wallet_confy::WalletConfy,
+    },
+    views::contact_view::ContactListView,
+};
+use sui_sdk::types::base_types::SuiAddress;
+
+#[derive(Default)]
+pub struct ContactServiceImpl;
+
+pub struct CreateContact {
+    pub address: SuiAddress,
+    pub alias: Alias,
+    pub tags: Option<TagList>,
+    pub note: Option<String>,
+}
+
+pub struct RemoveContact {
+    pub alias_or_address: AliasOrAddress,
+}
+
+pub struct EditContact {
+    pub alias_or_address: AliasOrAddress,
+    pub address: Option<SuiAddress>,
+    pub alias: Option<Alias>,
+    pub tags: Option<TagList>,
+    pub note: Option<String>,
+}
+
+pub struct ListContacts {
+    pub tags: Option<TagFilter>,
+    pub json: bool,
+}
+
+impl ContactServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> ContactService<R> for ContactServiceImpl {
+    fn create(&self, create_contact: CreateContact, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        wallet_confy.add_contact(Contact::new(
+            create_contact.address,
+            create_contact.alias,
+            create_contact.tags.unwrap_or_default(),
+            create_contact.note,
+        ))?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Contact added successfully");
+        Ok(())
+    }
+
+    fn remove(&self, remove_contact: RemoveContact, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        wallet_confy
+            .mut_contacts()
+            .remove(&remove_contact.alias_or_address)?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Contact removed successfully");
+        Ok(())
+    }
+
+    fn edit(&self, edit_contact: EditContact, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        if let Some(ref tags) = edit_contact.tags {
+            if !wallet_confy.get_tags().contains_all(tags) {
+                return Err(Error::TagNotFound);
+            }
+        }
+
+        wallet_confy.mut_contacts().edit(
+            &edit_contact.alias_or_address,
+            edit_contact.address,
+            edit_contact.alias,
+            edit_contact.tags,
+            edit_contact.note,
+        )?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Contact edited successfully");
+        Ok(())
+    }
+
+    fn list(&self, list_contacts: ListContacts, repository: R) -> Result<()> {
+        let contact_view = ContactListView::from_contact_list(
+            repository.load()?.get_contacts(),
+            list_contacts.tags,
+        );
+
+        if list_contacts.json {
+            println!("{}", contact_view.to_json_string());
+        } else {
+            contact_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    commands::{EventService, WalletRepository},
+    error::{Error, Result},
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, event_query::EventQuery,
+        rpc_url::RpcUrl, tag_filter::TagFilter, timestamp::Timestamp, wallet_confy::WalletConfy,
+        wallet_selection::WalletSelection,
+    },
+    services::suins_client::SuiNsClient,
+    views::event_view::EventListView,
+};
+use futures::StreamExt;
+use move_core_types::{identifier::Identifier, language_storage::StructTag};
+use std::time::Duration;
+use sui_sdk::{
+    rpc_types::SuiEvent,
+    types::base_types::{ObjectID, SuiAddress},
+    SuiClientBuilder,
+};
+
+const PAGE_SIZE: usize = 50;
+
+#[derive(Default)]
+pub struct EventServiceImpl;
+
+pub struct ListEvents {
+    pub event_type: Option<StructTag>,
+    pub package: Option<ObjectID>,
+    pub module: Option<Identifier>,
+    pub senders: Vec<AliasOrAddress>,
+    pub tags: Option<TagFilter>,
+    pub since: Option<Timestamp>,
+    pub until: Option<Timestamp>,
+    pub limit: usize,
+    pub ascending: bool,
+    pub follow: bool,
+    pub interval: u64,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+impl EventServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn query_events(
+        &self,
+        query: &EventQuery,
+        limit: usize,
+        ascending: bool,
+        url: RpcUrl,
+    ) -> Result<Vec<SuiEvent>> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let filter = query.to_event_filter();
+            let mut events = Vec::new();
+            let mut cursor = None;
+
+            loop {
+                let page = sui_client
+                    .event_api()
+                    .query_events(filter.clone(), cursor, Some(PAGE_SIZE), !ascending)
+                    .await?;
+
+                for event in page.data.into_iter() {
+                    if !ascending && query.is_before_range(&event) {
+                        return Ok(events);
+                    }
+                    if query.matches(&event) {
+                        events.push(event);
+                        if events.len() == limit {
+                            return Ok(events);
+                        }
+                    }
+                }
+
+                if !page.has_next_page {
+                    return Ok(events);
+                }
+                cursor = page.next_cursor;
+            }
+        })
+    }
+
+    /// Streams new matching events until interrupted. Subscribes over
+    /// WebSocket when the server has one registered, otherwise polls.
+    fn follow_events<F>(
+        &self,
+        query: &EventQuery,
+        interval: u64,
+        url: RpcUrl,
+        ws_url: Option<RpcUrl>,
+        print_events: F,
+    ) -> Result<()>
+    where
+        F: Fn(&[SuiEvent]),
+    {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let filter = query.to_event_filter();
+
+            if let Some(ws_url) = ws_url {
+                let sui_client = SuiClientBuilder::default()
+                    .ws_url(ws_url.to_string())
+                    .build(&url.to_string())
+                    .await?;
+
+                let mut subscription = sui_client.event_api().subscribe_event(filter).await?;
+                while let Some(event) = subscription.next().await {
+                    let event = event?;
+                    if query.matches(&event) {
+                        print_events(&[event]);
+                    }
+                }
+
+                return Ok(());
+            }
+
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let mut cursor = sui_client
+                .event_api()
+                .query_events(filter.clone(), None, Some(1), true)
+                .await?
+                .data
+                .first()
+                .map(|event| event.id.clone());
+
+            loop {
+                let page = sui_client
+                    .event_api()
+                    .query_events(filter.clone(), cursor.clone(), Some(PAGE_SIZE), false)
+                    .await?;
+
+                if let Some(event) = page.data.last() {
+                    cursor = Some(event.id.clone());
+                }
+
+                let events = page
+                    .data
+                    .into_iter()
+                    .filter(|event| query.matches(event))
+                    .collect::<Vec<SuiEvent>>();
+                if !events.is_empty() {
+                    print_events(&events);
+                }
+
+                if !page.has_next_page {
+                    tokio::time::sleep(Duration::from_secs(interval)).await;
+                }
+            }
+        })
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> EventService<R> for EventServiceImpl {
+    fn list(&self, list_events: ListEvents, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let url = confy.get_rpc_servers().resolve_url(&list_events.rpc)?;
+
+        let mut suins_client = SuiNsClient::new(&url)?;
+        let mut senders = list_events
+            .senders
+            .iter()
+            .map(|sender| suins_client.resolve_address(&confy, sender))
+            .collect::<Result<Vec<SuiAddress>>>()?;
+
+        if let Some(tags) = list_events.tags {
+            let wallets =
+                WalletSelection::new(vec![], Some(tags), false).select(confy.get_wallets())?;
+            if wallets.is_empty() {
+                return Err(Error::EmptyWalletSelection);
+            }
+            senders.extend(wallets.iter().map(|wallet| *wallet.get_address()));
+        }
+        senders.sort();
+        senders.dedup();
+
+        let query = EventQuery::new(
+            list_events.event_type,
+            list_events.package,
+            list_events.module,
+            senders,
+            list_events.since,
+            list_events.until,
+        );
+
+        let labels = confy.get_address_labels();
+
+        if list_events.follow {
+            let ws_url = confy.get_rpc_servers().get_ws_url(&url);
+            let json = list_events.json;
+
+            return self.follow_events(&query, list_events.interval, url, ws_url, |events| {
+                let event_view = EventListView::from_events(events, &labels);
+                if json {
+                    for line in event_view.to_json_lines() {
+                        println!("{}", line);
+                    }
+                } else {
+                    event_view.to_table().printstd();
+                }
+            });
+        }
+
+        let events = self.query_events(&query, list_events.limit, list_events.ascending, url)?;
+        let event_view = EventListView::from_events(&events, &labels);
+
+        if list_events.json {
+            println!("{}", event_view.to_json_string());
+        } else {
+            event_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    commands::{InspectService, WalletRepository},
+    error::{Error, Result},
+    models::{alias_or_url::AliasOrUrl, rpc_url::RpcUrl, wallet_confy::WalletConfy},
+    views::{object_view::ObjectDetailView, transaction_view::TransactionInspectView},
+};
+use sui_sdk::{
+    rpc_types::{
+        SuiObjectData, SuiObjectDataOptions, SuiTransactionBlockResponse,
+        SuiTransactionBlockResponseOptions,
+    },
+    types::{base_types::ObjectID, digests::TransactionDigest},
+    SuiClientBuilder,
+};
+
+#[derive(Default)]
+pub struct InspectServiceImpl;
+
+pub struct InspectObject {
+    pub object_id: ObjectID,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+pub struct InspectTransaction {
+    pub digest: TransactionDigest,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+impl InspectServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn get_object(&self, object_id: ObjectID, url: RpcUrl) -> Result<SuiObjectData> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            sui_client
+                .read_api()
+                .get_object_with_options(object_id, SuiObjectDataOptions::full_content())
+                .await?
+                .data
+                .ok_or(Error::ObjectNotFound(object_id))
+        })
+    }
+
+    fn get_transaction(
+        &self,
+        digest: TransactionDigest,
+        url: RpcUrl,
+    ) -> Result<SuiTransactionBlockResponse> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            Ok(sui_client
+                .read_api()
+                .get_transaction_with_options(
+                    digest,
+                    SuiTransactionBlockResponseOptions::full_content(),
+                )
+                .await?)
+        })
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> InspectService<R> for InspectServiceImpl {
+    fn object(&self, inspect_object: InspectObject, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let url = confy.get_rpc_servers().resolve_url(&inspect_object.rpc)?;
+
+        let object = self.get_object(inspect_object.object_id, url)?;
+        let object_view = ObjectDetailView::from_object_data(&object, &confy.get_address_labels());
+
+        if inspect_object.json {
+            println!("{}", object_view.to_json_string());
+        } else {
+            object_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+
+    fn transaction(&self, inspect_transaction: InspectTransaction, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let url = confy
+            .get_rpc_servers()
+            .resolve_url(&inspect_transaction.rpc)?;
+
+        let response = self.get_transaction(inspect_transaction.digest, url)?;
+        let transaction_view =
+            TransactionInspectView::from_response(&response, &confy.get_address_labels());
+
+        if inspect_transaction.json {
+            println!("{}", transaction_view.to_json_string());
+        } else {
+            for table in transaction_view.to_tables() {
+                table.printstd();
+            }
+        }
+
+        Ok(())
+    }
+}
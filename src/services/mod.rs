@@ -1,5 +1,16 @@
+pub mod backup_service;
 pub mod cipher_service;
+pub mod contact_service;
+pub mod event_service;
+pub mod inspect_service;
+pub mod move_service;
+pub mod object_service;
+pub mod profile_service;
+pub mod rpc_client;
 pub mod rpc_service;
+pub mod stake_service;
+pub mod storage_service;
+pub mod suins_client;
 pub mod tag_service;
 pub mod transaction_service;
 pub mod wallet_service;
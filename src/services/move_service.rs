@@ -0,0 +1,476 @@
+use crate::{
+    commands::{MoveService, WalletRepository},
+    error::{Error, Result},
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, move_argument::MoveArgument,
+        move_function_id::MoveFunctionId, move_struct_layouts::MoveStructLayouts,
+        move_type::MoveType, move_value_decoder::MoveValueDecoder, object_filter::ObjectFilter,
+        rpc_url::RpcUrl, timestamp::Timestamp, type_argument::TypeArgument,
+        upgrade_cap::UpgradeCap, wallet::Wallet, wallet_confy::WalletConfy,
+        wallet_selection::WalletSelection,
+    },
+    services::{
+        rpc_client::{execute_programmable_transaction, get_owned_objects},
+        suins_client::SuiNsClient,
+    },
+    views::{
+        move_view::MoveValueListView, package_view::PackageView,
+        transaction_view::TransactionDetailView,
+    },
+};
+use dialoguer::Confirm;
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashSet},
+    str::FromStr,
+};
+use sui_sdk::{
+    rpc_types::{SuiMoveNormalizedModule, SuiObjectDataOptions, SuiTransactionBlockResponse},
+    types::{
+        base_types::{ObjectID, SuiAddress},
+        move_package::UpgradeCap as UpgradeCapObject,
+        object::Owner,
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        transaction::{ObjectArg, ProgrammableTransaction, TransactionKind},
+    },
+    SuiClient, SuiClientBuilder,
+};
+
+#[derive(Default)]
+pub struct MoveServiceImpl;
+
+pub struct CallMove {
+    pub alias_or_address: AliasOrAddress,
+    pub function: MoveFunctionId,
+    pub type_args: Vec<TypeArgument>,
+    pub args: Vec<String>,
+    pub rpc: AliasOrUrl,
+    pub gas_budget: u64,
+    pub yes: bool,
+    pub json: bool,
+}
+
+pub struct ViewMove {
+    pub function: MoveFunctionId,
+    pub type_args: Vec<TypeArgument>,
+    pub args: Vec<String>,
+    pub sender: Option<AliasOrAddress>,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+pub struct InspectPackage {
+    pub package: ObjectID,
+    pub selection: WalletSelection,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+impl MoveServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Builds a single Move call, parsing `args` against the function's
+    /// normalized signature with `type_args` substituted in. Returns the
+    /// function's return types alongside.
+    async fn build_move_call<F>(
+        &self,
+        sui_client: &SuiClient,
+        function: &MoveFunctionId,
+        type_args: &[TypeArgument],
+        args: &[String],
+        resolve_address: &F,
+    ) -> Result<(ProgrammableTransaction, Vec<MoveType>)>
+    where
+        F: Fn(&str) -> Option<SuiAddress>,
+    {
+        let modules = sui_client
+            .read_api()
+            .get_normalized_move_modules_by_package(*function.get_package())
+            .await?;
+
+        let normalized_function = modules
+            .get(function.get_module().as_str())
+            .and_then(|module| {
+                module
+                    .exposed_functions
+                    .get(function.get_function().as_str())
+            })
+            .ok_or(Error::MoveFunctionNotFound(function.to_string()))?;
+
+        if normalized_function.type_parameters.len() != type_args.len() {
+            return Err(Error::MoveArgumentCountMismatch(
+                normalized_function.type_parameters.len(),
+                type_args.len(),
+                "type arguments".to_string(),
+            ));
+        }
+
+        let type_arguments = type_args
+            .iter()
+            .map(|type_arg| MoveType::from_type_tag(type_arg.get_type_tag()))
+            .collect::<Vec<MoveType>>();
+
+        let parameters = normalized_function
+            .parameters
+            .iter()
+            .map(MoveType::from_normalized)
+            .collect::<Result<Vec<MoveType>>>()?
+            .into_iter()
+            .filter(|parameter| !parameter.is_tx_context())
+            .map(|parameter| parameter.substitute(&type_arguments))
+            .collect::<Vec<MoveType>>();
+
+        if parameters.len() != args.len() {
+            return Err(Error::MoveArgumentCountMismatch(
+                parameters.len(),
+                args.len(),
+                "arguments".to_string(),
+            ));
+        }
+
+        let return_types = normalized_function
+            .return_
+            .iter()
+            .map(|return_type| {
+                MoveType::from_normalized(return_type)
+                    .map(|return_type| return_type.substitute(&type_arguments))
+            })
+            .collect::<Result<Vec<MoveType>>>()?;
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let mut arguments = Vec::new();
+
+        for (raw, parameter) in args.iter().zip(parameters.iter()) {
+            let argument = match MoveArgument::parse(raw, parameter, resolve_address)? {
+                MoveArgument::Pure(bytes) => builder.pure_bytes(bytes, false),
+                MoveArgument::Object(object_id) => {
+                    let data = sui_client
+                        .read_api()
+                        .get_object_with_options(
+                            object_id,
+                            SuiObjectDataOptions::new().with_owner(),
+                        )
+                        .await?
+                        .data
+                        .ok_or(Error::ObjectNotFound(object_id))?;
+
+                    let object_arg = match data.owner {
+                        Some(Owner::Shared {
+                            initial_shared_version,
+                        }) => ObjectArg::SharedObject {
+                            id: object_id,
+                            initial_shared_version,
+                            mutable: !matches!(parameter, MoveType::Reference(_)),
+                        },
+                        _ if parameter.is_struct(2, "transfer", "Receiving") => {
+                            ObjectArg::Receiving(data.object_ref())
+                        }
+                        _ => ObjectArg::ImmOrOwnedObject(data.object_ref()),
+                    };
+
+                    builder
+                        .obj(object_arg)
+                        .map_err(|e| Error::TransactionBuildError(e.to_string()))?
+                }
+            };
+            arguments.push(argument);
+        }
+
+        builder.programmable_move_call(
+            *function.get_package(),
+            function.get_module().clone(),
+            function.get_function().clone(),
+            type_args
+                .iter()
+                .map(|type_arg| type_arg.get_type_tag().clone())
+                .collect(),
+            arguments,
+        );
+
+        Ok((builder.finish(), return_types))
+    }
+
+    /// Fetches the package's modules and the `UpgradeCap`s for it owned by `wallets`.
+    fn get_package_details(
+        &self,
+        package: ObjectID,
+        wallets: Vec<Wallet>,
+        url: RpcUrl,
+    ) -> Result<(
+        BTreeMap<String, SuiMoveNormalizedModule>,
+        Vec<(Wallet, UpgradeCap)>,
+    )> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let modules = sui_client
+                .read_api()
+                .get_normalized_move_modules_by_package(package)
+                .await?;
+
+            let filter = ObjectFilter::StructType(UpgradeCapObject::type_());
+            let mut upgrade_caps = Vec::new();
+
+            for wallet in wallets.into_iter() {
+                let objects = get_owned_objects(
+                    &sui_client,
+                    *wallet.get_address(),
+                    Some(&filter),
+                    SuiObjectDataOptions::new().with_content(),
+                )
+                .await?;
+
+                upgrade_caps.extend(
+                    objects
+                        .iter()
+                        .filter_map(UpgradeCap::from_object_data)
+                        .filter(|upgrade_cap| *upgrade_cap.get_package() == package)
+                        .map(|upgrade_cap| (wallet.clone(), upgrade_cap)),
+                );
+            }
+
+            Ok((modules, upgrade_caps))
+        })
+    }
+
+    /// Fetches the struct layouts of every package reachable from `move_types`.
+    async fn fetch_struct_layouts(
+        &self,
+        sui_client: &SuiClient,
+        move_types: &[MoveType],
+    ) -> Result<MoveStructLayouts> {
+        let mut layouts = MoveStructLayouts::new();
+        let mut fetched = HashSet::new();
+
+        loop {
+            let missing = layouts
+                .get_missing_packages(move_types)
+                .into_iter()
+                .filter(|package| fetched.insert(*package))
+                .collect::<Vec<_>>();
+            if missing.is_empty() {
+                return Ok(layouts);
+            }
+
+            for package in missing.iter() {
+                let modules = sui_client
+                    .read_api()
+                    .get_normalized_move_modules_by_package(*package)
+                    .await?;
+                for (name, module) in modules.iter() {
+                    layouts.add_module(package, name, module)?;
+                }
+            }
+        }
+    }
+
+    fn view_move<F>(
+        &self,
+        sender: SuiAddress,
+        view_move: &ViewMove,
+        resolve_address: &F,
+        url: RpcUrl,
+    ) -> Result<Vec<(MoveType, Value)>>
+    where
+        F: Fn(&str) -> Option<SuiAddress>,
+    {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let (programmable_transaction, return_types) = self
+                .build_move_call(
+                    &sui_client,
+                    &view_move.function,
+                    &view_move.type_args,
+                    &view_move.args,
+                    resolve_address,
+                )
+                .await?;
+
+            let results = sui_client
+                .read_api()
+                .dev_inspect_transaction_block(
+                    sender,
+                    TransactionKind::programmable(programmable_transaction),
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+
+            if let Some(error) = results.error {
+                return Err(Error::DevInspectError(error));
+            }
+
+            let return_values = results
+                .results
+                .and_then(|mut results| results.pop())
+                .map(|result| result.return_values)
+                .unwrap_or_default();
+
+            let layouts = self
+                .fetch_struct_layouts(&sui_client, &return_types)
+                .await?;
+            let decoder = MoveValueDecoder::new(&layouts);
+
+            return_values
+                .iter()
+                .zip(return_types.into_iter())
+                .map(|((bytes, _), return_type)| {
+                    let value = decoder.decode(bytes, &return_type)?;
+                    Ok((return_type, value))
+                })
+                .collect()
+        })
+    }
+
+    fn call_move<F>(
+        &self,
+        wallet: &Wallet,
+        call_move: &CallMove,
+        resolve_address: &F,
+        url: RpcUrl,
+    ) -> Result<SuiTransactionBlockResponse>
+    where
+        F: Fn(&str) -> Option<SuiAddress>,
+    {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let (programmable_transaction, _) = self
+                .build_move_call(
+                    &sui_client,
+                    &call_move.function,
+                    &call_move.type_args,
+                    &call_move.args,
+                    resolve_address,
+                )
+                .await?;
+
+            execute_programmable_transaction(
+                &sui_client,
+                wallet,
+                programmable_transaction,
+                0,
+                call_move.gas_budget,
+            )
+            .await
+        })
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> MoveService<R> for MoveServiceImpl {
+    fn call(&self, call_move: CallMove, repository: R) -> Result<()> {
+        let mut confy = repository.load()?;
+
+        let wallet = confy
+            .get_wallets()
+            .get_by_alias_or_address(&call_move.alias_or_address)?
+            .clone();
+        let url = confy.get_rpc_servers().resolve_url(&call_move.rpc)?;
+
+        let names = SuiNsClient::new(&url)?.resolve_names_in(&call_move.args)?;
+        if !names.is_empty() {
+            let mut resolved = names.iter().collect::<Vec<_>>();
+            resolved.sort_by_key(|(name, _)| name.to_string());
+            for (name, address) in resolved {
+                eprintln!("{} resolves to {}", name, address);
+            }
+
+            if !call_move.yes
+                && !Confirm::new()
+                    .with_prompt(format!("Call {} with these addresses?", call_move.function))
+                    .default(false)
+                    .interact()?
+            {
+                return Err(Error::Aborted);
+            }
+        }
+
+        let resolve_address = |raw: &str| match AliasOrAddress::from_str(raw).ok()? {
+            AliasOrAddress::SuiNs(name) => names.get(&name).copied(),
+            alias_or_address => confy.resolve_address(&alias_or_address).ok(),
+        };
+
+        let response = self.call_move(&wallet, &call_move, &resolve_address, url)?;
+
+        confy.mark_used(&[*wallet.get_address()], Timestamp::now());
+        repository.store(confy.clone())?;
+
+        let transaction_view = TransactionDetailView::from_response(&wallet, &response);
+
+        if call_move.json {
+            println!("{}", transaction_view.to_json_string());
+        } else {
+            for table in transaction_view.to_tables() {
+                table.printstd();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn view(&self, view_move: ViewMove, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let url = confy.get_rpc_servers().resolve_url(&view_move.rpc)?;
+        let mut suins_client = SuiNsClient::new(&url)?;
+        let sender = match view_move.sender {
+            Some(ref sender) => suins_client.resolve_address(&confy, sender)?,
+            None => SuiAddress::ZERO,
+        };
+
+        let names = suins_client.resolve_names_in(&view_move.args)?;
+
+        let resolve_address = |raw: &str| match AliasOrAddress::from_str(raw).ok()? {
+            AliasOrAddress::SuiNs(name) => names.get(&name).copied(),
+            alias_or_address => confy.resolve_address(&alias_or_address).ok(),
+        };
+
+        let values = self.view_move(sender, &view_move, &resolve_address, url)?;
+
+        let move_view = MoveValueListView::from_values(&view_move.function, values);
+
+        if view_move.json {
+            println!("{}", move_view.to_json_string());
+        } else {
+            move_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+
+    fn inspect_package(&self, inspect_package: InspectPackage, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let wallets = if inspect_package.selection.is_empty() {
+            Vec::new()
+        } else {
+            inspect_package.selection.select(confy.get_wallets())?
+        };
+        let url = confy.get_rpc_servers().resolve_url(&inspect_package.rpc)?;
+
+        let (modules, upgrade_caps) =
+            self.get_package_details(inspect_package.package, wallets, url)?;
+
+        let package_view =
+            PackageView::from_modules(&inspect_package.package, &modules, upgrade_caps);
+
+        if inspect_package.json {
+            println!("{}", package_view.to_json_string());
+        } else {
+            for table in package_view.to_tables() {
+                table.printstd();
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    commands::{ObjectService, WalletRepository},
+    error::Result,
+    models::{
+        alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl, object_filter::ObjectFilter,
+        rpc_url::RpcUrl, wallet_confy::WalletConfy,
+    },
+    services::{rpc_client::get_owned_objects, suins_client::SuiNsClient},
+    views::object_view::ObjectListView,
+};
+use sui_sdk::{
+    rpc_types::{SuiObjectData, SuiObjectDataOptions},
+    types::base_types::SuiAddress,
+    SuiClientBuilder,
+};
+
+#[derive(Default)]
+pub struct ObjectServiceImpl;
+
+pub struct ListObjects {
+    pub alias_or_address: AliasOrAddress,
+    pub filter: Option<ObjectFilter>,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+impl ObjectServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn get_owned_objects(
+        &self,
+        address: SuiAddress,
+        filter: Option<ObjectFilter>,
+        url: RpcUrl,
+    ) -> Result<Vec<SuiObjectData>> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            get_owned_objects(
+                &sui_client,
+                address,
+                filter.as_ref(),
+                SuiObjectDataOptions::new()
+                    .with_type()
+                    .with_owner()
+                    .with_display(),
+            )
+            .await
+        })
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> ObjectService<R> for ObjectServiceImpl {
+    fn list(&self, list_objects: ListObjects, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let url = confy.get_rpc_servers().resolve_url(&list_objects.rpc)?;
+        let address =
+            SuiNsClient::new(&url)?.resolve_address(&confy, &list_objects.alias_or_address)?;
+
+        let object_view = ObjectListView::from_objects(self.get_owned_objects(
+            address,
+            list_objects.filter,
+            url,
+        )?);
+
+        if list_objects.json {
+            println!("{}", object_view.to_json_string());
+        } else {
+            object_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+}
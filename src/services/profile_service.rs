@@ -0,0 +1,262 @@
+use crate::{
+    commands::{ProfileService, WalletRepository},
+    error::{Error, Result},
+    models::{
+        cipher::Cipher, merge_conflict::MergeConflict, profile::Profile, wallet_confy::WalletConfy,
+        wallet_selection::WalletSelection,
+    },
+    storages::{confy::ConfyClient, file::find_siblings, StorageConfig},
+    views::{
+        backup_view::MergeConflictListView,
+        profile_view::{ProfileListView, ProfileView},
+    },
+};
+use dialoguer::Confirm;
+use std::{collections::BTreeSet, fs};
+
+#[derive(Default)]
+pub struct ProfileServiceImpl;
+
+pub struct ListProfiles {
+    pub json: bool,
+}
+
+pub struct CreateProfile {
+    pub name: Profile,
+}
+
+pub struct DeleteProfile {
+    pub name: Profile,
+    pub yes: bool,
+}
+
+pub struct TransferWallets {
+    pub selection: WalletSelection,
+    pub to: Profile,
+    pub remove: bool,
+}
+
+impl ProfileServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Profiles with a vault file in the config directory, plus the default
+    /// and active ones.
+    fn find_profiles(&self, config: &StorageConfig) -> Result<BTreeSet<Profile>> {
+        let mut profiles = BTreeSet::from([Profile::default(), config.get_profile().clone()]);
+
+        let path = ConfyClient::<WalletConfy>::get_default_path(&Profile::default())?;
+        let Some(dir) = path.parent().filter(|dir| dir.exists()) else {
+            return Ok(profiles);
+        };
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_vault = path
+                .extension()
+                .is_some_and(|extension| extension == "yml" || extension == "sqlite");
+
+            if let Some(profile) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .filter(|_| is_vault)
+                .and_then(Profile::from_config_name)
+            {
+                profiles.insert(profile);
+            }
+        }
+
+        Ok(profiles)
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> ProfileService<R> for ProfileServiceImpl {
+    fn list(&self, list_profiles: ListProfiles) -> Result<()> {
+        let config = StorageConfig::load_from_env()?;
+
+        let mut views = Vec::new();
+        for profile in self.find_profiles(&config)? {
+            let active = &profile == config.get_profile();
+            let client = if active {
+                config.open::<WalletConfy>(config.get_storage())?
+            } else {
+                config
+                    .for_profile(&profile)
+                    .open::<WalletConfy>(config.get_storage())?
+            };
+
+            views.push(ProfileView::new(
+                profile.to_string(),
+                active,
+                client.get_path().display().to_string(),
+                client.exists(),
+                Cipher::load_for_profile(&profile).is_ok(),
+            ));
+        }
+
+        let view = ProfileListView::from_profiles(views);
+        if list_profiles.json {
+            println!("{}", view.to_json_string());
+        } else {
+            view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+
+    fn create(&self, create_profile: CreateProfile) -> Result<()> {
+        let config = StorageConfig::load_from_env()?;
+        let client = config
+            .for_profile(&create_profile.name)
+            .open::<WalletConfy>(config.get_storage())?;
+
+        if client.exists() {
+            return Err(Error::ProfileAlreadyExists(create_profile.name.to_string()));
+        }
+
+        // The new store is signed with the profile's cipher
+        if let Err(e) = Cipher::load_for_profile(&create_profile.name) {
+            let prefix = create_profile.name.get_env_prefix();
+            eprintln!(
+                "Run new-cipher and set {}CIPHER_KEY and {}CIPHER_NONCE before creating the profile",
+                prefix, prefix
+            );
+            return Err(e);
+        }
+
+        client.store(WalletConfy::default())?;
+
+        println!(
+            "Profile {} created at {}",
+            create_profile.name,
+            client.get_path().display()
+        );
+
+        Ok(())
+    }
+
+    fn delete(&self, delete_profile: DeleteProfile) -> Result<()> {
+        let config = StorageConfig::load_from_env()?;
+        if &delete_profile.name == config.get_profile() {
+            return Err(Error::ProfileInUse(delete_profile.name.to_string()));
+        }
+
+        let client = config
+            .for_profile(&delete_profile.name)
+            .open::<WalletConfy>(config.get_storage())?;
+        if !client.exists() {
+            return Err(Error::ProfileNotFound(delete_profile.name.to_string()));
+        }
+
+        // Backups hold encrypted credentials too, and would otherwise be
+        // picked up by a new profile of the same name. A store that can't be
+        // verified with the profile's cipher can still be deleted.
+        let loaded = client.load();
+        let path = client.get_path();
+        let siblings = find_siblings(&path)?;
+
+        let prompt = match loaded {
+            Ok(wallet_confy) => format!(
+                "Delete profile {} with {} wallet(s) and {} related file(s)?",
+                delete_profile.name,
+                wallet_confy.get_wallets().get_wallets().len(),
+                siblings.len()
+            ),
+            Err(_) => format!(
+                "Delete profile {} and {} related file(s)?",
+                delete_profile.name,
+                siblings.len()
+            ),
+        };
+        if !delete_profile.yes
+            && !Confirm::new()
+                .with_prompt(prompt)
+                .default(false)
+                .interact()?
+        {
+            return Err(Error::Aborted);
+        }
+
+        // Releases the lock before its file is removed
+        drop(client);
+        fs::remove_file(&path)?;
+        for sibling in siblings.iter() {
+            fs::remove_file(sibling)?;
+        }
+
+        println!(
+            "Profile {} deleted along with {} related file(s)",
+            delete_profile.name,
+            siblings.len()
+        );
+
+        Ok(())
+    }
+
+    fn transfer(&self, transfer_wallets: TransferWallets, repository: R) -> Result<()> {
+        let config = StorageConfig::load_from_env()?;
+        if &transfer_wallets.to == config.get_profile() {
+            return Err(Error::ProfileInUse(transfer_wallets.to.to_string()));
+        }
+
+        let target = config
+            .for_profile(&transfer_wallets.to)
+            .open::<WalletConfy>(config.get_storage())?;
+        if !target.exists() {
+            return Err(Error::ProfileNotFound(transfer_wallets.to.to_string()));
+        }
+
+        let source_cipher = Cipher::load_for_profile(config.get_profile())?;
+        let target_cipher = Cipher::load_for_profile(&transfer_wallets.to)?;
+
+        let mut wallet_confy = repository.load()?;
+        let mut target_confy = target.load()?;
+
+        let mut transferred = Vec::new();
+        let mut conflicts = Vec::new();
+        for wallet in transfer_wallets
+            .selection
+            .select(wallet_confy.get_wallets())?
+        {
+            let mut copy = wallet.clone();
+            if let Some(secret) = wallet.get_secret(&source_cipher)? {
+                copy.set_secret(&secret, &target_cipher)?;
+            }
+
+            target_confy.import_tags(wallet.get_tags(), &wallet_confy);
+            match target_confy.add_wallet(copy) {
+                Ok(()) => transferred.push(*wallet.get_address()),
+                Err(e) => conflicts.push(MergeConflict::new("wallet", wallet.get_label(), e)),
+            }
+        }
+
+        if !conflicts.is_empty() {
+            MergeConflictListView::from_conflicts(&conflicts)
+                .to_table()
+                .printstd();
+        }
+
+        target.store(target_confy)?;
+
+        if transfer_wallets.remove {
+            for address in transferred.iter() {
+                wallet_confy.mut_wallets().remove(address);
+            }
+            repository.store(wallet_confy)?;
+        }
+
+        println!(
+            "{} {} wallet(s) to profile {}",
+            if transfer_wallets.remove {
+                "Moved"
+            } else {
+                "Copied"
+            },
+            transferred.len(),
+            transfer_wallets.to
+        );
+
+        Ok(())
+    }
+}
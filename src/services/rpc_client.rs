@@ -0,0 +1,102 @@
+use crate::{
+    error::{Error, Result},
+    models::{object_filter::ObjectFilter, wallet::Wallet},
+};
+use sui_sdk::{
+    rpc_types::{
+        SuiObjectData, SuiObjectDataOptions, SuiObjectResponseQuery, SuiTransactionBlockResponse,
+        SuiTransactionBlockResponseOptions,
+    },
+    types::{
+        base_types::{ObjectID, SuiAddress},
+        quorum_driver_types::ExecuteTransactionRequestType,
+        transaction::{CallArg, ObjectArg, ProgrammableTransaction, TransactionData},
+    },
+    SuiClient,
+};
+
+pub const DEFAULT_GAS_BUDGET: u64 = 50_000_000;
+
+pub async fn get_owned_objects(
+    sui_client: &SuiClient,
+    address: SuiAddress,
+    filter: Option<&ObjectFilter>,
+    options: SuiObjectDataOptions,
+) -> Result<Vec<SuiObjectData>> {
+    let mut objects = Vec::new();
+
+    let query = SuiObjectResponseQuery::new(
+        filter.map(|filter| filter.to_sui_object_data_filter()),
+        Some(options),
+    );
+
+    let mut next_cursor = None;
+
+    loop {
+        let result = sui_client
+            .read_api()
+            .get_owned_objects(address, Some(query.clone()), next_cursor, None)
+            .await?;
+
+        objects.extend(result.data.into_iter().filter_map(|object| object.data));
+
+        if result.has_next_page {
+            next_cursor = result.next_cursor;
+        } else {
+            break;
+        }
+    }
+
+    Ok(objects)
+}
+
+/// Pays gas from the wallet's SUI coins, adding `amount` on top of the budget
+/// for transactions that split their payment off the gas coin.
+pub async fn execute_programmable_transaction(
+    sui_client: &SuiClient,
+    wallet: &Wallet,
+    programmable_transaction: ProgrammableTransaction,
+    amount: u64,
+    gas_budget: u64,
+) -> Result<SuiTransactionBlockResponse> {
+    let sender = *wallet.get_address();
+
+    let input_objects = programmable_transaction
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            CallArg::Object(ObjectArg::ImmOrOwnedObject((object_id, _, _))) => Some(*object_id),
+            _ => None,
+        })
+        .collect::<Vec<ObjectID>>();
+
+    let gas_coins = sui_client
+        .coin_read_api()
+        .select_coins(
+            sender,
+            None,
+            amount as u128 + gas_budget as u128,
+            input_objects,
+        )
+        .await?;
+
+    let gas_price = sui_client.read_api().get_reference_gas_price().await?;
+
+    let transaction = wallet.sign_transaction(TransactionData::new_programmable(
+        sender,
+        gas_coins.iter().map(|coin| coin.object_ref()).collect(),
+        programmable_transaction,
+        gas_budget,
+        gas_price,
+    ))?;
+
+    sui_client
+        .quorum_driver_api()
+        .execute_transaction_block(
+            transaction,
+            SuiTransactionBlockResponseOptions::full_content(),
+            Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+        )
+        .await
+        .map_err(Error::SuiClientError)
+}
@@ -33,6 +33,7 @@ pub struct CreateRpc {
     pub url: RpcUrl,
     pub alias: Alias,
     pub env: Option<AddNetworkEnv>,
+    pub ws_url: Option<RpcUrl>,
 }
 
 pub struct RemoveRpc {
@@ -55,17 +56,20 @@ impl<R: WalletRepository<WalletConfy>> RpcService<R> for RpcServiceImpl {
     fn create(&self, create_rpc: CreateRpc, repository: R) -> Result<()> {
         let mut wallet_confy = repository.load()?;
 
-        wallet_confy.mut_rpc_servers().add(RpcServer::new(
-            create_rpc.url,
-            create_rpc.alias,
-            match create_rpc.env {
-                Some(AddNetworkEnv::Mainnet) => NetworkEnv::Mainnet,
-                Some(AddNetworkEnv::Testnet) => NetworkEnv::Testnet,
-                Some(AddNetworkEnv::Devnet) => NetworkEnv::Devnet,
-                Some(AddNetworkEnv::Local) => NetworkEnv::Local,
-                None => NetworkEnv::None,
-            },
-        ))?;
+        wallet_confy.mut_rpc_servers().add(
+            RpcServer::new(
+                create_rpc.url,
+                create_rpc.alias,
+                match create_rpc.env {
+                    Some(AddNetworkEnv::Mainnet) => NetworkEnv::Mainnet,
+                    Some(AddNetworkEnv::Testnet) => NetworkEnv::Testnet,
+                    Some(AddNetworkEnv::Devnet) => NetworkEnv::Devnet,
+                    Some(AddNetworkEnv::Local) => NetworkEnv::Local,
+                    None => NetworkEnv::None,
+                },
+            )
+            .with_ws_url(create_rpc.ws_url),
+        )?;
 
         repository.store(wallet_confy.clone())?;
 
@@ -76,14 +80,9 @@ impl<R: WalletRepository<WalletConfy>> RpcService<R> for RpcServiceImpl {
     fn remote(&self, remove_rpc: RemoveRpc, repository: R) -> Result<()> {
         let mut wallet_confy = repository.load()?;
 
-        let url = match remove_rpc.alias_or_url {
-            AliasOrUrl::Url(url) => url,
-            AliasOrUrl::Alias(alias) => wallet_confy
-                .get_rpc_servers()
-                .get_url_by_alias(&alias)
-                .ok_or(Error::NetworkAliasNotFound(alias))?
-                .clone(),
-        };
+        let url = wallet_confy
+            .get_rpc_servers()
+            .resolve_url(&remove_rpc.alias_or_url)?;
 
         let rpc_server = wallet_confy
             .get_rpc_servers()
@@ -0,0 +1,369 @@
+use crate::{
+    commands::{StakeService, WalletRepository},
+    error::{Error, Result},
+    models::{
+        address_or_name::AddressOrName, alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl,
+        rpc_url::RpcUrl, sui_amount::SuiAmount, timestamp::Timestamp, wallet::Wallet,
+        wallet_confy::WalletConfy, wallet_selection::WalletSelection,
+    },
+    services::rpc_client::execute_programmable_transaction,
+    views::{
+        stake_view::{StakeListView, ValidatorListView},
+        transaction_view::TransactionListView,
+    },
+};
+use indicatif::ProgressIterator;
+use std::collections::HashMap;
+use sui_sdk::{
+    rpc_types::{DelegatedStake, SuiObjectDataOptions, SuiTransactionBlockResponse},
+    types::{
+        base_types::{ObjectID, ObjectRef, SuiAddress},
+        governance::{ADD_STAKE_FUN_NAME, WITHDRAW_STAKE_FUN_NAME},
+        object::Owner,
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        sui_system_state::{
+            sui_system_state_summary::SuiSystemStateSummary, SUI_SYSTEM_MODULE_NAME,
+        },
+        transaction::{Argument, Command, ObjectArg},
+        SUI_SYSTEM_PACKAGE_ID,
+    },
+    SuiClientBuilder,
+};
+
+#[derive(Default)]
+pub struct StakeServiceImpl;
+
+pub struct AddStake {
+    pub alias_or_address: AliasOrAddress,
+    pub validator: AddressOrName,
+    pub amount: SuiAmount,
+    pub rpc: AliasOrUrl,
+    pub gas_budget: u64,
+    pub json: bool,
+}
+
+pub struct WithdrawStake {
+    pub staked_sui_ids: Vec<ObjectID>,
+    pub rpc: AliasOrUrl,
+    pub gas_budget: u64,
+    pub json: bool,
+}
+
+pub struct ListStakes {
+    pub selection: WalletSelection,
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+pub struct ListValidators {
+    pub rpc: AliasOrUrl,
+    pub json: bool,
+}
+
+impl StakeServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn add_stake(
+        &self,
+        wallet: &Wallet,
+        validator: AddressOrName,
+        amount: SuiAmount,
+        gas_budget: u64,
+        url: RpcUrl,
+    ) -> Result<SuiTransactionBlockResponse> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let system_state = sui_client
+                .governance_api()
+                .get_latest_sui_system_state()
+                .await?;
+
+            let validator_address = system_state
+                .active_validators
+                .iter()
+                .find(|summary| validator.matches(&summary.sui_address, &summary.name))
+                .ok_or(Error::ValidatorNotFound(validator.to_string()))?
+                .sui_address;
+
+            let mut builder = ProgrammableTransactionBuilder::new();
+
+            let amount_argument = builder
+                .pure(amount.get_mist())
+                .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+            let coin = builder.command(Command::SplitCoins(
+                Argument::GasCoin,
+                vec![amount_argument],
+            ));
+            let system_state_argument = builder
+                .obj(ObjectArg::SUI_SYSTEM_MUT)
+                .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+            let validator_argument = builder
+                .pure(validator_address)
+                .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+
+            builder.programmable_move_call(
+                SUI_SYSTEM_PACKAGE_ID,
+                SUI_SYSTEM_MODULE_NAME.to_owned(),
+                ADD_STAKE_FUN_NAME.to_owned(),
+                vec![],
+                vec![system_state_argument, coin, validator_argument],
+            );
+
+            execute_programmable_transaction(
+                &sui_client,
+                wallet,
+                builder.finish(),
+                amount.get_mist(),
+                gas_budget,
+            )
+            .await
+        })
+    }
+
+    fn withdraw_stakes(
+        &self,
+        wallets: Vec<Wallet>,
+        staked_sui_ids: Vec<ObjectID>,
+        gas_budget: u64,
+        url: RpcUrl,
+    ) -> Result<Vec<(Wallet, SuiTransactionBlockResponse)>> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let objects = sui_client
+                .read_api()
+                .multi_get_object_with_options(
+                    staked_sui_ids.clone(),
+                    SuiObjectDataOptions::new().with_owner(),
+                )
+                .await?;
+
+            let mut withdrawals: Vec<(Wallet, Vec<ObjectRef>)> = Vec::new();
+
+            for (object_id, object) in staked_sui_ids.iter().zip(objects.into_iter()) {
+                let data = object.data.ok_or(Error::ObjectNotOwned(*object_id))?;
+
+                let wallet = match data.owner {
+                    Some(Owner::AddressOwner(owner)) => wallets
+                        .iter()
+                        .find(|wallet| *wallet.get_address() == owner)
+                        .ok_or(Error::ObjectNotOwned(*object_id))?,
+                    _ => return Err(Error::ObjectNotOwned(*object_id)),
+                };
+
+                match withdrawals
+                    .iter_mut()
+                    .find(|(w, _)| w.get_address() == wallet.get_address())
+                {
+                    Some((_, object_refs)) => object_refs.push(data.object_ref()),
+                    None => withdrawals.push((wallet.clone(), vec![data.object_ref()])),
+                }
+            }
+
+            let mut responses = Vec::new();
+
+            for (wallet, object_refs) in withdrawals.into_iter().progress() {
+                let mut builder = ProgrammableTransactionBuilder::new();
+
+                for object_ref in object_refs {
+                    let system_state_argument = builder
+                        .obj(ObjectArg::SUI_SYSTEM_MUT)
+                        .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+                    let staked_sui_argument = builder
+                        .obj(ObjectArg::ImmOrOwnedObject(object_ref))
+                        .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+
+                    builder.programmable_move_call(
+                        SUI_SYSTEM_PACKAGE_ID,
+                        SUI_SYSTEM_MODULE_NAME.to_owned(),
+                        WITHDRAW_STAKE_FUN_NAME.to_owned(),
+                        vec![],
+                        vec![system_state_argument, staked_sui_argument],
+                    );
+                }
+
+                let response = execute_programmable_transaction(
+                    &sui_client,
+                    &wallet,
+                    builder.finish(),
+                    0,
+                    gas_budget,
+                )
+                .await?;
+
+                responses.push((wallet, response));
+            }
+
+            Ok(responses)
+        })
+    }
+
+    fn get_stakes(
+        &self,
+        wallets: &[Wallet],
+        url: RpcUrl,
+    ) -> Result<(SuiSystemStateSummary, Vec<(Wallet, Vec<DelegatedStake>)>)> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let system_state = sui_client
+                .governance_api()
+                .get_latest_sui_system_state()
+                .await?;
+
+            let mut stakes = Vec::new();
+
+            for wallet in wallets.iter().progress() {
+                stakes.push((
+                    wallet.clone(),
+                    sui_client
+                        .governance_api()
+                        .get_stakes(*wallet.get_address())
+                        .await?,
+                ));
+            }
+
+            Ok((system_state, stakes))
+        })
+        .map_err(Error::SuiClientError)
+    }
+
+    fn get_validators(
+        &self,
+        url: RpcUrl,
+    ) -> Result<(SuiSystemStateSummary, HashMap<SuiAddress, f64>)> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let system_state = sui_client
+                .governance_api()
+                .get_latest_sui_system_state()
+                .await?;
+
+            let apys = sui_client
+                .governance_api()
+                .get_validators_apy()
+                .await?
+                .apys
+                .into_iter()
+                .map(|apy| (apy.address, apy.apy))
+                .collect();
+
+            Ok((system_state, apys))
+        })
+        .map_err(Error::SuiClientError)
+    }
+
+    fn print_transactions(
+        &self,
+        responses: Vec<(Wallet, SuiTransactionBlockResponse)>,
+        json: bool,
+    ) {
+        let transaction_view = TransactionListView::from_responses(&responses);
+
+        if json {
+            println!("{}", transaction_view.to_json_string());
+        } else {
+            transaction_view.to_table().printstd();
+        }
+    }
+}
+
+impl<R: WalletRepository<WalletConfy>> StakeService<R> for StakeServiceImpl {
+    fn stake(&self, add_stake: AddStake, repository: R) -> Result<()> {
+        let mut confy = repository.load()?;
+
+        let wallet = confy
+            .get_wallets()
+            .get_by_alias_or_address(&add_stake.alias_or_address)?
+            .clone();
+        let url = confy.get_rpc_servers().resolve_url(&add_stake.rpc)?;
+
+        let response = self.add_stake(
+            &wallet,
+            add_stake.validator,
+            add_stake.amount,
+            add_stake.gas_budget,
+            url,
+        )?;
+
+        confy.mark_used(&[*wallet.get_address()], Timestamp::now());
+        repository.store(confy.clone())?;
+
+        self.print_transactions(vec![(wallet, response)], add_stake.json);
+
+        Ok(())
+    }
+
+    fn unstake(&self, withdraw_stake: WithdrawStake, repository: R) -> Result<()> {
+        let mut confy = repository.load()?;
+
+        let url = confy.get_rpc_servers().resolve_url(&withdraw_stake.rpc)?;
+
+        let responses = self.withdraw_stakes(
+            confy.get_wallets().get_wallets(),
+            withdraw_stake.staked_sui_ids,
+            withdraw_stake.gas_budget,
+            url,
+        )?;
+
+        confy.mark_used(
+            &responses
+                .iter()
+                .map(|(wallet, _)| *wallet.get_address())
+                .collect::<Vec<_>>(),
+            Timestamp::now(),
+        );
+        repository.store(confy.clone())?;
+
+        self.print_transactions(responses, withdraw_stake.json);
+
+        Ok(())
+    }
+
+    fn list_stakes(&self, list_stakes: ListStakes, repository: R) -> Result<()> {
+        let confy = repository.load()?;
+        repository.release();
+
+        let wallets = list_stakes.selection.select(confy.get_wallets())?;
+        let url = confy.get_rpc_servers().resolve_url(&list_stakes.rpc)?;
+
+        let (system_state, stakes) = self.get_stakes(&wallets, url)?;
+
+        let stake_view = StakeListView::from_stakes(&system_state, stakes);
+
+        if list_stakes.json {
+            println!("{}", stake_view.to_json_string());
+        } else {
+            stake_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+
+    fn list_validators(&self, list_validators: ListValidators, repository: R) -> Result<()> {
+        let url = repository
+            .load()?
+            .get_rpc_servers()
+            .resolve_url(&list_validators.rpc)?;
+
+        let (system_state, apys) = self.get_validators(url)?;
+
+        let validator_view = ValidatorListView::from_system_state(&system_state, &apys);
+
+        if list_validators.json {
+            println!("{}", validator_view.to_json_string());
+        } else {
+            validator_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
+}
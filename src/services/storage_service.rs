@@ -0,0 +1,90 @@
+use crate::{
+    commands::{StorageService, WalletRepository},
+    error::{Error, Result},
+    models::wallet_confy::WalletConfy,
+    storages::{StorageConfig, WalletStorage},
+};
+use serde_json::Value;
+
+#[derive(Default)]
+pub struct StorageServiceImpl;
+
+pub struct MigrateStorage {
+    pub to: WalletStorage,
+    pub force: bool,
+}
+
+impl StorageServiceImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl StorageService for StorageServiceImpl {
+    fn migrate(&self, migrate_storage: MigrateStorage) -> Result<()> {
+        let config = StorageConfig::load_from_env()?;
+        let from = match migrate_storage.to {
+            WalletStorage::Yaml => WalletStorage::Sqlite,
+            WalletStorage::Sqlite => WalletStorage::Yaml,
+        };
+        let source = config.open::<WalletConfy>(from)?;
+        let target = config.open::<WalletConfy>(migrate_storage.to)?;
+
+        if !source.exists() {
+            return Err(Error::StorageNotFound(
+                source.get_path().display().to_string(),
+            ));
+        }
+        if target.exists() && !migrate_storage.force {
+            return Err(Error::StorageAlreadyExists(
+                target.get_path().display().to_string(),
+            ));
+        }
+
+        let confy = source.load()?;
+        let count = confy.get_wallets().get_wallets().len();
+        let expected = canonical(serde_json::to_value(&confy)?);
+        target.store(confy)?;
+
+        if canonical(serde_json::to_value(target.load()?)?) != expected {
+            return Err(Error::StorageError(format!(
+                "{} does not match {} after migration",
+                target.get_path().display(),
+                source.get_path().display()
+            )));
+        }
+
+        println!(
+            "Migrated {} wallets from {} to {}",
+            count,
+            source.get_path().display(),
+            target.get_path().display()
+        );
+        if config.get_storage() != migrate_storage.to {
+            println!(
+                "Set WALLET_STORAGE={} to use the migrated storage",
+                migrate_storage.to
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Sorts arrays so that sets serialized in hash order compare equal.
+fn canonical(value: Value) -> Value {
+    match value {
+        Value::Array(values) => {
+            let mut values = values.into_iter().map(canonical).collect::<Vec<_>>();
+            values.sort_by_key(|value| value.to_string());
+            Value::Array(values)
+        }
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .map(|(key, value)| (key, canonical(value)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    error::{Error, Result},
+    models::{
+        alias_or_address::AliasOrAddress, rpc_url::RpcUrl, suins_cache::SuiNsCache,
+        suins_name::SuiNsName, timestamp::Timestamp, wallet_confy::WalletConfy,
+    },
+    storages::cache::CacheClient,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use std::{collections::HashMap, str::FromStr};
+use sui_sdk::types::base_types::SuiAddress;
+
+#[derive(Deserialize)]
+struct NamePage {
+    data: Vec<String>,
+}
+
+const CACHE_NAME: &str = "suins_cache";
+
+/// SuiNS lookups go through the node's JSON-RPC name service endpoints.
+fn call<T: DeserializeOwned>(url: &RpcUrl, method: &str, params: Value) -> Result<T> {
+    let response = reqwest::blocking::Client::new()
+        .post(url.to_string())
+        .header("Content-Type", "application/json")
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        }))
+        .send()?
+        .json::<Value>()?;
+
+    if let Some(error) = response.get("error") {
+        return Err(Error::SuiNsLookupError(error.to_string()));
+    }
+
+    serde_json::from_value(response.get("result").cloned().unwrap_or(Value::Null))
+        .map_err(|e| Error::SuiNsLookupError(e.to_string()))
+}
+
+/// Resolves SuiNS names through `url`. Results are cached in their own
+/// file rather than the vault, so lookups never rewrite the vault.
+pub struct SuiNsClient {
+    url: RpcUrl,
+    cache_client: CacheClient<SuiNsCache>,
+    cache: SuiNsCache,
+}
+
+impl SuiNsClient {
+    pub fn new(url: &RpcUrl) -> Result<Self> {
+        let cache_client = CacheClient::new(CACHE_NAME)?;
+        let cache = cache_client.load();
+
+        Ok(Self {
+            url: url.clone(),
+            cache_client,
+            cache,
+        })
+    }
+
+    pub fn resolve_name(&mut self, name: &SuiNsName) -> Result<SuiAddress> {
+        let address = self.lookup_address(name)?;
+        self.save()?;
+
+        address.ok_or(Error::SuiNsNameNotFound(name.to_string()))
+    }
+
+    /// Like `WalletConfy::resolve_address`, but also resolves `name.sui`.
+    pub fn resolve_address(
+        &mut self,
+        confy: &WalletConfy,
+        alias_or_address: &AliasOrAddress,
+    ) -> Result<SuiAddress> {
+        match alias_or_address {
+            AliasOrAddress::SuiNs(name) => self.resolve_name(name),
+            _ => confy.resolve_address(alias_or_address),
+        }
+    }
+
+    /// Resolves every SuiNS name mentioned in free-form arguments such as
+    /// `[alice.sui, bob.sui]`. Unregistered names are left out.
+    pub fn resolve_names_in(&mut self, args: &[String]) -> Result<HashMap<SuiNsName, SuiAddress>> {
+        let mut names = HashMap::new();
+
+        for token in args.iter().flat_map(|arg| {
+            arg.split(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-'))
+        }) {
+            if let Ok(name) = SuiNsName::from_str(token) {
+                if let Some(address) = self.lookup_address(&name)? {
+                    names.insert(name, address);
+                }
+            }
+        }
+        self.save()?;
+
+        Ok(names)
+    }
+
+    /// Primary SuiNS names of `addresses`, for those that have one.
+    pub fn lookup_names(
+        &mut self,
+        addresses: &[SuiAddress],
+    ) -> Result<HashMap<SuiAddress, SuiNsName>> {
+        let now = Timestamp::now();
+        let mut names = HashMap::new();
+
+        for address in addresses.iter() {
+            let name = match self.cache.get_name(&self.url, address, now) {
+                Some(name) => name,
+                None => {
+                    let name = call::<NamePage>(
+                        &self.url,
+                        "suix_resolveNameServiceNames",
+                        json!([address, null, 1]),
+                    )?
+                    .data
+                    .first()
+                    .and_then(|name| SuiNsName::from_str(name).ok());
+                    self.cache.prune(now);
+                    self.cache
+                        .insert_name(&self.url, *address, name.clone(), now);
+                    name
+                }
+            };
+
+            if let Some(name) = name {
+                names.insert(*address, name);
+            }
+        }
+        self.save()?;
+
+        Ok(names)
+    }
+
+    fn lookup_address(&mut self, name: &SuiNsName) -> Result<Option<SuiAddress>> {
+        let now = Timestamp::now();
+
+        if let Some(address) = self.cache.get_address(&self.url, name, now) {
+            return Ok(address);
+        }
+
+        let address = call::<Option<SuiAddress>>(
+            &self.url,
+            "suix_resolveNameServiceAddress",
+            json!([name.to_string()]),
+        )?;
+        self.cache.prune(now);
+        self.cache
+            .insert_address(&self.url, name.clone(), address, now);
+
+        Ok(address)
+    }
+
+    /// Writes the cache back if a lookup went to the network.
+    fn save(&mut self) -> Result<()> {
+        if self.cache.is_changed() {
+            self.cache_client.store(&self.cache)?;
+            self.cache.clear_changed();
+        }
+
+        Ok(())
+    }
+}
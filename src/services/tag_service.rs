@@ -1,29 +1,77 @@
 use crate::{
     commands::{TagService, WalletRepository},
-    error::Result,
-    models::{tag_list::TagList, wallet_confy::WalletConfy},
-    views::tag_view::TagListView,
+    error::{Error, Result},
+    models::{
+        alias_or_address::AliasOrAddress, tag::Tag, tag_color::TagColor, tag_details::TagDetails,
+        tag_list::TagList, wallet_confy::WalletConfy, wallet_edit::WalletEdit,
+        wallet_selection::WalletSelection,
+    },
+    views::tag_view::{TagListView, TagUsageView},
 };
+use dialoguer::Confirm;
 
 #[derive(Default)]
 pub struct TagServiceImpl;
 
 pub struct CreateTags {
     pub names: TagList,
+    pub description: Option<String>,
+    pub color: Option<TagColor>,
+}
+
+pub struct EditTag {
+    pub name: Tag,
+    pub description: Option<String>,
+    pub color: Option<TagColor>,
+    pub clear_color: bool,
+}
+
+pub struct RenameTag {
+    pub old: Tag,
+    pub new: Tag,
 }
 
 pub struct RemoveTags {
     pub names: TagList,
+    pub yes: bool,
 }
 
 pub struct ListTags {
     pub json: bool,
 }
 
+pub struct AssignTags {
+    pub names: TagList,
+    pub selection: WalletSelection,
+}
+
+pub struct UnassignTags {
+    pub names: TagList,
+    pub selection: WalletSelection,
+}
+
 impl TagServiceImpl {
     pub fn new() -> Self {
         Self
     }
+
+    fn edit_selection(
+        &self,
+        wallet_confy: &mut WalletConfy,
+        selection: &WalletSelection,
+        edit: &WalletEdit,
+    ) -> Result<usize> {
+        let wallets = selection.select(wallet_confy.get_wallets())?;
+        if wallets.is_empty() {
+            return Err(Error::EmptyWalletSelection);
+        }
+
+        for wallet in wallets.iter() {
+            wallet_confy.edit_wallet(&AliasOrAddress::Address(*wallet.get_address()), edit)?;
+        }
+
+        Ok(wallets.len())
+    }
 }
 
 impl<R: WalletRepository<WalletConfy>> TagService<R> for TagServiceImpl {
@@ -32,6 +80,15 @@ impl<R: WalletRepository<WalletConfy>> TagService<R> for TagServiceImpl {
 
         wallet_confy.mut_tags().extend(&create_tags.names);
 
+        if create_tags.description.is_some() || create_tags.color.is_some() {
+            for tag in create_tags.names.iter() {
+                wallet_confy.set_tag_details(
+                    tag.clone(),
+                    TagDetails::new(create_tags.description.clone(), create_tags.color),
+                )?;
+            }
+        }
+
         repository.store(wallet_confy.clone())?;
 
         println!("Tags added successfully");
@@ -41,11 +98,36 @@ impl<R: WalletRepository<WalletConfy>> TagService<R> for TagServiceImpl {
     fn remote(&self, remove_tags: RemoveTags, repository: R) -> Result<()> {
         let mut wallet_confy = repository.load()?;
 
-        for wallet in wallet_confy.mut_wallets().values_mut() {
-            wallet.mut_tags().remove(&remove_tags.names);
+        let affected = wallet_confy
+            .get_wallets()
+            .get_wallets()
+            .into_iter()
+            .filter(|wallet| {
+                remove_tags
+                    .names
+                    .iter()
+                    .any(|tag| wallet.get_tags().contains(tag))
+            })
+            .collect::<Vec<_>>();
+
+        if !affected.is_empty() && !remove_tags.yes {
+            TagUsageView::from_wallets(&affected, &remove_tags.names)
+                .to_table()
+                .printstd();
+
+            if !Confirm::new()
+                .with_prompt(format!(
+                    "Remove these tags from {} wallet(s)?",
+                    affected.len()
+                ))
+                .default(false)
+                .interact()?
+            {
+                return Err(Error::Aborted);
+            }
         }
 
-        wallet_confy.mut_tags().remove(&remove_tags.names);
+        wallet_confy.remove_tags(&remove_tags.names);
 
         repository.store(wallet_confy.clone())?;
 
@@ -54,8 +136,89 @@ impl<R: WalletRepository<WalletConfy>> TagService<R> for TagServiceImpl {
         Ok(())
     }
 
+    fn assign(&self, assign_tags: AssignTags, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        let count = self.edit_selection(
+            &mut wallet_confy,
+            &assign_tags.selection,
+            &WalletEdit::default().with_add_tags(assign_tags.names),
+        )?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Tags assigned to {} wallet(s)", count);
+
+        Ok(())
+    }
+
+    fn unassign(&self, unassign_tags: UnassignTags, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        let count = self.edit_selection(
+            &mut wallet_confy,
+            &unassign_tags.selection,
+            &WalletEdit::default().with_remove_tags(unassign_tags.names),
+        )?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Tags unassigned from {} wallet(s)", count);
+
+        Ok(())
+    }
+
+    fn edit(&self, edit_tag: EditTag, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        let mut details = wallet_confy
+            .get_tag_details()
+            .get(&edit_tag.name)
+            .cloned()
+            .unwrap_or_default();
+
+        if let Some(description) = edit_tag.description {
+            *details.mut_description() = if description.is_empty() {
+                None
+            } else {
+                Some(description)
+            };
+        }
+
+        if edit_tag.clear_color {
+            *details.mut_color() = None;
+        } else if edit_tag.color.is_some() {
+            *details.mut_color() = edit_tag.color;
+        }
+
+        wallet_confy.set_tag_details(edit_tag.name, details)?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Tag edited successfully");
+
+        Ok(())
+    }
+
+    fn rename(&self, rename_tag: RenameTag, repository: R) -> Result<()> {
+        let mut wallet_confy = repository.load()?;
+
+        wallet_confy.rename_tag(&rename_tag.old, &rename_tag.new)?;
+
+        repository.store(wallet_confy.clone())?;
+
+        println!("Tag renamed successfully");
+
+        Ok(())
+    }
+
     fn list(&self, list_tags: ListTags, repository: R) -> Result<()> {
-        let tag_view = TagListView::from_tag_list(repository.load()?.get_tags());
+        let wallet_confy = repository.load()?;
+        let tag_view = TagListView::from_tag_list(
+            wallet_confy.get_tags(),
+            wallet_confy.get_tag_details(),
+            &wallet_confy.get_tag_usage(),
+        );
 
         if list_tags.json {
             println!("{}", tag_view.to_json_string());
@@ -3,15 +3,34 @@ use crate::{
     error::{Error, Result},
     models::{
         alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl,
-        coin_object_list::CoinObjectList, rpc_url::RpcUrl, tag_list::TagList, wallet::Wallet,
-        wallet_confy::WalletConfy,
+        coin_object_list::CoinObjectList, object_filter::ObjectFilter, rpc_url::RpcUrl,
+        timestamp::Timestamp, wallet::Wallet, wallet_coin_object_list::WalletCoinObjectList,
+        wallet_confy::WalletConfy, wallet_selection::WalletSelection,
+    },
+    services::{
+        rpc_client::{execute_programmable_transaction, get_owned_objects},
+        suins_client::SuiNsClient,
+    },
+    views::{
+        coin_view::{CoinListView, WalletCoinListView},
+        transaction_view::TransactionListView,
     },
-    views::coin_view::CoinListView,
 };
 use clap::ValueEnum;
+use dialoguer::Confirm;
 use indicatif::ProgressIterator;
 use serde_json::json;
-use sui_sdk::SuiClientBuilder;
+use std::collections::HashMap;
+use sui_sdk::{
+    rpc_types::{SuiCoinMetadata, SuiObjectDataOptions, SuiTransactionBlockResponse},
+    types::{
+        base_types::{ObjectID, ObjectRef, SuiAddress},
+        object::Owner,
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        transaction::{Command, ObjectArg},
+    },
+    SuiClientBuilder,
+};
 
 #[derive(ValueEnum, Clone, Debug)]
 pub enum FaucetNetworkEnv {
@@ -20,13 +39,20 @@ pub enum FaucetNetworkEnv {
     Localnet,
 }
 
+#[derive(ValueEnum, Clone, Debug)]
+pub enum BalanceGroupBy {
+    Coin,
+    Wallet,
+}
+
 #[derive(Default)]
 pub struct TransactionServiceImpl;
 
 pub struct GetAllBalance {
-    pub aliases_or_addresses: Vec<AliasOrAddress>,
-    pub tags: Option<TagList>,
+    pub selection: WalletSelection,
     pub rpc: AliasOrUrl,
+    pub group_by: BalanceGroupBy,
+    pub include_zero: bool,
     pub json: bool,
 }
 
@@ -39,19 +65,32 @@ pub struct RequestFaucet {
     pub env: FaucetNetworkEnv,
 }
 
+pub struct TransferObjects {
+    pub selection: WalletSelection,
+    pub object_ids: Vec<ObjectID>,
+    pub filter: Option<ObjectFilter>,
+    pub recipient: AliasOrAddress,
+    pub rpc: AliasOrUrl,
+    pub gas_budget: u64,
+    pub yes: bool,
+    pub json: bool,
+}
+
 impl TransactionServiceImpl {
     pub fn new() -> Self {
         Self
     }
 
-    fn get_all_balance(&self, wallets: Vec<&Wallet>, url: RpcUrl) -> Result<CoinObjectList> {
+    fn get_all_balance(&self, wallets: &[Wallet], url: RpcUrl) -> Result<WalletCoinObjectList> {
         let rt = tokio::runtime::Runtime::new().unwrap();
         rt.block_on(async {
-            let mut coin_list = CoinObjectList::default();
+            let mut wallet_coin_list = WalletCoinObjectList::default();
+            let mut metadata_list: HashMap<String, SuiCoinMetadata> = HashMap::new();
 
             let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
 
             for wallet in wallets.iter().progress() {
+                let mut coin_list = CoinObjectList::default();
                 let mut next_cursor = None;
 
                 loop {
@@ -61,13 +100,17 @@ impl TransactionServiceImpl {
                         .await?;
 
                     for coin in result.data.into_iter() {
-                        let metadata = match coin_list.get(&coin.coin_type) {
-                            Some(coins) => coins.get_metadata().clone(),
-                            None => sui_client
-                                .coin_read_api()
-                                .get_coin_metadata(coin.coin_type.clone())
-                                .await?
-                                .unwrap(),
+                        let metadata = match metadata_list.get(&coin.coin_type) {
+                            Some(metadata) => metadata.clone(),
+                            None => {
+                                let metadata = sui_client
+                                    .coin_read_api()
+                                    .get_coin_metadata(coin.coin_type.clone())
+                                    .await?
+                                    .unwrap();
+                                metadata_list.insert(coin.coin_type.clone(), metadata.clone());
+                                metadata
+                            }
                         };
 
                         coin_list.entry(coin, metadata);
@@ -79,12 +122,115 @@ impl TransactionServiceImpl {
                         break;
                     }
                 }
+
+                wallet_coin_list.add(wallet.clone(), coin_list);
             }
 
-            Ok(coin_list)
+            Ok(wallet_coin_list)
         })
         .map_err(Error::SuiClientError)
     }
+
+    fn transfer_objects(
+        &self,
+        wallets: Vec<Wallet>,
+        object_ids: Vec<ObjectID>,
+        filter: Option<ObjectFilter>,
+        recipient: SuiAddress,
+        gas_budget: u64,
+        url: RpcUrl,
+    ) -> Result<Vec<(Wallet, SuiTransactionBlockResponse)>> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let sui_client = SuiClientBuilder::default().build(&url.to_string()).await?;
+
+            let mut transfers: Vec<(Wallet, Vec<ObjectRef>)> = wallets
+                .into_iter()
+                .map(|wallet| (wallet, Vec::new()))
+                .collect();
+
+            if !object_ids.is_empty() {
+                let objects = sui_client
+                    .read_api()
+                    .multi_get_object_with_options(
+                        object_ids.clone(),
+                        SuiObjectDataOptions::new().with_owner(),
+                    )
+                    .await?;
+
+                for (object_id, object) in object_ids.iter().zip(objects.into_iter()) {
+                    let data = object.data.ok_or(Error::ObjectNotOwned(*object_id))?;
+
+                    let owner = match data.owner {
+                        Some(Owner::AddressOwner(owner)) => owner,
+                        _ => return Err(Error::ObjectNotOwned(*object_id)),
+                    };
+
+                    transfers
+                        .iter_mut()
+                        .find(|(wallet, _)| *wallet.get_address() == owner)
+                        .ok_or(Error::ObjectNotOwned(*object_id))?
+                        .1
+                        .push(data.object_ref());
+                }
+            }
+
+            if let Some(ref filter) = filter {
+                for (wallet, object_refs) in transfers.iter_mut() {
+                    for object in get_owned_objects(
+                        &sui_client,
+                        *wallet.get_address(),
+                        Some(filter),
+                        SuiObjectDataOptions::new(),
+                    )
+                    .await?
+                    {
+                        if !object_refs
+                            .iter()
+                            .any(|(object_id, _, _)| *object_id == object.object_id)
+                        {
+                            object_refs.push(object.object_ref());
+                        }
+                    }
+                }
+            }
+
+            transfers.retain(|(_, object_refs)| !object_refs.is_empty());
+
+            if transfers.is_empty() {
+                return Err(Error::NoObjectsToTransfer);
+            }
+
+            let mut responses = Vec::new();
+
+            for (wallet, object_refs) in transfers.into_iter().progress() {
+                let mut builder = ProgrammableTransactionBuilder::new();
+
+                let objects = object_refs
+                    .into_iter()
+                    .map(|object_ref| builder.obj(ObjectArg::ImmOrOwnedObject(object_ref)))
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+                let recipient = builder
+                    .pure(recipient)
+                    .map_err(|e| Error::TransactionBuildError(e.to_string()))?;
+                builder.command(Command::TransferObjects(objects, recipient));
+
+                let response = execute_programmable_transaction(
+                    &sui_client,
+                    &wallet,
+                    builder.finish(),
+                    0,
+                    gas_budget,
+                )
+                .await?;
+
+                responses.push((wallet, response));
+            }
+
+            Ok(responses)
+        })
+    }
 }
 
 impl<R: WalletRepository<WalletConfy>> TransactionService<R> for TransactionServiceImpl {
@@ -93,40 +239,46 @@ impl<R: WalletRepository<WalletConfy>> TransactionService<R> for TransactionServ
     }
     fn balance(&self, get_all_balance: GetAllBalance, repository: R) -> Result<()> {
         let confy = repository.load()?;
-        let wallets = confy.get_wallets();
-        let rpc_servers = confy.get_rpc_servers();
-
-        let wallets: Vec<&Wallet> = get_all_balance
-            .aliases_or_addresses
-            .iter()
-            .filter_map(|alias_or_address| match alias_or_address {
-                AliasOrAddress::Address(address) => wallets.get_by_key(address),
-                AliasOrAddress::Alias(alias) => wallets
-                    .get_address_by_alias(alias)
-                    .and_then(|address| wallets.get_by_key(address)),
-            })
-            .filter(|wallet| {
-                get_all_balance
-                    .tags
-                    .as_ref()
-                    .map_or(true, |tag| wallet.get_tags().contains_all(tag))
-            })
-            .collect();
+        repository.release();
 
-        let url = match get_all_balance.rpc {
-            AliasOrUrl::Url(url) => url,
-            AliasOrUrl::Alias(alias) => rpc_servers
-                .get_url_by_alias(&alias)
-                .ok_or(Error::NetworkAliasNotFound(alias))?
-                .clone(),
-        };
+        let wallets = get_all_balance.selection.select(confy.get_wallets())?;
 
-        let coin_view = CoinListView::from_coin_object_list(self.get_all_balance(wallets, url)?);
+        let url = confy.get_rpc_servers().resolve_url(&get_all_balance.rpc)?;
 
-        if get_all_balance.json {
-            println!("{}", coin_view.to_json_string());
-        } else {
-            coin_view.to_table().printstd();
+        let wallet_coin_list = self.get_all_balance(&wallets, url.clone())?;
+
+        match get_all_balance.group_by {
+            BalanceGroupBy::Coin => {
+                let coin_view = CoinListView::from_coin_object_list(
+                    wallet_coin_list.merge(),
+                    get_all_balance.include_zero,
+                );
+
+                if get_all_balance.json {
+                    println!("{}", coin_view.to_json_string());
+                } else {
+                    coin_view.to_table().printstd();
+                }
+            }
+            BalanceGroupBy::Wallet => {
+                let addresses = wallets
+                    .iter()
+                    .map(|wallet| *wallet.get_address())
+                    .collect::<Vec<_>>();
+                let names = SuiNsClient::new(&url)?.lookup_names(&addresses)?;
+
+                let coin_view = WalletCoinListView::from_wallet_coin_object_list(
+                    wallet_coin_list,
+                    &names,
+                    get_all_balance.include_zero,
+                );
+
+                if get_all_balance.json {
+                    println!("{}", coin_view.to_json_string());
+                } else {
+                    coin_view.to_table().printstd();
+                }
+            }
         }
 
         Ok(())
@@ -134,24 +286,11 @@ impl<R: WalletRepository<WalletConfy>> TransactionService<R> for TransactionServ
 
     fn faucet(&self, request_faucet: RequestFaucet, repository: R) -> Result<()> {
         let confy = repository.load()?;
-        let wallet = match request_faucet.alias_or_address {
-            AliasOrAddress::Address(address) => confy
-                .get_wallets()
-                .get_by_key(&address)
-                .ok_or(Error::WalletAddressNotFound(address))?,
-            AliasOrAddress::Alias(alias) => {
-                let address = confy
-                    .get_wallets()
-                    .get_address_by_alias(&alias)
-                    .ok_or(Error::WalletAliasNotFound(alias))?;
-
-                confy
-                    .get_wallets()
-                    .get_by_key(address)
-                    .ok_or(Error::WalletAddressNotFound(*address))?
-            }
-        }
-        .clone();
+        repository.release();
+        let wallet = confy
+            .get_wallets()
+            .get_by_alias_or_address(&request_faucet.alias_or_address)?
+            .clone();
 
         let resp = reqwest::blocking::Client::new()
             .post(match request_faucet.env {
@@ -175,4 +314,58 @@ impl<R: WalletRepository<WalletConfy>> TransactionService<R> for TransactionServ
     fn send_coin(&self) -> Result<()> {
         todo!()
     }
+
+    fn transfer_object(&self, transfer_objects: TransferObjects, repository: R) -> Result<()> {
+        let mut confy = repository.load()?;
+
+        if transfer_objects.object_ids.is_empty() && transfer_objects.filter.is_none() {
+            return Err(Error::NoObjectsToTransfer);
+        }
+
+        let wallets = transfer_objects.selection.select(confy.get_wallets())?;
+        let url = confy.get_rpc_servers().resolve_url(&transfer_objects.rpc)?;
+        let recipient =
+            SuiNsClient::new(&url)?.resolve_address(&confy, &transfer_objects.recipient)?;
+
+        if let AliasOrAddress::SuiNs(name) = &transfer_objects.recipient {
+            eprintln!("{} resolves to {}", name, recipient);
+
+            if !transfer_objects.yes
+                && !Confirm::new()
+                    .with_prompt(format!("Transfer to {}?", recipient))
+                    .default(false)
+                    .interact()?
+            {
+                return Err(Error::Aborted);
+            }
+        }
+
+        let responses = self.transfer_objects(
+            wallets,
+            transfer_objects.object_ids,
+            transfer_objects.filter,
+            recipient,
+            transfer_objects.gas_budget,
+            url,
+        )?;
+
+        confy.mark_used(
+            &responses
+                .iter()
+                .map(|(wallet, _)| *wallet.get_address())
+                .collect::<Vec<_>>(),
+            Timestamp::now(),
+        );
+        repository.store(confy.clone())?;
+
+        let transaction_view = TransactionListView::from_responses(&responses);
+
+        if transfer_objects.json {
+            println!("{}", transaction_view.to_json_string());
+        } else {
+            transaction_view.to_table().printstd();
+        }
+
+        Ok(())
+    }
 }
@@ -2,14 +2,18 @@ use crate::{
     commands::{WalletRepository, WalletService},
     error::{Error, Result},
     models::{
-        alias::Alias, alias_or_address::AliasOrAddress, tag_list::TagList, wallet::Wallet,
-        wallet_confy::WalletConfy,
+        alias::Alias, alias_or_address::AliasOrAddress, alias_or_url::AliasOrUrl,
+        alias_pattern::AliasPattern, metadata_filter::MetadataFilter, tag_filter::TagFilter,
+        tag_list::TagList, timestamp::Timestamp, wallet::Wallet, wallet_confy::WalletConfy,
+        wallet_edit::WalletEdit,
     },
+    services::suins_client::SuiNsClient,
     views::wallet_view::WalletListView,
 };
 use bip39::{Language, Mnemonic, Seed};
 use clap::ValueEnum;
 use dialoguer::Input;
+use std::collections::HashMap;
 use sui_keys::key_derive::{derive_key_pair_from_path, generate_new_key};
 use sui_sdk::types::{base_types::SuiAddress, crypto::SignatureScheme};
 
@@ -23,6 +27,46 @@ pub enum KeyScheme {
     Secp256r1,
 }
 
+#[derive(ValueEnum, Debug, Clone)]
+pub enum WalletSortBy {
+    Alias,
+    Address,
+    Created,
+}
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+pub enum WalletColumn {
+    Address,
+    Alias,
+    Suins,
+    Tags,
+    Scheme,
+    Credentials,
+    Note,
+    Metadata,
+    Created,
+    Imported,
+    LastUsed,
+}
+
+impl WalletColumn {
+    pub fn get_key(&self) -> &'static str {
+        match self {
+            WalletColumn::Address => "address",
+            WalletColumn::Alias => "alias",
+            WalletColumn::Suins => "suins_name",
+            WalletColumn::Tags => "tags",
+            WalletColumn::Scheme => "key_scheme",
+            WalletColumn::Credentials => "credentials",
+            WalletColumn::Note => "note",
+            WalletColumn::Metadata => "metadata",
+            WalletColumn::Created => "created_at",
+            WalletColumn::Imported => "imported_at",
+            WalletColumn::LastUsed => "last_used_at",
+        }
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone)]
 pub enum WordLength {
     Word12,
@@ -49,13 +93,19 @@ pub struct ImportWallet {
 
 pub struct EditWallet {
     pub alias_or_address: AliasOrAddress,
-    pub alias: Option<Alias>,
-    pub tags: Option<TagList>,
+    pub edit: WalletEdit,
 }
 
 pub struct ListWallet {
-    pub alias: Option<Alias>,
-    pub tags: Option<TagList>,
+    pub alias: Option<AliasPattern>,
+    pub tags: Option<TagFilter>,
+    pub metadata: Vec<MetadataFilter>,
+    pub sort: WalletSortBy,
+    pub columns: Vec<WalletColumn>,
+    pub details: bool,
+    pub limit: Option<usize>,
+    pub offset: usize,
+    pub rpc: Option<AliasOrUrl>,
     pub json: bool,
 }
 
@@ -105,7 +155,9 @@ impl<R: WalletRepository<WalletConfy>> WalletService<R> for WalletServiceImpl {
         }
 
         wallet_confy.add_wallet(
-            Wallet::new(address, alias, tags.clone()).with_credentials(key_pair, scheme, phrase)?,
+            Wallet::new(address, alias, tags.clone())
+                .with_created_at(Timestamp::now())
+                .with_credentials(key_pair, scheme, phrase)?,
         )?;
 
         repository.store(wallet_confy.clone())?;
@@ -147,6 +199,7 @@ impl<R: WalletRepository<WalletConfy>> WalletService<R> for WalletServiceImpl {
                     import_wallet.alias,
                     import_wallet.tags.unwrap_or_default(),
                 )
+                .with_imported_at(Timestamp::now())
                 .with_credentials(
                     key_pair,
                     scheme,
@@ -154,11 +207,14 @@ impl<R: WalletRepository<WalletConfy>> WalletService<R> for WalletServiceImpl {
                 )?,
             )?;
         } else if let Some(address) = import_wallet.address {
-            wallet_confy.add_wallet(Wallet::new(
-                address,
-                import_wallet.alias,
-                import_wallet.tags.unwrap_or_default(),
-            ))?;
+            wallet_confy.add_wallet(
+                Wallet::new(
+                    address,
+                    import_wallet.alias,
+                    import_wallet.tags.unwrap_or_default(),
+                )
+                .with_imported_at(Timestamp::now()),
+            )?;
         }
 
         repository.store(wallet_confy.clone())?;
@@ -170,20 +226,8 @@ impl<R: WalletRepository<WalletConfy>> WalletService<R> for WalletServiceImpl {
 
     fn edit(&self, edit_wallet: EditWallet, repository: R) -> Result<()> {
         let mut wallet_confy = repository.load()?;
-        let wallets = wallet_confy.mut_wallets();
 
-        match edit_wallet.alias_or_address {
-            AliasOrAddress::Address(address) => {
-                wallets.edit(address, edit_wallet.alias, edit_wallet.tags)?;
-            }
-            AliasOrAddress::Alias(alias) => {
-                if let Some(address) = wallets.get_address_by_alias(&alias) {
-                    wallets.edit(*address, edit_wallet.alias, edit_wallet.tags)?;
-                } else {
-                    return Err(Error::WalletAliasNotFound(alias));
-                }
-            }
-        };
+        wallet_confy.edit_wallet(&edit_wallet.alias_or_address, &edit_wallet.edit)?;
 
         repository.store(wallet_confy.clone())?;
 
@@ -193,10 +237,98 @@ impl<R: WalletRepository<WalletConfy>> WalletService<R> for WalletServiceImpl {
     }
 
     fn list(&self, list_wallet: ListWallet, repository: R) -> Result<()> {
-        let wallet_view = WalletListView::from_walet_list(
-            repository.load()?.get_wallets(),
-            list_wallet.alias,
-            list_wallet.tags,
+        let confy = repository.load()?;
+        repository.release();
+
+        let mut wallets = confy
+            .get_wallets()
+            .get_wallets()
+            .into_iter()
+            .filter(|wallet| {
+                if let Some(ref pattern) = list_wallet.alias {
+                    if !wallet
+                        .get_alias()
+                        .as_ref()
+                        .is_some_and(|alias| pattern.matches(alias))
+                    {
+                        return false;
+                    }
+                }
+
+                if let Some(ref tags) = list_wallet.tags {
+                    if !tags.matches(wallet.get_tags()) {
+                        return false;
+                    }
+                }
+
+                list_wallet
+                    .metadata
+                    .iter()
+                    .all(|filter| filter.matches(wallet.get_metadata()))
+            })
+            .collect::<Vec<Wallet>>();
+
+        // Stable sorts: address breaks ties, wallets without the key go last.
+        wallets.sort_by(|a, b| a.get_address().cmp(b.get_address()));
+        match list_wallet.sort {
+            WalletSortBy::Address => {}
+            WalletSortBy::Alias => wallets.sort_by(|a, b| {
+                (a.get_alias().is_none(), a.get_alias())
+                    .cmp(&(b.get_alias().is_none(), b.get_alias()))
+            }),
+            WalletSortBy::Created => wallets.sort_by_key(|wallet| {
+                let created = wallet.get_created_at().or(*wallet.get_imported_at());
+                (created.is_none(), created)
+            }),
+        }
+
+        let total = wallets.len();
+        let wallets = wallets
+            .into_iter()
+            .skip(list_wallet.offset)
+            .take(list_wallet.limit.unwrap_or(usize::MAX))
+            .collect::<Vec<Wallet>>();
+
+        let names = match list_wallet.rpc {
+            Some(ref rpc) => {
+                let url = confy.get_rpc_servers().resolve_url(rpc)?;
+                let addresses = wallets
+                    .iter()
+                    .map(|wallet| *wallet.get_address())
+                    .collect::<Vec<_>>();
+                SuiNsClient::new(&url)?.lookup_names(&addresses)?
+            }
+            None => HashMap::new(),
+        };
+
+        let columns = if list_wallet.columns.is_empty() {
+            let mut columns = vec![WalletColumn::Address, WalletColumn::Alias];
+            if list_wallet.rpc.is_some() {
+                columns.push(WalletColumn::Suins);
+            }
+            columns.push(WalletColumn::Tags);
+            if list_wallet.details {
+                columns.extend([
+                    WalletColumn::Scheme,
+                    WalletColumn::Credentials,
+                    WalletColumn::Note,
+                    WalletColumn::Metadata,
+                    WalletColumn::Created,
+                    WalletColumn::Imported,
+                    WalletColumn::LastUsed,
+                ]);
+            }
+            columns
+        } else {
+            list_wallet.columns
+        };
+
+        let wallet_view = WalletListView::from_wallets(
+            &wallets,
+            &names,
+            columns.iter().map(|column| column.get_key()).collect(),
+            total,
+            list_wallet.offset,
         );
 
         if list_wallet.json {
@@ -210,25 +342,12 @@ impl<R: WalletRepository<WalletConfy>> WalletService<R> for WalletServiceImpl {
 
     fn export(&self, export_wallet: ExportWallet, repository: R) -> Result<()> {
         let confy = repository.load()?;
+        repository.release();
 
-        let wallet = match export_wallet.alias_or_address {
-            AliasOrAddress::Address(address) => confy
-                .get_wallets()
-                .get_by_key(&address)
-                .ok_or(Error::WalletAddressNotFound(address))?,
-            AliasOrAddress::Alias(alias) => {
-                let address = confy
-                    .get_wallets()
-                    .get_address_by_alias(&alias)
-                    .ok_or(Error::WalletAliasNotFound(alias))?;
-
-                confy
-                    .get_wallets()
-                    .get_by_key(address)
-                    .ok_or(Error::WalletAddressNotFound(*address))?
-            }
-        }
-        .clone();
+        let wallet = confy
+            .get_wallets()
+            .get_by_alias_or_address(&export_wallet.alias_or_address)?
+            .clone();
 
         if let Some(phrase) = wallet.get_phrase() {
             println!("{}", phrase);
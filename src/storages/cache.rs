@@ -0,0 +1,32 @@
+use super::{confy::APP_NAME, file::write_atomic};
+use crate::error::Result;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fs, marker::PhantomData, path::PathBuf};
+
+/// Data fetched from the network, kept as plain YAML next to the vault. It
+/// isn't signed, locked or backed up, and an unreadable file is simply
+/// treated as empty.
+pub struct CacheClient<C> {
+    path: PathBuf,
+    phantom: PhantomData<C>,
+}
+
+impl<C: Default + DeserializeOwned + Serialize> CacheClient<C> {
+    pub fn new(name: &str) -> Result<Self> {
+        Ok(Self {
+            path: ::confy::get_configuration_file_path(APP_NAME, Some(name))?,
+            phantom: PhantomData,
+        })
+    }
+
+    pub fn load(&self) -> C {
+        fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| serde_yaml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn store(&self, cache: &C) -> Result<()> {
+        write_atomic(&self.path, serde_yaml::to_string(cache)?.as_bytes())
+    }
+}
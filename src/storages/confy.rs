@@ -1,30 +1,102 @@
-use crate::{commands::WalletRepository, error::Result};
+use super::{
+    decode,
+    file::{remove_backups, rotate_backups, write_atomic, FileLock},
+    sign_and_write, Versioned,
+};
+use crate::{
+    commands::WalletRepository,
+    error::Result,
+    models::{cipher::Cipher, profile::Profile},
+};
 use serde::{de::DeserializeOwned, Serialize};
-use std::marker::PhantomData;
+use serde_json::Value;
+use std::{
+    fs,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
 
-const APP_NAME: &str = "sui_wallet_cli";
-const CONFIG_FILE: Option<&str> = Some("wallets");
+pub const APP_NAME: &str = "sui_wallet_cli";
+const BACKUP_COUNT: usize = 5;
 
-pub struct ConfyClient<C>(PhantomData<C>);
+pub struct ConfyClient<C> {
+    path: PathBuf,
+    profile: Profile,
+    accept_changes: bool,
+    lock: FileLock,
+    phantom: PhantomData<C>,
+}
 
 impl<C> ConfyClient<C> {
-    pub fn new() -> Self {
-        Self(PhantomData)
+    pub fn new(path: PathBuf, profile: Profile, accept_changes: bool) -> Self {
+        Self {
+            path,
+            profile,
+            accept_changes,
+            lock: FileLock::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
     }
-}
 
-impl<C> Default for ConfyClient<C> {
-    fn default() -> Self {
-        Self::new()
+    pub fn get_default_path(profile: &Profile) -> Result<PathBuf> {
+        Ok(confy::get_configuration_file_path(
+            APP_NAME,
+            Some(profile.get_config_name().as_str()),
+        )?)
+    }
+
+    fn write(&self, value: &Value) -> Result<()> {
+        let contents = serde_yaml::to_string(value)?;
+        rotate_backups(&self.path, BACKUP_COUNT)?;
+        write_atomic(&self.path, contents.as_bytes())
     }
 }
 
-impl<C: Default + DeserializeOwned + Serialize> WalletRepository<C> for ConfyClient<C> {
+impl<C: Default + DeserializeOwned + Serialize + Versioned> WalletRepository<C> for ConfyClient<C> {
     fn load(&self) -> Result<C> {
-        Ok(confy::load(APP_NAME, CONFIG_FILE)?)
+        self.lock.acquire(&self.path)?;
+
+        if !self.path.exists() {
+            return Ok(C::default());
+        }
+
+        let value: Value = serde_yaml::from_str(&fs::read_to_string(&self.path)?)?;
+        decode(
+            value,
+            &self.path,
+            &Cipher::load_for_profile(&self.profile)?,
+            self.accept_changes,
+            |value| self.write(value),
+        )
     }
 
     fn store(&self, confy: C) -> Result<()> {
-        Ok(confy::store(APP_NAME, CONFIG_FILE, confy)?)
+        self.lock.acquire(&self.path)?;
+
+        let mut value = serde_json::to_value(confy)?;
+        let cipher = Cipher::load_for_profile(&self.profile)?;
+        sign_and_write(&mut value, &self.path, &cipher, |value| self.write(value))?;
+        self.lock.release();
+        Ok(())
+    }
+
+    fn store_with_cipher(&self, confy: C, cipher: &Cipher) -> Result<()> {
+        self.lock.acquire(&self.path)?;
+
+        let mut value = serde_json::to_value(confy)?;
+        sign_and_write(&mut value, &self.path, cipher, |value| {
+            write_atomic(&self.path, serde_yaml::to_string(value)?.as_bytes())
+        })?;
+        remove_backups(&self.path)?;
+        self.lock.release();
+        Ok(())
+    }
+
+    fn release(&self) {
+        self.lock.release();
     }
 }
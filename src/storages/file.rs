@@ -0,0 +1,154 @@
+use crate::error::Result;
+use fs2::FileExt;
+use std::{
+    cell::RefCell,
+    ffi::OsString,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Exclusive lock on `<path>.lock`, taken on first use and held until
+/// released, so a command's whole load → mutate → store runs without
+/// interleaving with another process.
+#[derive(Default)]
+pub struct FileLock(RefCell<Option<File>>);
+
+impl FileLock {
+    pub fn new() -> Self {
+        Self(RefCell::new(None))
+    }
+
+    pub fn acquire(&self, path: &Path) -> Result<()> {
+        if self.0.borrow().is_some() {
+            return Ok(());
+        }
+
+        create_parent_dir(path)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(with_suffix(path, ".lock"))?;
+        file.lock_exclusive()?;
+
+        self.0.replace(Some(file));
+        Ok(())
+    }
+
+    /// Closing the lock file drops the lock.
+    pub fn release(&self) {
+        self.0.take();
+    }
+}
+
+pub fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+pub fn create_parent_dir(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    Ok(())
+}
+
+/// Files kept next to `path`, named `<path>.<suffix>`: its lock, backups and
+/// copies taken before schema upgrades.
+pub fn find_siblings(path: &Path) -> Result<Vec<PathBuf>> {
+    let Some(name) = path.file_name() else {
+        return Ok(Vec::new());
+    };
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if !parent.exists() {
+        return Ok(Vec::new());
+    }
+
+    let prefix = format!("{}.", name.to_string_lossy());
+    let mut siblings = Vec::new();
+    for entry in fs::read_dir(parent)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() && entry.file_name().to_string_lossy().starts_with(&prefix)
+        {
+            siblings.push(entry.path());
+        }
+    }
+    siblings.sort();
+
+    Ok(siblings)
+}
+
+/// Keeps the last `count` versions of `path` as `<path>.bak.1` (newest) to
+/// `<path>.bak.<count>`.
+pub fn rotate_backups(path: &Path, count: usize) -> Result<()> {
+    if count == 0 || !path.exists() {
+        return Ok(());
+    }
+
+    for index in (1..count).rev() {
+        let backup = with_suffix(path, &format!(".bak.{}", index));
+        if backup.exists() {
+            fs::rename(&backup, with_suffix(path, &format!(".bak.{}", index + 1)))?;
+        }
+    }
+    fs::copy(path, with_suffix(path, ".bak.1"))?;
+
+    Ok(())
+}
+
+/// Removes the `<path>.bak.<n>` and `<path>.v<version>.bak` copies of
+/// `path`, e.g. once they're encrypted with a key that's been replaced.
+pub fn remove_backups(path: &Path) -> Result<()> {
+    let prefix = format!(
+        "{}.",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    );
+
+    for sibling in find_siblings(path)? {
+        let name = sibling.file_name().unwrap_or_default().to_string_lossy();
+        let Some(suffix) = name.strip_prefix(&prefix) else {
+            continue;
+        };
+        let is_backup = suffix
+            .strip_prefix("bak.")
+            .or_else(|| {
+                suffix
+                    .strip_prefix('v')
+                    .and_then(|rest| rest.strip_suffix(".bak"))
+            })
+            .is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()));
+
+        if is_backup {
+            fs::remove_file(&sibling)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes to `<path>.tmp`, fsyncs it and renames it over `path`, so readers
+/// only ever see the old or the new contents.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    create_parent_dir(path)?;
+    let temp = with_suffix(path, ".tmp");
+
+    let mut file = File::create(&temp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(&temp, path)?;
+
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        File::open(parent)?.sync_all()?;
+    }
+
+    Ok(())
+}
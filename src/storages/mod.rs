@@ -1 +1,275 @@
+use self::confy::ConfyClient;
+use self::file::{with_suffix, write_atomic};
+use self::sqlite::SqliteClient;
+use crate::{
+    commands::WalletRepository,
+    error::{Error, Result},
+    models::{
+        cipher::Cipher, profile::Profile, schema_migration, vault_mac, wallet_confy::WalletConfy,
+    },
+};
+use clap::ValueEnum;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+pub mod cache;
 pub mod confy;
+pub mod file;
+pub mod sqlite;
+
+pub const CONFIG_ENV: &str = "WALLET_CONFIG";
+const GENERATION_SUFFIX: &str = ".generation";
+
+/// Config types stored with a schema version and upgraded on load.
+pub trait Versioned {
+    /// Upgrades the stored layout in place, returning the version it was
+    /// stored with if it changed.
+    fn migrate(value: &mut Value) -> Result<Option<u32>>;
+}
+
+impl Versioned for WalletConfy {
+    fn migrate(value: &mut Value) -> Result<Option<u32>> {
+        schema_migration::migrate(value)
+    }
+}
+
+/// Last generation seen for the store at `path`, kept next to it in
+/// `<path>.generation` where backup rotation doesn't touch it.
+fn read_generation(path: &Path) -> Result<u64> {
+    let generation_path = with_suffix(path, GENERATION_SUFFIX);
+    if !generation_path.exists() {
+        return Ok(0);
+    }
+
+    fs::read_to_string(&generation_path)?
+        .trim()
+        .parse()
+        .map_err(|_| Error::StorageError(format!("invalid {}", generation_path.display())))
+}
+
+fn write_generation(path: &Path, generation: u64) -> Result<()> {
+    write_atomic(
+        &with_suffix(path, GENERATION_SUFFIX),
+        generation.to_string().as_bytes(),
+    )
+}
+
+/// Refuses a store older than the last one seen, e.g. a backup copied over
+/// it, and otherwise remembers its generation.
+fn check_generation(path: &Path, generation: u64) -> Result<()> {
+    let last_seen = read_generation(path)?;
+    if generation < last_seen {
+        return Err(Error::VaultRolledBack(generation, last_seen));
+    }
+    if generation > last_seen {
+        write_generation(path, generation)?;
+    }
+
+    Ok(())
+}
+
+/// Signs `value` as the next generation of the store at `path` and persists
+/// it with `write`.
+fn sign_and_write(
+    value: &mut Value,
+    path: &Path,
+    cipher: &Cipher,
+    write: impl FnOnce(&Value) -> Result<()>,
+) -> Result<()> {
+    let generation = read_generation(path)? + 1;
+    vault_mac::sign(value, cipher, generation)?;
+    write(value)?;
+    write_generation(path, generation)
+}
+
+/// Checks the MAC of a config read from `path` and upgrades its schema.
+///
+/// A config that fails the check, or is older than the last one seen, is
+/// only trusted with `accept_changes`, in which case it's re-signed as is. Re-signed or upgraded configs are
+/// persisted with `write`.
+fn decode<C: DeserializeOwned + Versioned>(
+    mut value: Value,
+    path: &Path,
+    cipher: &Cipher,
+    accept_changes: bool,
+    write: impl Fn(&Value) -> Result<()>,
+) -> Result<C> {
+    let verified =
+        vault_mac::verify(&value, cipher).and_then(|generation| check_generation(path, generation));
+    let accepted = match verified {
+        Ok(()) => false,
+        Err(Error::VaultTampered | Error::VaultNotSigned | Error::VaultRolledBack(..))
+            if accept_changes =>
+        {
+            eprintln!(
+                "Accepting changes made to {} outside sui_wallet",
+                path.display()
+            );
+            true
+        }
+        Err(e) => return Err(e),
+    };
+
+    let version = C::migrate(&mut value)?;
+    if let Some(version) = version {
+        // Keep the store as it was before upgrading it in place
+        fs::copy(path, with_suffix(path, &format!(".v{}.bak", version)))?;
+    }
+
+    if accepted || version.is_some() {
+        sign_and_write(&mut value, path, cipher, write)?;
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WalletStorage {
+    #[default]
+    Yaml,
+    Sqlite,
+}
+
+impl fmt::Display for WalletStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalletStorage::Yaml => write!(f, "yaml"),
+            WalletStorage::Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct StorageConfig {
+    #[serde(default)]
+    wallet_storage: WalletStorage,
+    wallet_database: Option<PathBuf>,
+    wallet_config: Option<PathBuf>,
+    #[serde(skip)]
+    profile: Profile,
+    #[serde(skip)]
+    accept_changes: bool,
+}
+
+impl StorageConfig {
+    pub fn load_from_env() -> Result<Self> {
+        Ok(Self {
+            profile: Profile::from_env()?,
+            ..envy::from_env::<StorageConfig>()?
+        })
+    }
+
+    /// The same backend for another profile, at its default location.
+    pub fn for_profile(&self, profile: &Profile) -> Self {
+        Self {
+            wallet_storage: self.wallet_storage,
+            wallet_database: None,
+            wallet_config: None,
+            profile: profile.clone(),
+            accept_changes: false,
+        }
+    }
+
+    /// Trusts and re-signs a store that was modified outside sui_wallet.
+    /// Only ever set from the command line, never from the environment.
+    pub fn with_accept_changes(mut self, accept_changes: bool) -> Self {
+        self.accept_changes = accept_changes;
+        self
+    }
+
+    pub fn get_storage(&self) -> WalletStorage {
+        self.wallet_storage
+    }
+
+    pub fn get_profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    /// `WALLET_CONFIG` points at the vault file of the configured backend;
+    /// `WALLET_DATABASE` only at the SQLite one.
+    pub fn open<C>(&self, storage: WalletStorage) -> Result<StorageClient<C>> {
+        let config = self
+            .wallet_config
+            .clone()
+            .filter(|_| storage == self.wallet_storage);
+
+        Ok(match storage {
+            WalletStorage::Yaml => StorageClient::Yaml(ConfyClient::new(
+                match config {
+                    Some(path) => path,
+                    None => ConfyClient::<C>::get_default_path(&self.profile)?,
+                },
+                self.profile.clone(),
+                self.accept_changes,
+            )),
+            WalletStorage::Sqlite => {
+                let path = match config.or(self.wallet_database.clone()) {
+                    Some(path) => path,
+                    None => {
+                        ConfyClient::<C>::get_default_path(&self.profile)?.with_extension("sqlite")
+                    }
+                };
+                StorageClient::Sqlite(SqliteClient::new(
+                    path,
+                    self.profile.clone(),
+                    self.accept_changes,
+                ))
+            }
+        })
+    }
+}
+
+pub enum StorageClient<C> {
+    Yaml(ConfyClient<C>),
+    Sqlite(SqliteClient<C>),
+}
+
+impl<C> StorageClient<C> {
+    pub fn get_path(&self) -> PathBuf {
+        match self {
+            StorageClient::Yaml(client) => client.get_path().to_path_buf(),
+            StorageClient::Sqlite(client) => client.get_path().to_path_buf(),
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.get_path().exists()
+    }
+}
+
+impl<C: Default + DeserializeOwned + Serialize + Versioned> WalletRepository<C>
+    for StorageClient<C>
+{
+    fn load(&self) -> Result<C> {
+        match self {
+            StorageClient::Yaml(client) => client.load(),
+            StorageClient::Sqlite(client) => client.load(),
+        }
+    }
+
+    fn store(&self, confy: C) -> Result<()> {
+        match self {
+            StorageClient::Yaml(client) => client.store(confy),
+            StorageClient::Sqlite(client) => client.store(confy),
+        }
+    }
+
+    fn store_with_cipher(&self, confy: C, cipher: &Cipher) -> Result<()> {
+        match self {
+            StorageClient::Yaml(client) => client.store_with_cipher(confy, cipher),
+            StorageClient::Sqlite(client) => client.store_with_cipher(confy, cipher),
+        }
+    }
+
+    fn release(&self) {
+        match self {
+            StorageClient::Yaml(client) => client.release(),
+            StorageClient::Sqlite(client) => client.release(),
+        }
+    }
+}
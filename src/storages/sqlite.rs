@@ -0,0 +1,320 @@
+use super::{
+    decode,
+    file::{create_parent_dir, remove_backups, FileLock},
+    sign_and_write, Versioned,
+};
+use crate::{
+    commands::WalletRepository,
+    error::{Error, Result},
+    models::{cipher::Cipher, profile::Profile},
+};
+use rusqlite::{params, Connection, Transaction};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+use std::{
+    collections::HashSet,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS wallets (
+    address TEXT PRIMARY KEY,
+    alias TEXT,
+    credentials TEXT,
+    wallet TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS wallets_alias ON wallets (alias);
+CREATE TABLE IF NOT EXISTS contacts (
+    alias TEXT PRIMARY KEY,
+    contact TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS tags (
+    name TEXT PRIMARY KEY
+);
+CREATE TABLE IF NOT EXISTS settings (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+";
+
+const WALLETS: &str = "wallets";
+const WALLET_ALIASSES: &str = "aliasses";
+const WALLET_ALIAS: &str = "alias";
+const WALLET_CREDENTIALS: &str = "credentials";
+const CONTACTS: &str = "contacts";
+const TAGS: &str = "tags";
+
+/// Stores wallets, contacts and tags as one row each, and every other
+/// top-level config field as a JSON value in `settings`.
+///
+/// Credentials are kept in their own column exactly as serialized, so the
+/// private key and mnemonic stay encrypted by `Cipher`.
+pub struct SqliteClient<C> {
+    path: PathBuf,
+    profile: Profile,
+    accept_changes: bool,
+    lock: FileLock,
+    phantom: PhantomData<C>,
+}
+
+impl<C> SqliteClient<C> {
+    pub fn new(path: PathBuf, profile: Profile, accept_changes: bool) -> Self {
+        Self {
+            path,
+            profile,
+            accept_changes,
+            lock: FileLock::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    fn open(&self) -> Result<Connection> {
+        self.lock.acquire(&self.path)?;
+        create_parent_dir(&self.path)?;
+
+        let connection = Connection::open(&self.path)?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(connection)
+    }
+
+    fn read(&self) -> Result<Value> {
+        let connection = self.open()?;
+        let mut confy = Map::new();
+
+        let mut statement = connection.prepare("SELECT key, value FROM settings")?;
+        let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get::<_, String>(1)?)))?;
+        for row in rows {
+            let (key, value) = row?;
+            confy.insert(key, serde_json::from_str(&value)?);
+        }
+
+        let mut wallets = Map::new();
+        let mut aliasses = Map::new();
+        let mut statement =
+            connection.prepare("SELECT address, alias, credentials, wallet FROM wallets")?;
+        let rows = statement.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+        for row in rows {
+            let (address, alias, credentials, wallet) = row?;
+            let mut wallet = into_object(serde_json::from_str(&wallet)?, WALLETS)?;
+            let credentials = match credentials {
+                Some(credentials) => serde_json::from_str(&credentials)?,
+                None => Value::Null,
+            };
+            wallet.insert(WALLET_CREDENTIALS.to_string(), credentials);
+
+            if let Some(alias) = alias {
+                aliasses.insert(alias, Value::String(address.clone()));
+            }
+            wallets.insert(address, Value::Object(wallet));
+        }
+        confy.insert(
+            WALLETS.to_string(),
+            json!({ WALLETS: wallets, WALLET_ALIASSES: aliasses }),
+        );
+
+        let mut contacts = Map::new();
+        let mut statement = connection.prepare("SELECT alias, contact FROM contacts")?;
+        let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get::<_, String>(1)?)))?;
+        for row in rows {
+            let (alias, contact) = row?;
+            contacts.insert(alias, serde_json::from_str(&contact)?);
+        }
+        confy.insert(CONTACTS.to_string(), Value::Object(contacts));
+
+        let mut statement = connection.prepare("SELECT name FROM tags ORDER BY name")?;
+        let tags = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .map(|name| name.map(Value::String))
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        confy.insert(TAGS.to_string(), Value::Array(tags));
+
+        Ok(Value::Object(confy))
+    }
+
+    fn write(&self, value: Value) -> Result<()> {
+        let mut confy = into_object(value, "config")?;
+        let mut connection = self.open()?;
+        let transaction = connection.transaction()?;
+
+        let mut wallet_list = take_object(&mut confy, WALLETS)?;
+        let wallets = take_object(&mut wallet_list, WALLETS)?;
+        let mut addresses = HashSet::new();
+        {
+            let mut statement = transaction.prepare(
+                "INSERT INTO wallets (address, alias, credentials, wallet) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (address) DO UPDATE SET
+                     alias = excluded.alias,
+                     credentials = excluded.credentials,
+                     wallet = excluded.wallet
+                 WHERE alias IS NOT excluded.alias
+                     OR credentials IS NOT excluded.credentials
+                     OR wallet IS NOT excluded.wallet",
+            )?;
+            for (address, wallet) in wallets {
+                let mut wallet = into_object(wallet, WALLETS)?;
+                let credentials = match wallet.remove(WALLET_CREDENTIALS) {
+                    Some(Value::Null) | None => None,
+                    Some(credentials) => Some(serde_json::to_string(&credentials)?),
+                };
+                let alias = wallet
+                    .get(WALLET_ALIAS)
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+
+                statement.execute(params![
+                    address,
+                    alias,
+                    credentials,
+                    serde_json::to_string(&wallet)?
+                ])?;
+                addresses.insert(address);
+            }
+        }
+        delete_missing(&transaction, WALLETS, "address", &addresses)?;
+
+        let contacts = take_object(&mut confy, CONTACTS)?;
+        let mut aliasses = HashSet::new();
+        {
+            let mut statement = transaction.prepare(
+                "INSERT INTO contacts (alias, contact) VALUES (?1, ?2)
+                 ON CONFLICT (alias) DO UPDATE SET contact = excluded.contact
+                 WHERE contact IS NOT excluded.contact",
+            )?;
+            for (alias, contact) in contacts {
+                statement.execute(params![alias, serde_json::to_string(&contact)?])?;
+                aliasses.insert(alias);
+            }
+        }
+        delete_missing(&transaction, CONTACTS, "alias", &aliasses)?;
+
+        let tags = match confy.remove(TAGS) {
+            Some(Value::Array(tags)) => tags,
+            None => Vec::new(),
+            Some(_) => return Err(Error::StorageError(format!("unexpected {} layout", TAGS))),
+        };
+        let mut names = HashSet::new();
+        {
+            let mut statement =
+                transaction.prepare("INSERT OR IGNORE INTO tags (name) VALUES (?1)")?;
+            for tag in tags {
+                let name = tag
+                    .as_str()
+                    .ok_or_else(|| Error::StorageError(format!("unexpected tag {}", tag)))?
+                    .to_string();
+                statement.execute(params![name])?;
+                names.insert(name);
+            }
+        }
+        delete_missing(&transaction, TAGS, "name", &names)?;
+
+        let mut keys = HashSet::new();
+        {
+            let mut statement = transaction.prepare(
+                "INSERT INTO settings (key, value) VALUES (?1, ?2)
+                 ON CONFLICT (key) DO UPDATE SET value = excluded.value
+                 WHERE value IS NOT excluded.value",
+            )?;
+            for (key, value) in confy {
+                statement.execute(params![key, serde_json::to_string(&value)?])?;
+                keys.insert(key);
+            }
+        }
+        delete_missing(&transaction, "settings", "key", &keys)?;
+
+        Ok(transaction.commit()?)
+    }
+}
+
+impl<C: Default + DeserializeOwned + Serialize + Versioned> WalletRepository<C>
+    for SqliteClient<C>
+{
+    fn load(&self) -> Result<C> {
+        self.lock.acquire(&self.path)?;
+
+        if !self.path.exists() {
+            return Ok(C::default());
+        }
+
+        decode(
+            self.read()?,
+            &self.path,
+            &Cipher::load_for_profile(&self.profile)?,
+            self.accept_changes,
+            |value| self.write(value.clone()),
+        )
+    }
+
+    fn store(&self, confy: C) -> Result<()> {
+        self.lock.acquire(&self.path)?;
+
+        let mut value = serde_json::to_value(confy)?;
+        let cipher = Cipher::load_for_profile(&self.profile)?;
+        sign_and_write(&mut value, &self.path, &cipher, |value| {
+            self.write(value.clone())
+        })?;
+        self.lock.release();
+        Ok(())
+    }
+
+    fn store_with_cipher(&self, confy: C, cipher: &Cipher) -> Result<()> {
+        self.lock.acquire(&self.path)?;
+
+        let mut value = serde_json::to_value(confy)?;
+        sign_and_write(&mut value, &self.path, cipher, |value| {
+            self.write(value.clone())
+        })?;
+        remove_backups(&self.path)?;
+        self.lock.release();
+        Ok(())
+    }
+
+    fn release(&self) {
+        self.lock.release();
+    }
+}
+
+fn into_object(value: Value, name: &str) -> Result<Map<String, Value>> {
+    match value {
+        Value::Object(object) => Ok(object),
+        _ => Err(Error::StorageError(format!("unexpected {} layout", name))),
+    }
+}
+
+fn take_object(object: &mut Map<String, Value>, key: &str) -> Result<Map<String, Value>> {
+    match object.remove(key) {
+        Some(value) => into_object(value, key),
+        None => Ok(Map::new()),
+    }
+}
+
+fn delete_missing(
+    transaction: &Transaction,
+    table: &str,
+    column: &str,
+    keep: &HashSet<String>,
+) -> Result<()> {
+    let existing = transaction
+        .prepare(&format!("SELECT {} FROM {}", column, table))?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut statement =
+        transaction.prepare(&format!("DELETE FROM {} WHERE {} = ?1", table, column))?;
+    for key in existing.iter().filter(|key| !keep.contains(*key)) {
+        statement.execute(params![key])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::models::{merge_conflict::MergeConflict, vault_backup::VaultBackup};
+use prettytable::{cell, row, Table};
+use serde_json::json;
+
+pub struct BackupSummaryView {
+    created_at: String,
+    wallets: usize,
+    credentials: usize,
+    tags: usize,
+    contacts: usize,
+    rpc_servers: usize,
+}
+
+impl BackupSummaryView {
+    pub fn from_backup(backup: &VaultBackup) -> Self {
+        let confy = backup.get_confy();
+
+        Self {
+            created_at: backup.get_created_at().to_string(),
+            wallets: confy.get_wallets().get_wallets().len(),
+            credentials: backup.get_secret_count(),
+            tags: confy.get_tags().iter().count(),
+            contacts: confy.get_contacts().get_contacts().len(),
+            rpc_servers: confy.get_rpc_servers().get_rpc_servers().len(),
+        }
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Created", self.created_at]);
+        table.add_row(row!["Wallets", self.wallets]);
+        table.add_row(row!["Credentials", self.credentials]);
+        table.add_row(row!["Tags", self.tags]);
+        table.add_row(row!["Contacts", self.contacts]);
+        table.add_row(row!["RPC Servers", self.rpc_servers]);
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json!({
+            "created_at": self.created_at,
+            "wallets": self.wallets,
+            "credentials": self.credentials,
+            "tags": self.tags,
+            "contacts": self.contacts,
+            "rpc_servers": self.rpc_servers,
+        });
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+pub struct MergeConflictListView(Vec<MergeConflict>);
+
+impl MergeConflictListView {
+    pub fn from_conflicts(conflicts: &[MergeConflict]) -> Self {
+        Self(conflicts.to_vec())
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Kind", "Item", "Conflict"]);
+        for conflict in self.0.iter() {
+            table.add_row(row![
+                cell!(conflict.get_kind()),
+                cell!(conflict.get_item()),
+                cell!(conflict.get_reason()),
+            ]);
+        }
+        table
+    }
+}
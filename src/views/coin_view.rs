@@ -1,7 +1,16 @@
-use prettytable::{cell, row, Table};
+use prettytable::{cell, row, Cell, Row, Table};
 use serde_json::json;
+use std::collections::HashMap;
+use sui_sdk::types::base_types::SuiAddress;
 
-use crate::models::coin_object_list::CoinObjectList;
+use crate::models::{
+    coin_object_list::CoinObjectList, suins_name::SuiNsName,
+    wallet_coin_object_list::WalletCoinObjectList,
+};
+
+fn format_amount(balance: u64, decimals: u8) -> String {
+    format!("{}", balance as f64 / (10_f64.powi(decimals as i32)))
+}
 
 pub struct ObjectView {
     object_id: String,
@@ -22,43 +31,26 @@ pub struct CoinView {
 pub struct CoinListView(Vec<CoinView>);
 
 impl CoinListView {
-    pub fn from_coin_object_list(coins: CoinObjectList) -> Self {
+    pub fn from_coin_object_list(coins: CoinObjectList, include_zero: bool) -> Self {
         Self(
             coins
                 .iter()
-                .filter(|(_, coin)| {
-                    0 < coin
-                        .get_objects()
-                        .iter()
-                        .map(|object| object.balance)
-                        .sum::<u64>()
-                })
+                .filter(|(_, coin)| include_zero || 0 < coin.get_total_balance())
                 .map(|(coin_type, coin)| {
-                    let total_balance = coin
-                        .get_objects()
-                        .iter()
-                        .map(|object| object.balance)
-                        .sum::<u64>();
+                    let total_balance = coin.get_total_balance();
                     let metadata = coin.get_metadata().clone();
                     CoinView {
                         name: metadata.name,
                         kind: coin_type.to_string(),
                         symbol: metadata.symbol,
                         total_balance: total_balance.to_string(),
-                        amount: format!(
-                            "{}",
-                            total_balance as f64
-                                / (10_f64.powi(coin.get_metadata().decimals as i32))
-                        ),
+                        amount: format_amount(total_balance, metadata.decimals),
                         objects: coin
                             .get_objects()
                             .iter()
                             .map(|object| ObjectView {
                                 object_id: object.coin_object_id.to_string(),
-                                amount: format!(
-                                    "{}",
-                                    object.balance as f64 / (10_f64.powi(metadata.decimals as i32))
-                                ),
+                                amount: format_amount(object.balance, metadata.decimals),
                                 balance: object.balance.to_string(),
                             })
                             .collect(),
@@ -106,3 +98,181 @@ impl CoinListView {
         serde_json::to_string(&json).unwrap()
     }
 }
+
+pub struct CoinColumnView {
+    name: String,
+    kind: String,
+    symbol: String,
+    decimals: u8,
+}
+
+pub struct BalanceView {
+    amount: String,
+    balance: String,
+}
+
+impl BalanceView {
+    fn new(balance: u64, decimals: u8) -> Self {
+        Self {
+            amount: format_amount(balance, decimals),
+            balance: balance.to_string(),
+        }
+    }
+}
+
+pub struct WalletBalanceView {
+    address: String,
+    alias: String,
+    suins_name: Option<String>,
+    balances: Vec<BalanceView>,
+}
+
+pub struct WalletCoinListView {
+    coins: Vec<CoinColumnView>,
+    wallets: Vec<WalletBalanceView>,
+    totals: Vec<BalanceView>,
+    show_suins: bool,
+}
+
+impl WalletCoinListView {
+    pub fn from_wallet_coin_object_list(
+        wallets: WalletCoinObjectList,
+        names: &HashMap<SuiAddress, SuiNsName>,
+        include_zero: bool,
+    ) -> Self {
+        let merged = wallets.merge();
+
+        let mut coins = merged
+            .iter()
+            .filter(|(_, coin)| include_zero || 0 < coin.get_total_balance())
+            .map(|(coin_type, coin)| {
+                let metadata = coin.get_metadata();
+                CoinColumnView {
+                    name: metadata.name.clone(),
+                    kind: coin_type.to_string(),
+                    symbol: metadata.symbol.clone(),
+                    decimals: metadata.decimals,
+                }
+            })
+            .collect::<Vec<CoinColumnView>>();
+        coins.sort_by(|a, b| a.symbol.cmp(&b.symbol).then(a.kind.cmp(&b.kind)));
+
+        let mut wallet_views = wallets
+            .iter()
+            .map(|(wallet, wallet_coins)| {
+                let balances = coins
+                    .iter()
+                    .map(|coin| {
+                        wallet_coins
+                            .get(&coin.kind)
+                            .map_or(0, |objects| objects.get_total_balance())
+                    })
+                    .collect::<Vec<u64>>();
+                (wallet, balances)
+            })
+            .filter(|(_, balances)| include_zero || balances.iter().any(|balance| 0 < *balance))
+            .map(|(wallet, balances)| WalletBalanceView {
+                address: wallet.get_address().to_string(),
+                alias: wallet.get_alias().clone().unwrap_or_default().to_string(),
+                suins_name: names.get(wallet.get_address()).map(|name| name.to_string()),
+                balances: balances
+                    .into_iter()
+                    .zip(coins.iter())
+                    .map(|(balance, coin)| BalanceView::new(balance, coin.decimals))
+                    .collect(),
+            })
+            .collect::<Vec<WalletBalanceView>>();
+        wallet_views.sort_by(|a, b| a.alias.cmp(&b.alias).then(a.address.cmp(&b.address)));
+
+        let totals = coins
+            .iter()
+            .map(|coin| {
+                BalanceView::new(
+                    merged
+                        .get(&coin.kind)
+                        .map_or(0, |objects| objects.get_total_balance()),
+                    coin.decimals,
+                )
+            })
+            .collect();
+
+        Self {
+            coins,
+            wallets: wallet_views,
+            totals,
+            show_suins: !names.is_empty(),
+        }
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+
+        let mut header = vec![cell!("Address"), cell!("Alias")];
+        if self.show_suins {
+            header.push(cell!("SuiNS"));
+        }
+        header.extend(self.coins.iter().map(|coin| cell!(coin.symbol)));
+        table.add_row(Row::new(header));
+
+        for wallet in self.wallets.iter() {
+            let mut cells = vec![cell!(wallet.address), cell!(wallet.alias)];
+            if self.show_suins {
+                cells.push(cell!(wallet.suins_name.clone().unwrap_or_default()));
+            }
+            cells.extend(wallet.balances.iter().map(|balance| cell!(balance.amount)));
+            table.add_row(Row::new(cells));
+        }
+
+        let mut total = vec![cell!("Total"), Cell::new("")];
+        if self.show_suins {
+            total.push(Cell::new(""));
+        }
+        total.extend(
+            self.totals
+                .iter()
+                .zip(self.coins.iter())
+                .map(|(balance, coin)| cell!(format!("{} {}", balance.amount, coin.symbol))),
+        );
+        table.add_row(Row::new(total));
+
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let balances_json = |balances: &Vec<BalanceView>| {
+            balances
+                .iter()
+                .zip(self.coins.iter())
+                .map(|(balance, coin)| {
+                    json!({
+                        "kind": coin.kind,
+                        "symbol": coin.symbol,
+                        "amount": balance.amount,
+                        "balance": balance.balance,
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let json = json![{
+            "coins": self.coins.iter().map(|coin| {
+                json!({
+                    "name": coin.name,
+                    "kind": coin.kind,
+                    "symbol": coin.symbol,
+                    "decimals": coin.decimals,
+                })
+            }).collect::<Vec<_>>(),
+            "wallets": self.wallets.iter().map(|wallet| {
+                json!({
+                    "address": wallet.address,
+                    "alias": wallet.alias,
+                    "suins_name": wallet.suins_name,
+                    "balances": balances_json(&wallet.balances),
+                })
+            }).collect::<Vec<_>>(),
+            "totals": balances_json(&self.totals),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::models::{contact::Contact, contact_list::ContactList, tag_filter::TagFilter};
+use prettytable::{cell, row, Table};
+use serde_json::json;
+
+pub struct ContactView {
+    alias: String,
+    address: String,
+    tags: String,
+    note: String,
+}
+
+impl ContactView {
+    pub fn from_contact(contact: &Contact) -> Self {
+        Self {
+            alias: format!("@{}", contact.get_alias()),
+            address: contact.get_address().to_string(),
+            tags: contact.get_tags().join(", "),
+            note: contact.get_note().clone().unwrap_or_default(),
+        }
+    }
+}
+
+pub struct ContactListView(Vec<ContactView>);
+
+impl ContactListView {
+    pub fn from_contact_list(contacts: &ContactList, tags: Option<TagFilter>) -> Self {
+        let mut contacts = contacts
+            .get_contacts()
+            .iter()
+            .filter(|contact| {
+                tags.as_ref()
+                    .map_or(true, |tags| tags.matches(contact.get_tags()))
+            })
+            .map(ContactView::from_contact)
+            .collect::<Vec<ContactView>>();
+        contacts.sort_by(|a, b| a.alias.cmp(&b.alias));
+
+        Self(contacts)
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Alias", "Address", "Tags", "Note"]);
+        for contact in self.0.iter() {
+            table.add_row(row![
+                cell!(contact.alias),
+                cell!(contact.address),
+                cell!(contact.tags),
+                cell!(contact.note),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "contacts": self.0.iter().map(|contact| {
+                json!({
+                    "alias": contact.alias,
+                    "address": contact.address,
+                    "tags": contact.tags,
+                    "note": contact.note,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
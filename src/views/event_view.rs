@@ -0,0 +1,98 @@
+use crate::models::{address_labels::AddressLabels, timestamp::Timestamp};
+use prettytable::{cell, row, Table};
+use serde_json::{json, Value};
+use sui_sdk::rpc_types::SuiEvent;
+
+pub struct EventView {
+    timestamp: String,
+    kind: String,
+    sender: String,
+    module: String,
+    tx_digest: String,
+    event_seq: String,
+    parsed_json: Value,
+}
+
+impl EventView {
+    pub fn from_event(event: &SuiEvent, labels: &AddressLabels) -> Self {
+        Self {
+            timestamp: event
+                .timestamp_ms
+                .map(|timestamp_ms| Timestamp::from_millis(timestamp_ms).to_string())
+                .unwrap_or_default(),
+            kind: event.type_.to_string(),
+            sender: labels.get_label(&event.sender),
+            module: format!("{}::{}", event.package_id, event.transaction_module),
+            tx_digest: event.id.tx_digest.to_string(),
+            event_seq: event.id.event_seq.to_string(),
+            parsed_json: event.parsed_json.clone(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "timestamp": self.timestamp,
+            "kind": self.kind,
+            "sender": self.sender,
+            "module": self.module,
+            "tx_digest": self.tx_digest,
+            "event_seq": self.event_seq,
+            "parsed_json": self.parsed_json,
+        })
+    }
+}
+
+pub struct EventListView(Vec<EventView>);
+
+impl EventListView {
+    pub fn from_events(events: &[SuiEvent], labels: &AddressLabels) -> Self {
+        Self(
+            events
+                .iter()
+                .map(|event| EventView::from_event(event, labels))
+                .collect(),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row![
+            "Timestamp",
+            "Event Type",
+            "Sender",
+            "Module",
+            "Tx Digest",
+            "Data"
+        ]);
+        for event in self.0.iter() {
+            table.add_row(row![
+                cell!(event.timestamp),
+                cell!(event.kind),
+                cell!(event.sender),
+                cell!(event.module),
+                cell!(format!("{}:{}", event.tx_digest, event.event_seq)),
+                cell!(serde_json::to_string_pretty(&event.parsed_json).unwrap()),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "events": self.0.iter().map(EventView::to_json).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+
+    /// One JSON object per line, for streaming into other tools.
+    pub fn to_json_lines(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|event| serde_json::to_string(&event.to_json()).unwrap())
+            .collect()
+    }
+}
@@ -1,4 +1,13 @@
+pub mod backup_view;
 pub mod coin_view;
+pub mod contact_view;
+pub mod event_view;
+pub mod move_view;
+pub mod object_view;
+pub mod package_view;
+pub mod profile_view;
 pub mod rpc_server_view;
+pub mod stake_view;
 pub mod tag_view;
+pub mod transaction_view;
 pub mod wallet_view;
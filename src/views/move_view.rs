@@ -0,0 +1,57 @@
+use crate::models::{move_function_id::MoveFunctionId, move_type::MoveType};
+use prettytable::{cell, row, Table};
+use serde_json::{json, Value};
+
+pub struct MoveValueView {
+    kind: String,
+    value: Value,
+}
+
+pub struct MoveValueListView {
+    function: String,
+    values: Vec<MoveValueView>,
+}
+
+impl MoveValueListView {
+    pub fn from_values(function: &MoveFunctionId, values: Vec<(MoveType, Value)>) -> Self {
+        Self {
+            function: function.to_string(),
+            values: values
+                .into_iter()
+                .map(|(move_type, value)| MoveValueView {
+                    kind: move_type.to_string(),
+                    value,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Index", "Type", "Value"]);
+        for (index, value) in self.values.iter().enumerate() {
+            table.add_row(row![
+                cell!(index),
+                cell!(value.kind),
+                cell!(match value.value {
+                    Value::String(ref s) => s.clone(),
+                    ref other => serde_json::to_string_pretty(other).unwrap(),
+                }),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "function": self.function,
+            "results": self.values.iter().map(|value| {
+                json!({
+                    "type": value.kind,
+                    "value": value.value,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
@@ -0,0 +1,175 @@
+use crate::models::address_labels::AddressLabels;
+use prettytable::{cell, row, Table};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use sui_sdk::{rpc_types::SuiObjectData, types::object::Owner};
+
+/// Describes an owner, naming our wallets and contacts.
+pub fn format_owner(owner: &Owner, labels: &AddressLabels) -> String {
+    match owner {
+        Owner::AddressOwner(address) => labels.get_label(address),
+        Owner::ObjectOwner(address) => format!("object {}", address),
+        Owner::Shared {
+            initial_shared_version,
+        } => format!(
+            "shared (initial version {})",
+            initial_shared_version.value()
+        ),
+        Owner::Immutable => "immutable".to_string(),
+        owner => owner.to_string(),
+    }
+}
+
+pub struct ObjectView {
+    object_id: String,
+    kind: String,
+    version: String,
+    name: String,
+    description: String,
+    image_url: String,
+    display: BTreeMap<String, String>,
+}
+
+impl ObjectView {
+    pub fn from_object_data(object: &SuiObjectData) -> Self {
+        let display = object
+            .display
+            .as_ref()
+            .and_then(|display| display.data.clone())
+            .unwrap_or_default();
+
+        Self {
+            object_id: object.object_id.to_string(),
+            kind: object
+                .type_
+                .as_ref()
+                .map(|kind| kind.to_string())
+                .unwrap_or_default(),
+            version: object.version.value().to_string(),
+            name: display.get("name").cloned().unwrap_or_default(),
+            description: display.get("description").cloned().unwrap_or_default(),
+            image_url: display.get("image_url").cloned().unwrap_or_default(),
+            display,
+        }
+    }
+}
+
+pub struct ObjectListView(Vec<ObjectView>);
+
+impl ObjectListView {
+    pub fn from_objects(objects: Vec<SuiObjectData>) -> Self {
+        let mut objects = objects
+            .iter()
+            .map(ObjectView::from_object_data)
+            .collect::<Vec<ObjectView>>();
+        objects.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.object_id.cmp(&b.object_id)));
+
+        Self(objects)
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row![
+            "Object ID",
+            "Type",
+            "Version",
+            "Name",
+            "Description",
+            "Image URL"
+        ]);
+        for object in self.0.iter() {
+            table.add_row(row![
+                cell!(object.object_id),
+                cell!(object.kind),
+                cell!(object.version),
+                cell!(object.name),
+                cell!(object.description),
+                cell!(object.image_url),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "objects": self.0.iter().map(|object| {
+                json!({
+                    "object_id": object.object_id,
+                    "kind": object.kind,
+                    "version": object.version,
+                    "display": object.display,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+pub struct ObjectDetailView {
+    object_id: String,
+    kind: String,
+    version: String,
+    digest: String,
+    owner: String,
+    previous_transaction: String,
+    storage_rebate: String,
+    fields: Value,
+    raw: Value,
+}
+
+impl ObjectDetailView {
+    pub fn from_object_data(object: &SuiObjectData, labels: &AddressLabels) -> Self {
+        let fields = object
+            .content
+            .as_ref()
+            .and_then(|content| serde_json::to_value(content).ok())
+            .and_then(|content| content.get("fields").cloned())
+            .unwrap_or(Value::Null);
+
+        Self {
+            object_id: object.object_id.to_string(),
+            kind: object
+                .type_
+                .as_ref()
+                .map(|kind| kind.to_string())
+                .unwrap_or_default(),
+            version: object.version.value().to_string(),
+            digest: object.digest.to_string(),
+            owner: object
+                .owner
+                .as_ref()
+                .map(|owner| format_owner(owner, labels))
+                .unwrap_or_default(),
+            previous_transaction: object
+                .previous_transaction
+                .map(|digest| digest.to_string())
+                .unwrap_or_default(),
+            storage_rebate: object
+                .storage_rebate
+                .map(|rebate| rebate.to_string())
+                .unwrap_or_default(),
+            fields,
+            raw: serde_json::to_value(object).unwrap_or(Value::Null),
+        }
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Object ID", self.object_id]);
+        table.add_row(row!["Type", self.kind]);
+        table.add_row(row!["Version", self.version]);
+        table.add_row(row!["Digest", self.digest]);
+        table.add_row(row!["Owner", self.owner]);
+        table.add_row(row!["Previous Transaction", self.previous_transaction]);
+        table.add_row(row!["Storage Rebate", self.storage_rebate]);
+        table.add_row(row![
+            "Fields",
+            serde_json::to_string_pretty(&self.fields).unwrap()
+        ]);
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(&self.raw).unwrap()
+    }
+}
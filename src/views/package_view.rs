@@ -0,0 +1,279 @@
+use crate::models::{move_type::MoveType, upgrade_cap::UpgradeCap, wallet::Wallet};
+use prettytable::{cell, row, Table};
+use serde_json::json;
+use std::collections::BTreeMap;
+use sui_sdk::{
+    rpc_types::{
+        SuiMoveAbilitySet, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
+        SuiMoveNormalizedStruct, SuiMoveNormalizedType, SuiMoveVisibility,
+    },
+    types::base_types::ObjectID,
+};
+
+fn format_type(normalized: &SuiMoveNormalizedType) -> String {
+    MoveType::from_normalized(normalized)
+        .map(|move_type| move_type.to_string())
+        .unwrap_or_else(|_| format!("{:?}", normalized))
+}
+
+fn format_abilities(abilities: &SuiMoveAbilitySet) -> Vec<String> {
+    abilities
+        .abilities
+        .iter()
+        .map(|ability| format!("{:?}", ability).to_lowercase())
+        .collect()
+}
+
+fn format_type_parameter(index: usize, abilities: &SuiMoveAbilitySet, is_phantom: bool) -> String {
+    let name = if is_phantom {
+        format!("phantom T{}", index)
+    } else {
+        format!("T{}", index)
+    };
+    let abilities = format_abilities(abilities);
+    if abilities.is_empty() {
+        name
+    } else {
+        format!("{}: {}", name, abilities.join(" + "))
+    }
+}
+
+fn format_type_parameters(type_parameters: &[String]) -> String {
+    if type_parameters.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_parameters.join(", "))
+    }
+}
+
+pub struct FunctionView {
+    module: String,
+    name: String,
+    visibility: String,
+    type_parameters: Vec<String>,
+    parameters: Vec<String>,
+    returns: Vec<String>,
+}
+
+impl FunctionView {
+    pub fn from_normalized(module: &str, name: &str, function: &SuiMoveNormalizedFunction) -> Self {
+        let visibility = match (&function.visibility, function.is_entry) {
+            (SuiMoveVisibility::Public, true) => "public entry",
+            (SuiMoveVisibility::Public, false) => "public",
+            (SuiMoveVisibility::Friend, true) => "friend entry",
+            (SuiMoveVisibility::Friend, false) => "friend",
+            (SuiMoveVisibility::Private, _) => "entry",
+        };
+
+        Self {
+            module: module.to_string(),
+            name: name.to_string(),
+            visibility: visibility.to_string(),
+            type_parameters: function
+                .type_parameters
+                .iter()
+                .enumerate()
+                .map(|(index, abilities)| format_type_parameter(index, abilities, false))
+                .collect(),
+            parameters: function.parameters.iter().map(format_type).collect(),
+            returns: function.return_.iter().map(format_type).collect(),
+        }
+    }
+
+    fn get_signature(&self) -> String {
+        let returns = match self.returns.len() {
+            0 => String::new(),
+            1 => format!(": {}", self.returns[0]),
+            _ => format!(": ({})", self.returns.join(", ")),
+        };
+        format!(
+            "{}{}({}){}",
+            self.name,
+            format_type_parameters(&self.type_parameters),
+            self.parameters.join(", "),
+            returns
+        )
+    }
+}
+
+pub struct StructView {
+    module: String,
+    name: String,
+    abilities: Vec<String>,
+    type_parameters: Vec<String>,
+    fields: Vec<(String, String)>,
+}
+
+impl StructView {
+    pub fn from_normalized(module: &str, name: &str, normalized: &SuiMoveNormalizedStruct) -> Self {
+        Self {
+            module: module.to_string(),
+            name: name.to_string(),
+            abilities: format_abilities(&normalized.abilities),
+            type_parameters: normalized
+                .type_parameters
+                .iter()
+                .enumerate()
+                .map(|(index, parameter)| {
+                    format_type_parameter(index, &parameter.constraints, parameter.is_phantom)
+                })
+                .collect(),
+            fields: normalized
+                .fields
+                .iter()
+                .map(|field| (field.name.clone(), format_type(&field.type_)))
+                .collect(),
+        }
+    }
+}
+
+pub struct UpgradeCapView {
+    address: String,
+    alias: String,
+    object_id: String,
+    version: String,
+    policy: String,
+}
+
+pub struct PackageView {
+    package_id: String,
+    modules: Vec<String>,
+    functions: Vec<FunctionView>,
+    structs: Vec<StructView>,
+    upgrade_caps: Vec<UpgradeCapView>,
+}
+
+impl PackageView {
+    pub fn from_modules(
+        package_id: &ObjectID,
+        modules: &BTreeMap<String, SuiMoveNormalizedModule>,
+        upgrade_caps: Vec<(Wallet, UpgradeCap)>,
+    ) -> Self {
+        Self {
+            package_id: package_id.to_string(),
+            modules: modules.keys().cloned().collect(),
+            functions: modules
+                .iter()
+                .flat_map(|(module_name, module)| {
+                    module
+                        .exposed_functions
+                        .iter()
+                        .map(move |(name, function)| {
+                            FunctionView::from_normalized(module_name, name, function)
+                        })
+                })
+                .collect(),
+            structs: modules
+                .iter()
+                .flat_map(|(module_name, module)| {
+                    module.structs.iter().map(move |(name, normalized)| {
+                        StructView::from_normalized(module_name, name, normalized)
+                    })
+                })
+                .collect(),
+            upgrade_caps: upgrade_caps
+                .into_iter()
+                .map(|(wallet, upgrade_cap)| UpgradeCapView {
+                    address: wallet.get_address().to_string(),
+                    alias: wallet.get_alias().clone().unwrap_or_default().to_string(),
+                    object_id: upgrade_cap.get_object_id().to_string(),
+                    version: upgrade_cap.get_version().to_string(),
+                    policy: upgrade_cap.get_policy_name(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_tables(&self) -> Vec<Table> {
+        let mut functions = Table::new();
+        functions.add_row(row!["Module", "Visibility", "Function"]);
+        for function in self.functions.iter() {
+            functions.add_row(row![
+                cell!(function.module),
+                cell!(function.visibility),
+                cell!(function.get_signature()),
+            ]);
+        }
+
+        let mut structs = Table::new();
+        structs.add_row(row!["Module", "Struct", "Abilities", "Fields"]);
+        for normalized in self.structs.iter() {
+            structs.add_row(row![
+                cell!(normalized.module),
+                cell!(format!(
+                    "{}{}",
+                    normalized.name,
+                    format_type_parameters(&normalized.type_parameters)
+                )),
+                cell!(normalized.abilities.join(", ")),
+                cell!(normalized
+                    .fields
+                    .iter()
+                    .map(|(name, kind)| format!("{}: {}", name, kind))
+                    .collect::<Vec<_>>()
+                    .join("\n")),
+            ]);
+        }
+
+        let mut tables = vec![functions, structs];
+
+        if !self.upgrade_caps.is_empty() {
+            let mut upgrade_caps = Table::new();
+            upgrade_caps.add_row(row!["Address", "Alias", "UpgradeCap", "Version", "Policy"]);
+            for upgrade_cap in self.upgrade_caps.iter() {
+                upgrade_caps.add_row(row![
+                    cell!(upgrade_cap.address),
+                    cell!(upgrade_cap.alias),
+                    cell!(upgrade_cap.object_id),
+                    cell!(upgrade_cap.version),
+                    cell!(upgrade_cap.policy),
+                ]);
+            }
+            tables.push(upgrade_caps);
+        }
+
+        tables
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "package_id": self.package_id,
+            "modules": self.modules.iter().map(|module| {
+                json!({
+                    "name": module,
+                    "functions": self.functions.iter()
+                        .filter(|function| &function.module == module)
+                        .map(|function| json!({
+                            "name": function.name,
+                            "visibility": function.visibility,
+                            "type_parameters": function.type_parameters,
+                            "parameters": function.parameters,
+                            "returns": function.returns,
+                        }))
+                        .collect::<Vec<_>>(),
+                    "structs": self.structs.iter()
+                        .filter(|normalized| &normalized.module == module)
+                        .map(|normalized| json!({
+                            "name": normalized.name,
+                            "abilities": normalized.abilities,
+                            "type_parameters": normalized.type_parameters,
+                            "fields": normalized.fields.iter()
+                                .map(|(name, kind)| json!({ "name": name, "type": kind }))
+                                .collect::<Vec<_>>(),
+                        }))
+                        .collect::<Vec<_>>(),
+                })
+            }).collect::<Vec<_>>(),
+            "upgrade_caps": self.upgrade_caps.iter().map(|upgrade_cap| {
+                json!({
+                    "address": upgrade_cap.address,
+                    "alias": upgrade_cap.alias,
+                    "object_id": upgrade_cap.object_id,
+                    "version": upgrade_cap.version,
+                    "policy": upgrade_cap.policy,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
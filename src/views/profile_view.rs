@@ -0,0 +1,63 @@
+use prettytable::{cell, row, Table};
+use serde_json::json;
+
+pub struct ProfileView {
+    name: String,
+    active: bool,
+    path: String,
+    exists: bool,
+    cipher: bool,
+}
+
+impl ProfileView {
+    pub fn new(name: String, active: bool, path: String, exists: bool, cipher: bool) -> Self {
+        Self {
+            name,
+            active,
+            path,
+            exists,
+            cipher,
+        }
+    }
+}
+
+pub struct ProfileListView(Vec<ProfileView>);
+
+impl ProfileListView {
+    pub fn from_profiles(profiles: Vec<ProfileView>) -> Self {
+        Self(profiles)
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["", "Profile", "Path", "Cipher"]);
+        for profile in self.0.iter() {
+            table.add_row(row![
+                cell!(if profile.active { "*" } else { "" }),
+                cell!(profile.name),
+                cell!(if profile.exists {
+                    profile.path.clone()
+                } else {
+                    format!("{} (not created)", profile.path)
+                }),
+                cell!(if profile.cipher { "set" } else { "missing" }),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json!({
+            "profiles": self.0.iter().map(|profile| {
+                json!({
+                    "name": profile.name,
+                    "active": profile.active,
+                    "path": profile.path,
+                    "exists": profile.exists,
+                    "cipher": profile.cipher,
+                })
+            }).collect::<Vec<_>>(),
+        });
+        serde_json::to_string(&json).unwrap()
+    }
+}
@@ -8,6 +8,7 @@ pub struct RpcServerView {
     url: String,
     alias: String,
     env: String,
+    ws_url: String,
 }
 
 impl RpcServerView {
@@ -16,6 +17,11 @@ impl RpcServerView {
             url: rpc_server.get_url().to_string(),
             alias: rpc_server.get_alias().to_string(),
             env: rpc_server.get_env().to_string(),
+            ws_url: rpc_server
+                .get_ws_url()
+                .as_ref()
+                .map(|ws_url| ws_url.to_string())
+                .unwrap_or_default(),
         }
     }
 }
@@ -53,12 +59,13 @@ impl RpcServerListView {
 
     pub fn to_table(&self) -> Table {
         let mut table = Table::new();
-        table.add_row(row!["Url", "Alias", "Env"]);
+        table.add_row(row!["Url", "Alias", "Env", "WebSocket"]);
         for server in self.0.iter() {
             table.add_row(row![
                 cell!(server.url),
                 cell!(server.alias),
                 cell!(server.env),
+                cell!(server.ws_url),
             ]);
         }
         table
@@ -71,6 +78,7 @@ impl RpcServerListView {
                     "rps_url": server.url,
                     "alias": server.alias,
                     "env": server.env,
+                    "ws_url": server.ws_url,
                 })
             }).collect::<Vec<_>>(),
         }];
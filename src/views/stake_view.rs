@@ -0,0 +1,202 @@
+use crate::models::{sui_amount::SuiAmount, wallet::Wallet};
+use prettytable::{cell, row, Table};
+use serde_json::json;
+use std::collections::HashMap;
+use sui_sdk::{
+    rpc_types::{DelegatedStake, StakeStatus},
+    types::{
+        base_types::SuiAddress, sui_system_state::sui_system_state_summary::SuiSystemStateSummary,
+    },
+};
+
+pub struct StakeView {
+    wallet: String,
+    validator: String,
+    staked_sui_id: String,
+    principal: u64,
+    estimated_reward: u64,
+    request_epoch: String,
+    active_epoch: String,
+    status: String,
+}
+
+pub struct StakeListView(Vec<StakeView>);
+
+impl StakeListView {
+    pub fn from_stakes(
+        system_state: &SuiSystemStateSummary,
+        stakes: Vec<(Wallet, Vec<DelegatedStake>)>,
+    ) -> Self {
+        let names = system_state
+            .active_validators
+            .iter()
+            .map(|validator| (validator.sui_address, validator.name.clone()))
+            .collect::<HashMap<SuiAddress, String>>();
+
+        let mut views = Vec::new();
+        for (wallet, delegated_stakes) in stakes.iter() {
+            for delegated_stake in delegated_stakes.iter() {
+                for stake in delegated_stake.stakes.iter() {
+                    let (status, estimated_reward) = match stake.status {
+                        StakeStatus::Pending => ("pending", 0),
+                        StakeStatus::Active { estimated_reward } => ("active", estimated_reward),
+                        StakeStatus::Unstaked => ("unstaked", 0),
+                    };
+
+                    views.push(StakeView {
+                        wallet: wallet.get_label(),
+                        validator: names
+                            .get(&delegated_stake.validator_address)
+                            .cloned()
+                            .unwrap_or(delegated_stake.validator_address.to_string()),
+                        staked_sui_id: stake.staked_sui_id.to_string(),
+                        principal: stake.principal,
+                        estimated_reward,
+                        request_epoch: stake.stake_request_epoch.to_string(),
+                        active_epoch: stake.stake_active_epoch.to_string(),
+                        status: status.to_string(),
+                    });
+                }
+            }
+        }
+        views.sort_by(|a, b| a.wallet.cmp(&b.wallet).then(a.validator.cmp(&b.validator)));
+
+        Self(views)
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row![
+            "Wallet",
+            "Validator",
+            "Staked SUI",
+            "Principal",
+            "Estimated Reward",
+            "Request Epoch",
+            "Active Epoch",
+            "Status"
+        ]);
+        for stake in self.0.iter() {
+            table.add_row(row![
+                cell!(stake.wallet),
+                cell!(stake.validator),
+                cell!(stake.staked_sui_id),
+                cell!(format!("{} SUI", SuiAmount::from_mist(stake.principal))),
+                cell!(format!(
+                    "{} SUI",
+                    SuiAmount::from_mist(stake.estimated_reward)
+                )),
+                cell!(stake.request_epoch),
+                cell!(stake.active_epoch),
+                cell!(stake.status),
+            ]);
+        }
+        table.add_row(row![
+            cell!("Total"),
+            cell!(""),
+            cell!(""),
+            cell!(format!(
+                "{} SUI",
+                SuiAmount::from_mist(self.0.iter().map(|stake| stake.principal).sum())
+            )),
+            cell!(format!(
+                "{} SUI",
+                SuiAmount::from_mist(self.0.iter().map(|stake| stake.estimated_reward).sum())
+            )),
+            cell!(""),
+            cell!(""),
+            cell!(""),
+        ]);
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "stakes": self.0.iter().map(|stake| {
+                json!({
+                    "wallet": stake.wallet,
+                    "validator": stake.validator,
+                    "staked_sui_id": stake.staked_sui_id,
+                    "principal": stake.principal.to_string(),
+                    "estimated_reward": stake.estimated_reward.to_string(),
+                    "request_epoch": stake.request_epoch,
+                    "active_epoch": stake.active_epoch,
+                    "status": stake.status,
+                })
+            }).collect::<Vec<_>>(),
+            "total_principal": self.0.iter().map(|stake| stake.principal).sum::<u64>().to_string(),
+            "total_estimated_reward": self.0.iter().map(|stake| stake.estimated_reward).sum::<u64>().to_string(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+pub struct ValidatorView {
+    name: String,
+    address: String,
+    commission: String,
+    stake: u64,
+    apy: String,
+}
+
+pub struct ValidatorListView(Vec<ValidatorView>);
+
+impl ValidatorListView {
+    pub fn from_system_state(
+        system_state: &SuiSystemStateSummary,
+        apys: &HashMap<SuiAddress, f64>,
+    ) -> Self {
+        let mut validators = system_state
+            .active_validators
+            .iter()
+            .map(|validator| ValidatorView {
+                name: validator.name.clone(),
+                address: validator.sui_address.to_string(),
+                commission: format!("{:.2}", validator.commission_rate as f64 / 100.0),
+                stake: validator.staking_pool_sui_balance,
+                apy: apys
+                    .get(&validator.sui_address)
+                    .map_or(String::new(), |apy| format!("{:.2}", apy * 100.0)),
+            })
+            .collect::<Vec<ValidatorView>>();
+        validators.sort_by(|a, b| b.stake.cmp(&a.stake).then(a.name.cmp(&b.name)));
+
+        Self(validators)
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row![
+            "Name",
+            "Address",
+            "Commission (%)",
+            "Stake",
+            "APY (%)"
+        ]);
+        for validator in self.0.iter() {
+            table.add_row(row![
+                cell!(validator.name),
+                cell!(validator.address),
+                cell!(validator.commission),
+                cell!(format!("{} SUI", SuiAmount::from_mist(validator.stake))),
+                cell!(validator.apy),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "validators": self.0.iter().map(|validator| {
+                json!({
+                    "name": validator.name,
+                    "address": validator.address,
+                    "commission": validator.commission,
+                    "stake": validator.stake.to_string(),
+                    "apy": validator.apy,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
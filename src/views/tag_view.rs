@@ -1,32 +1,116 @@
-use crate::models::tag_list::TagList;
-use prettytable::{cell, row, Table};
+use crate::models::{
+    tag::{Tag, TAG_SEPARATOR},
+    tag_details::TagDetails,
+    tag_list::TagList,
+    wallet::Wallet,
+};
+use prettytable::{cell, row, Cell, Row, Table};
 use serde_json::json;
+use std::collections::HashMap;
 
-pub struct TagListView(Vec<String>);
+pub struct TagView {
+    name: String,
+    label: String,
+    registered: bool,
+    description: String,
+    color: Option<String>,
+    style_spec: &'static str,
+    wallets: usize,
+}
+
+pub struct TagListView(Vec<TagView>);
 
 impl TagListView {
-    pub fn from_tag_list(tag_list: &TagList) -> Self {
-        let mut tags = tag_list
-            .iter()
-            .map(|x| x.to_string())
-            .collect::<Vec<String>>();
-        tags.sort();
+    /// `usage` also holds unregistered parents of nested tags, which are
+    /// listed so the tree has no gaps.
+    pub fn from_tag_list(
+        tag_list: &TagList,
+        details: &HashMap<Tag, TagDetails>,
+        usage: &HashMap<Tag, usize>,
+    ) -> Self {
+        let mut tags = usage
+            .keys()
+            .chain(tag_list.iter().filter(|tag| !usage.contains_key(tag)))
+            .map(|tag| {
+                let details = details.get(tag).cloned().unwrap_or_default();
+                TagView {
+                    name: tag.to_string(),
+                    label: format!("{}{}", "  ".repeat(tag.get_depth()), tag.get_name()),
+                    registered: tag_list.contains(tag),
+                    description: details.get_description().clone().unwrap_or_default(),
+                    color: details.get_color().map(|color| color.to_string()),
+                    style_spec: details
+                        .get_color()
+                        .map_or("", |color| color.get_style_spec()),
+                    wallets: usage.get(tag).copied().unwrap_or_default(),
+                }
+            })
+            .collect::<Vec<TagView>>();
+        tags.sort_by(|a, b| a.name.split(TAG_SEPARATOR).cmp(b.name.split(TAG_SEPARATOR)));
         Self(tags)
     }
 
     pub fn to_table(&self) -> Table {
         let mut table = Table::new();
-        table.add_row(row!["Name"]);
+        table.add_row(row!["Name", "Description", "Wallets"]);
         for tag in self.0.iter() {
-            table.add_row(row![cell!(tag),]);
+            let style_spec = if tag.registered { tag.style_spec } else { "i" };
+            table.add_row(Row::new(vec![
+                Cell::new(&tag.label).style_spec(style_spec),
+                cell!(tag.description),
+                cell!(tag.wallets),
+            ]));
         }
         table
     }
 
     pub fn to_json_string(&self) -> String {
         let json = json![{
-            "tags": self.0
+            "tags": self.0.iter().map(|tag| {
+                json!({
+                    "name": tag.name,
+                    "registered": tag.registered,
+                    "description": tag.description,
+                    "color": tag.color,
+                    "wallets": tag.wallets,
+                })
+            }).collect::<Vec<_>>(),
         }];
         serde_json::to_string(&json).unwrap()
     }
 }
+
+/// Wallets that would lose a tag, shown before `tag remove` asks for confirmation.
+pub struct TagUsageView(Vec<(String, String, String)>);
+
+impl TagUsageView {
+    pub fn from_wallets(wallets: &[Wallet], tags: &TagList) -> Self {
+        let mut rows = wallets
+            .iter()
+            .map(|wallet| {
+                let mut affected = tags
+                    .iter()
+                    .filter(|tag| wallet.get_tags().contains(tag))
+                    .map(|tag| tag.to_string())
+                    .collect::<Vec<_>>();
+                affected.sort();
+                (
+                    wallet.get_address().to_string(),
+                    wallet.get_alias().clone().unwrap_or_default().to_string(),
+                    affected.join(", "),
+                )
+            })
+            .collect::<Vec<_>>();
+        rows.sort();
+        Self(rows)
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Address", "Alias", "Tags"]);
+        for (address, alias, tags) in self.0.iter() {
+            table.add_row(row![cell!(address), cell!(alias), cell!(tags)]);
+        }
+        table
+    }
+}
@@ -0,0 +1,444 @@
+use super::object_view::format_owner;
+use crate::models::{address_labels::AddressLabels, wallet::Wallet};
+use prettytable::{cell, row, Table};
+use serde_json::{json, Value};
+use sui_sdk::rpc_types::{
+    BalanceChange, ObjectChange, SuiCallArg, SuiEvent, SuiExecutionStatus,
+    SuiTransactionBlockDataAPI, SuiTransactionBlockEffectsAPI, SuiTransactionBlockKind,
+    SuiTransactionBlockResponse,
+};
+
+pub struct TransactionView {
+    sender: String,
+    digest: String,
+    status: String,
+    gas_used: String,
+}
+
+impl TransactionView {
+    pub fn from_response(sender: String, response: &SuiTransactionBlockResponse) -> Self {
+        let (status, gas_used) = match response.effects {
+            Some(ref effects) => (
+                match effects.status() {
+                    SuiExecutionStatus::Success => "success".to_string(),
+                    SuiExecutionStatus::Failure { error } => format!("failure: {}", error),
+                },
+                effects.gas_cost_summary().net_gas_usage().to_string(),
+            ),
+            None => ("unknown".to_string(), String::new()),
+        };
+
+        Self {
+            sender,
+            digest: response.digest.to_string(),
+            status,
+            gas_used,
+        }
+    }
+}
+
+pub struct TransactionListView(Vec<TransactionView>);
+
+impl TransactionListView {
+    pub fn from_responses(responses: &[(Wallet, SuiTransactionBlockResponse)]) -> Self {
+        Self(
+            responses
+                .iter()
+                .map(|(wallet, response)| {
+                    TransactionView::from_response(wallet.get_label(), response)
+                })
+                .collect(),
+        )
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.add_row(row!["Sender", "Digest", "Status", "Gas Used"]);
+        for transaction in self.0.iter() {
+            table.add_row(row![
+                cell!(transaction.sender),
+                cell!(transaction.digest),
+                cell!(transaction.status),
+                cell!(transaction.gas_used),
+            ]);
+        }
+        table
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "transactions": self.0.iter().map(|transaction| {
+                json!({
+                    "sender": transaction.sender,
+                    "digest": transaction.digest,
+                    "status": transaction.status,
+                    "gas_used": transaction.gas_used,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+pub struct ObjectChangeView {
+    change: String,
+    object_id: String,
+    kind: String,
+}
+
+impl ObjectChangeView {
+    pub fn from_object_change(object_change: &ObjectChange) -> Self {
+        let (change, object_id, kind) = match object_change {
+            ObjectChange::Published { package_id, .. } => {
+                ("published", package_id.to_string(), "package".to_string())
+            }
+            ObjectChange::Transferred {
+                object_id,
+                object_type,
+                ..
+            } => (
+                "transferred",
+                object_id.to_string(),
+                object_type.to_string(),
+            ),
+            ObjectChange::Mutated {
+                object_id,
+                object_type,
+                ..
+            } => ("mutated", object_id.to_string(), object_type.to_string()),
+            ObjectChange::Deleted {
+                object_id,
+                object_type,
+                ..
+            } => ("deleted", object_id.to_string(), object_type.to_string()),
+            ObjectChange::Wrapped {
+                object_id,
+                object_type,
+                ..
+            } => ("wrapped", object_id.to_string(), object_type.to_string()),
+            ObjectChange::Created {
+                object_id,
+                object_type,
+                ..
+            } => ("created", object_id.to_string(), object_type.to_string()),
+        };
+
+        Self {
+            change: change.to_string(),
+            object_id,
+            kind,
+        }
+    }
+}
+
+pub struct EventView {
+    kind: String,
+    sender: String,
+    parsed_json: serde_json::Value,
+}
+
+impl EventView {
+    pub fn from_event(event: &SuiEvent) -> Self {
+        Self {
+            kind: event.type_.to_string(),
+            sender: event.sender.to_string(),
+            parsed_json: event.parsed_json.clone(),
+        }
+    }
+}
+
+pub struct TransactionDetailView {
+    transaction: TransactionView,
+    object_changes: Vec<ObjectChangeView>,
+    events: Vec<EventView>,
+}
+
+impl TransactionDetailView {
+    pub fn from_response(wallet: &Wallet, response: &SuiTransactionBlockResponse) -> Self {
+        Self {
+            transaction: TransactionView::from_response(wallet.get_label(), response),
+            object_changes: response
+                .object_changes
+                .iter()
+                .flatten()
+                .map(ObjectChangeView::from_object_change)
+                .collect(),
+            events: response
+                .events
+                .iter()
+                .flat_map(|events| events.data.iter())
+                .map(EventView::from_event)
+                .collect(),
+        }
+    }
+
+    pub fn to_tables(&self) -> Vec<Table> {
+        let mut summary = Table::new();
+        summary.add_row(row!["Sender", "Digest", "Status", "Gas Used"]);
+        summary.add_row(row![
+            cell!(self.transaction.sender),
+            cell!(self.transaction.digest),
+            cell!(self.transaction.status),
+            cell!(self.transaction.gas_used),
+        ]);
+
+        let mut object_changes = Table::new();
+        object_changes.add_row(row!["Change", "Object ID", "Type"]);
+        for object_change in self.object_changes.iter() {
+            object_changes.add_row(row![
+                cell!(object_change.change),
+                cell!(object_change.object_id),
+                cell!(object_change.kind),
+            ]);
+        }
+
+        let mut events = Table::new();
+        events.add_row(row!["Event Type", "Sender", "Data"]);
+        for event in self.events.iter() {
+            events.add_row(row![
+                cell!(event.kind),
+                cell!(event.sender),
+                cell!(serde_json::to_string_pretty(&event.parsed_json).unwrap()),
+            ]);
+        }
+
+        vec![summary, object_changes, events]
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let json = json![{
+            "sender": self.transaction.sender,
+            "digest": self.transaction.digest,
+            "status": self.transaction.status,
+            "gas_used": self.transaction.gas_used,
+            "object_changes": self.object_changes.iter().map(|object_change| {
+                json!({
+                    "change": object_change.change,
+                    "object_id": object_change.object_id,
+                    "kind": object_change.kind,
+                })
+            }).collect::<Vec<_>>(),
+            "events": self.events.iter().map(|event| {
+                json!({
+                    "kind": event.kind,
+                    "sender": event.sender,
+                    "parsed_json": event.parsed_json,
+                })
+            }).collect::<Vec<_>>(),
+        }];
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+pub struct InputView {
+    kind: String,
+    value: String,
+}
+
+impl InputView {
+    pub fn from_call_arg(call_arg: &SuiCallArg) -> Self {
+        match call_arg.object() {
+            Some(object_id) => Self {
+                kind: "object".to_string(),
+                value: object_id.to_string(),
+            },
+            None => Self {
+                kind: "pure".to_string(),
+                value: call_arg
+                    .pure()
+                    .map(|value| value.to_json_value().to_string())
+                    .unwrap_or_default(),
+            },
+        }
+    }
+}
+
+pub struct BalanceChangeView {
+    owner: String,
+    coin_type: String,
+    amount: String,
+}
+
+impl BalanceChangeView {
+    pub fn from_balance_change(balance_change: &BalanceChange, labels: &AddressLabels) -> Self {
+        Self {
+            owner: format_owner(&balance_change.owner, labels),
+            coin_type: balance_change.coin_type.to_string(),
+            amount: balance_change.amount.to_string(),
+        }
+    }
+}
+
+pub struct TransactionInspectView {
+    digest: String,
+    sender: String,
+    kind: String,
+    status: String,
+    checkpoint: String,
+    timestamp_ms: String,
+    computation_cost: String,
+    storage_cost: String,
+    storage_rebate: String,
+    inputs: Vec<InputView>,
+    commands: Vec<String>,
+    object_changes: Vec<ObjectChangeView>,
+    events: Vec<EventView>,
+    balance_changes: Vec<BalanceChangeView>,
+    raw: Value,
+}
+
+impl TransactionInspectView {
+    pub fn from_response(response: &SuiTransactionBlockResponse, labels: &AddressLabels) -> Self {
+        let summary = TransactionView::from_response(String::new(), response);
+
+        let data = response
+            .transaction
+            .as_ref()
+            .map(|transaction| &transaction.data);
+
+        let (inputs, commands) = match data.map(|data| data.transaction()) {
+            Some(SuiTransactionBlockKind::ProgrammableTransaction(programmable)) => (
+                programmable
+                    .inputs
+                    .iter()
+                    .map(InputView::from_call_arg)
+                    .collect(),
+                programmable
+                    .commands
+                    .iter()
+                    .map(|command| command.to_string())
+                    .collect(),
+            ),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        let (computation_cost, storage_cost, storage_rebate) = match response.effects {
+            Some(ref effects) => {
+                let gas_cost_summary = effects.gas_cost_summary();
+                (
+                    gas_cost_summary.computation_cost.to_string(),
+                    gas_cost_summary.storage_cost.to_string(),
+                    gas_cost_summary.storage_rebate.to_string(),
+                )
+            }
+            None => (String::new(), String::new(), String::new()),
+        };
+
+        Self {
+            digest: summary.digest,
+            sender: data
+                .map(|data| labels.get_label(data.sender()))
+                .unwrap_or_default(),
+            kind: data
+                .map(|data| data.transaction().name().to_string())
+                .unwrap_or_default(),
+            status: summary.status,
+            checkpoint: response
+                .checkpoint
+                .map(|checkpoint| checkpoint.to_string())
+                .unwrap_or_default(),
+            timestamp_ms: response
+                .timestamp_ms
+                .map(|timestamp_ms| timestamp_ms.to_string())
+                .unwrap_or_default(),
+            computation_cost,
+            storage_cost,
+            storage_rebate,
+            inputs,
+            commands,
+            object_changes: response
+                .object_changes
+                .iter()
+                .flatten()
+                .map(ObjectChangeView::from_object_change)
+                .collect(),
+            events: response
+                .events
+                .iter()
+                .flat_map(|events| events.data.iter())
+                .map(|event| {
+                    let mut event_view = EventView::from_event(event);
+                    event_view.sender = labels.get_label(&event.sender);
+                    event_view
+                })
+                .collect(),
+            balance_changes: response
+                .balance_changes
+                .iter()
+                .flatten()
+                .map(|balance_change| {
+                    BalanceChangeView::from_balance_change(balance_change, labels)
+                })
+                .collect(),
+            raw: serde_json::to_value(response).unwrap_or(Value::Null),
+        }
+    }
+
+    pub fn to_tables(&self) -> Vec<Table> {
+        let mut summary = Table::new();
+        summary.add_row(row!["Digest", self.digest]);
+        summary.add_row(row!["Sender", self.sender]);
+        summary.add_row(row!["Kind", self.kind]);
+        summary.add_row(row!["Status", self.status]);
+        summary.add_row(row!["Checkpoint", self.checkpoint]);
+        summary.add_row(row!["Timestamp (ms)", self.timestamp_ms]);
+        summary.add_row(row!["Computation Cost", self.computation_cost]);
+        summary.add_row(row!["Storage Cost", self.storage_cost]);
+        summary.add_row(row!["Storage Rebate", self.storage_rebate]);
+
+        let mut inputs = Table::new();
+        inputs.add_row(row!["Input", "Kind", "Value"]);
+        for (index, input) in self.inputs.iter().enumerate() {
+            inputs.add_row(row![cell!(index), cell!(input.kind), cell!(input.value)]);
+        }
+
+        let mut commands = Table::new();
+        commands.add_row(row!["Command", "Detail"]);
+        for (index, command) in self.commands.iter().enumerate() {
+            commands.add_row(row![cell!(index), cell!(command)]);
+        }
+
+        let mut object_changes = Table::new();
+        object_changes.add_row(row!["Change", "Object ID", "Type"]);
+        for object_change in self.object_changes.iter() {
+            object_changes.add_row(row![
+                cell!(object_change.change),
+                cell!(object_change.object_id),
+                cell!(object_change.kind),
+            ]);
+        }
+
+        let mut events = Table::new();
+        events.add_row(row!["Event Type", "Sender", "Data"]);
+        for event in self.events.iter() {
+            events.add_row(row![
+                cell!(event.kind),
+                cell!(event.sender),
+                cell!(serde_json::to_string_pretty(&event.parsed_json).unwrap()),
+            ]);
+        }
+
+        let mut balance_changes = Table::new();
+        balance_changes.add_row(row!["Owner", "Coin Type", "Amount"]);
+        for balance_change in self.balance_changes.iter() {
+            balance_changes.add_row(row![
+                cell!(balance_change.owner),
+                cell!(balance_change.coin_type),
+                cell!(balance_change.amount),
+            ]);
+        }
+
+        vec![
+            summary,
+            inputs,
+            commands,
+            object_changes,
+            events,
+            balance_changes,
+        ]
+    }
+
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(&self.raw).unwrap()
+    }
+}
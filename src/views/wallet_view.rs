@@ -1,76 +1,152 @@
-use crate::models::{alias::Alias, tag_list::TagList, wallet::Wallet, wallet_list::WalletList};
-use prettytable::{cell, row, Table};
-use serde_json::json;
+use crate::models::{suins_name::SuiNsName, timestamp::Timestamp, wallet::Wallet};
+use prettytable::{cell, Row, Table};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use sui_sdk::types::{base_types::SuiAddress, crypto::SignatureScheme};
 
-pub struct WalletView {
-    address: String,
-    alias: String,
-    tags: String,
+fn format_timestamp(timestamp: &Option<Timestamp>) -> Value {
+    timestamp.map_or(Value::Null, |timestamp| json!(timestamp.to_string()))
 }
 
+fn format_key_scheme(scheme: Option<&SignatureScheme>) -> Value {
+    match scheme {
+        Some(SignatureScheme::ED25519) => json!("ed25519"),
+        Some(SignatureScheme::Secp256k1) => json!("secp256k1"),
+        Some(SignatureScheme::Secp256r1) => json!("secp256r1"),
+        Some(_) => json!("other"),
+        None => Value::Null,
+    }
+}
+
+fn get_title(column: &str) -> &str {
+    match column {
+        "address" => "Address",
+        "alias" => "Alias",
+        "suins_name" => "SuiNS",
+        "tags" => "Tags",
+        "key_scheme" => "Scheme",
+        "credentials" => "Credentials",
+        "note" => "Note",
+        "metadata" => "Metadata",
+        "created_at" => "Created",
+        "imported_at" => "Imported",
+        "last_used_at" => "Last used",
+        _ => column,
+    }
+}
+
+/// One wallet as JSON fields keyed by column name.
+pub struct WalletView(Map<String, Value>);
+
 impl WalletView {
-    pub fn from_wallet(wallet: &Wallet) -> Self {
-        Self {
-            address: wallet.get_address().to_string(),
-            alias: wallet.get_alias().clone().unwrap_or_default().to_string(),
-            tags: wallet.get_tags().clone().join(", "),
+    pub fn from_wallet(wallet: &Wallet, names: &HashMap<SuiAddress, SuiNsName>) -> Self {
+        let credentials = match (wallet.has_credentials(), wallet.has_mnemonic()) {
+            (true, true) => "full",
+            (true, false) => "key only",
+            (false, _) => "watch-only",
+        };
+
+        let fields = json!({
+            "address": wallet.get_address().to_string(),
+            "alias": wallet.get_alias().clone().unwrap_or_default().to_string(),
+            "suins_name": names.get(wallet.get_address()).map(|name| name.to_string()),
+            "tags": wallet.get_tags().clone().join(", "),
+            "key_scheme": format_key_scheme(wallet.get_key_scheme()),
+            "credentials": credentials,
+            "note": wallet.get_note(),
+            "metadata": wallet
+                .get_metadata()
+                .iter()
+                .map(|(key, value)| (key.clone(), json!(value)))
+                .collect::<Map<_, _>>(),
+            "created_at": format_timestamp(wallet.get_created_at()),
+            "imported_at": format_timestamp(wallet.get_imported_at()),
+            "last_used_at": format_timestamp(wallet.get_last_used_at()),
+        });
+
+        match fields {
+            Value::Object(fields) => Self(fields),
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_text(&self, column: &str) -> String {
+        match self.0.get(column) {
+            Some(Value::String(text)) => text.clone(),
+            Some(Value::Object(entries)) => entries
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value.as_str().unwrap_or_default()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Some(Value::Null) | None => String::new(),
+            Some(value) => value.to_string(),
         }
     }
 }
 
-pub struct WalletListView(Vec<WalletView>);
+pub struct WalletListView {
+    wallets: Vec<WalletView>,
+    columns: Vec<&'static str>,
+    total: usize,
+    offset: usize,
+}
 
 impl WalletListView {
-    pub fn from_walet_list(
-        wallets: &WalletList,
-        alias: Option<Alias>,
-        tags: Option<TagList>,
+    /// `wallets` is the already filtered, sorted and paged selection out of
+    /// `total` matches.
+    pub fn from_wallets(
+        wallets: &[Wallet],
+        names: &HashMap<SuiAddress, SuiNsName>,
+        columns: Vec<&'static str>,
+        total: usize,
+        offset: usize,
     ) -> Self {
-        let mut wallets = wallets
-            .get_wallets()
-            .iter()
-            .filter(|wallet| {
-                if let Some(alias) = &alias {
-                    if !wallet.contains_alias(alias) {
-                        return false;
-                    }
-                }
-
-                if let Some(ref tags) = tags {
-                    if !wallet.get_tags().contains_all(tags) {
-                        return false;
-                    }
-                }
-                true
-            })
-            .map(WalletView::from_wallet)
-            .collect::<Vec<WalletView>>();
-        wallets.sort_by(|a, b| a.address.cmp(&b.address));
-
-        Self(wallets)
+        Self {
+            wallets: wallets
+                .iter()
+                .map(|wallet| WalletView::from_wallet(wallet, names))
+                .collect(),
+            columns,
+            total,
+            offset,
+        }
     }
 
     pub fn to_table(&self) -> Table {
         let mut table = Table::new();
-        table.add_row(row!["Address", "Alias", "Tags"]);
-        for wallet in self.0.iter() {
-            table.add_row(row![
-                cell!(wallet.address),
-                cell!(wallet.alias),
-                cell!(wallet.tags),
-            ]);
+
+        table.add_row(Row::new(
+            self.columns
+                .iter()
+                .map(|column| cell!(get_title(column)))
+                .collect(),
+        ));
+
+        for wallet in self.wallets.iter() {
+            table.add_row(Row::new(
+                self.columns
+                    .iter()
+                    .map(|column| cell!(wallet.get_text(column)))
+                    .collect(),
+            ));
         }
         table
     }
 
     pub fn to_json_string(&self) -> String {
         let json = json![{
-            "wallets": self.0.iter().map(|wallet| {
-                json!({
-                    "address": wallet.address,
-                    "alias": wallet.alias,
-                    "tags": wallet.tags,
-                })
+            "total": self.total,
+            "offset": self.offset,
+            "wallets": self.wallets.iter().map(|wallet| {
+                self.columns
+                    .iter()
+                    .map(|column| {
+                        (
+                            column.to_string(),
+                            wallet.0.get(*column).cloned().unwrap_or(Value::Null),
+                        )
+                    })
+                    .collect::<Map<_, _>>()
             }).collect::<Vec<_>>(),
         }];
         serde_json::to_string(&json).unwrap()